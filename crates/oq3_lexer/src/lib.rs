@@ -66,6 +66,12 @@ pub enum TokenKind {
 
     HardwareIdent, // OQ3
 
+    /// An annotation together with the rest of its line, for example `@bind $0 q`.
+    Annotation,
+
+    /// A pragma together with the rest of its line, for example `pragma mode opt` or `#pragma mode opt`.
+    Pragma,
+
     /// Like the above, but containing invalid unicode codepoints.
     InvalidIdent,
 
@@ -199,6 +205,11 @@ pub enum LiteralKind {
         empty_exponent: bool,
     },
     SimpleFloat,
+    /// "1.5im", "2im", "1e-3 im"
+    Imaginary {
+        base: Base,
+        empty_digits: bool,
+    },
 }
 
 /// Base of numeric literal encoding according to its prefix.
@@ -310,6 +321,9 @@ impl Cursor<'_> {
             // Whitespace sequence.
             c if is_whitespace(c) => self.whitespace(),
 
+            // `pragma` swallows the rest of the line.
+            'p' if self.at_word("ragma") => self.pragma(),
+
             // Identifier (this should be checked after other variant that can
             // start as identifier).
             c if is_id_start(c) => self.ident_or_unknown_prefix(),
@@ -319,8 +333,22 @@ impl Cursor<'_> {
                 // n.b. floating point literals must have an integer part. e.g. .123 is not valid
                 let literal_kind = self.number(c);
                 let suffix_start = self.pos_within_token();
-                // Eat suffix, and return true if it is a timing suffix.
-                if self.timing_suffix() {
+                // Eat the suffix `im` of an imaginary literal. Otherwise eat suffix,
+                // and return true if it is a timing suffix.
+                if self.imaginary_suffix() {
+                    let (base, empty_digits) = match literal_kind {
+                        Int { base, empty_int } => (base, empty_int),
+                        Float {
+                            base,
+                            empty_exponent,
+                        } => (base, empty_exponent),
+                        _ => (Base::Decimal, false),
+                    };
+                    TokenKind::Literal {
+                        kind: Imaginary { base, empty_digits },
+                        suffix_start,
+                    }
+                } else if self.timing_suffix() {
                     match literal_kind {
                         Float {
                             base: baseval,
@@ -369,7 +397,10 @@ impl Cursor<'_> {
             '}' => CloseBrace,
             '[' => OpenBracket,
             ']' => CloseBracket,
+            // An `@` followed immediately by an identifier starts an annotation.
+            '@' if is_id_start(self.first()) => self.annotation(),
             '@' => At,
+            '#' if self.at_word("pragma") => self.pragma(),
             '#' => Pound,
             '~' => Tilde,
             '?' => Question,
@@ -457,6 +488,25 @@ impl Cursor<'_> {
         }
     }
 
+    // True if the input continues with `word` followed by something other than an identifier character.
+    fn at_word(&self, word: &str) -> bool {
+        let rest = self.as_str();
+        rest.starts_with(word) && !rest[word.len()..].starts_with(is_id_continue)
+    }
+
+    fn pragma(&mut self) -> TokenKind {
+        self.eat_while(|c| c != '\n');
+        Pragma
+    }
+
+    // The keyword may be dotted, as in `@vendor.hint`.
+    fn annotation(&mut self) -> TokenKind {
+        debug_assert!(self.prev() == '@');
+        self.eat_while(|c| is_id_continue(c) || c == '.');
+        self.eat_while(|c| c != '\n');
+        Annotation
+    }
+
     fn whitespace(&mut self) -> TokenKind {
         debug_assert!(is_whitespace(self.prev()));
         self.eat_while(is_whitespace);
@@ -767,6 +817,21 @@ impl Cursor<'_> {
         self.eat_identifier();
     }
 
+    // Eat the suffix `im` of an imaginary literal, which may be separated
+    // from the number by spaces, and return true. Otherwise eat nothing and return false.
+    fn imaginary_suffix(&mut self) -> bool {
+        let rest = self.as_str();
+        let spaces = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+        let rest = &rest[spaces..];
+        if !rest.starts_with("im") || rest[2..].starts_with(is_id_continue) {
+            return false;
+        }
+        for _ in 0..spaces + 2 {
+            self.bump();
+        }
+        true
+    }
+
     // Eat a timing suffix if found and returns true.
     // Otherwise eat suffix if present and return false.
     fn timing_suffix(&mut self) -> bool {
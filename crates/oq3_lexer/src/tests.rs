@@ -150,3 +150,53 @@ fn literal_suffixes() {
         "#]],
     )
 }
+
+#[test]
+fn imaginary_literals() {
+    check_lexing(
+        r####"
+1.5im
+2im
+1e-3 im
+3imag
+"####,
+        expect![[r#"
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Literal { kind: Imaginary { base: Decimal, empty_digits: false }, suffix_start: 3 }, len: 5 }
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Literal { kind: Imaginary { base: Decimal, empty_digits: false }, suffix_start: 1 }, len: 3 }
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Literal { kind: Imaginary { base: Decimal, empty_digits: false }, suffix_start: 4 }, len: 7 }
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Literal { kind: Int { base: Decimal, empty_int: false }, suffix_start: 1 }, len: 5 }
+            Token { kind: Whitespace, len: 1 }
+        "#]],
+    )
+}
+
+#[test]
+fn annotations_and_pragmas() {
+    check_lexing(
+        r####"
+@bind $0 q
+pragma mode opt
+#pragma mode opt
+ctrl @ x
+"####,
+        expect![[r#"
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Annotation, len: 10 }
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Pragma, len: 15 }
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Pragma, len: 16 }
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Ident, len: 4 }
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: At, len: 1 }
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Ident, len: 1 }
+            Token { kind: Whitespace, len: 1 }
+        "#]],
+    );
+}
@@ -94,13 +94,6 @@ impl BlockLike {
     }
 }
 
-fn plain_type(p: &mut Parser<'_>) {
-    assert!(p.current().is_type_name());
-    let r = p.start();
-    p.bump_any();
-    r.complete(p, PATH_TYPE);
-}
-
 /// Parse the optional return type of a `defcal` or `def` definition.
 /// Return `true` if the return type was found, else `false.
 fn opt_ret_type(p: &mut Parser<'_>) -> bool {
@@ -108,8 +101,8 @@ fn opt_ret_type(p: &mut Parser<'_>) -> bool {
         let m = p.start();
         p.bump(T![->]);
         //        types::type_no_bounds(p);
-        if p.current().is_type_name() {
-            plain_type(p);
+        if p.current().is_scalar_type() {
+            expressions::type_spec(p);
         } else {
             p.error("Expected return type after ->");
             m.abandon(p);
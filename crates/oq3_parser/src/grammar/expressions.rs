@@ -65,6 +65,10 @@ pub(crate) fn stmt(p: &mut Parser<'_>, semicolon: Semicolon) {
         let_stmt(p, m, semicolon);
         return;
     }
+    if p.at(ANNOTATION_TEXT) {
+        annotated_stmt(p, semicolon);
+        return;
+    }
     // if p.current().is_type_name() {
     //     let m = p.start();
     //     type_declaration_stmt(p, m);
@@ -121,7 +125,7 @@ pub(crate) fn stmt(p: &mut Parser<'_>, semicolon: Semicolon) {
     // It should appear once.
     fn let_stmt(p: &mut Parser<'_>, m: Marker, with_semi: Semicolon) {
         p.bump(T![let]);
-        p.expect(IDENT);
+        var_name(p);
         p.expect(T![=]);
         expressions::expr(p);
         match with_semi {
@@ -133,6 +137,22 @@ pub(crate) fn stmt(p: &mut Parser<'_>, semicolon: Semicolon) {
     }
 }
 
+// One or more annotations followed by the statement that they annotate.
+fn annotated_stmt(p: &mut Parser<'_>, semicolon: Semicolon) {
+    let m = p.start();
+    while p.at(ANNOTATION_TEXT) {
+        let ann = p.start();
+        p.bump(ANNOTATION_TEXT);
+        ann.complete(p, ANNOTATION);
+    }
+    if p.at(EOF) || p.at(T!['}']) {
+        p.error("expected statement after annotation");
+    } else {
+        stmt(p, semicolon);
+    }
+    m.complete(p, ANNOTATED_STMT);
+}
+
 // Careful, this reads til } *or* EOF. And this may be called without having read a
 // {. In a way, this is an implicit block expression. Or else it is inadvertent.
 // YES: inadvertent when adpating code for OQ3.
@@ -199,7 +219,9 @@ fn current_op(p: &Parser<'_>) -> (u8, SyntaxKind, Associativity) {
         T![&]                  => (8,  T![&],   Left),
         T![/] if p.at(T![/=])  => (1,  T![/=],  Right),
         T![/]                  => (11, T![/],   Left),
+        T![*] if p.at(T![**=]) => (1,  T![**=], Right),
         T![*] if p.at(T![*=])  => (1,  T![*=],  Right),
+        T![*] if p.at(T![**])  => (13, T![**],  Right),
         T![*]                  => (11, T![*],   Left),
         T![.] if p.at(T![..=]) => (2,  T![..=], Left),
         T![.] if p.at(T![..])  => (2,  T![..],  Left),
@@ -218,15 +240,16 @@ fn expr_bp(
     bp: u8,
 ) -> Option<(CompletedMarker, BlockLike)> {
     let m = m.unwrap_or_else(|| p.start());
-    #[allow(clippy::nonminimal_bool)]
-    if !p.at_ts(EXPR_FIRST) && !(p.current().is_classical_type() && p.nth(1) == T!['(']) {
+    // A type name begins a cast expression, such as `float(x)` or `int[32](x)`.
+    let at_cast = p.current().is_classical_type() && matches!(p.nth(1), T!['('] | T!['[']);
+    if !p.at_ts(EXPR_FIRST) && !at_cast {
         p.err_recover("expr_bp: expected expression", atom::EXPR_RECOVERY_SET); // FIXME, remove debug from string
         m.abandon(p);
         return None;
     }
     let lhs_result = lhs(p, r);
     //    dbg!(&lhs_result);
-    let mut lhs = match lhs_result {
+    let lhs = match lhs_result {
         Some((lhs, blocklike, got_call)) => {
             if got_call {
                 m.abandon(p);
@@ -243,7 +266,17 @@ fn expr_bp(
             return None;
         }
     };
+    Some((binary_expr_rhs(p, lhs, r, bp), BlockLike::NotBlock))
+}
 
+// Parse the binary operators, and their right operands, that follow the already
+// parsed left operand `lhs`. Only operators with binding power at least `bp` are parsed.
+fn binary_expr_rhs(
+    p: &mut Parser<'_>,
+    mut lhs: CompletedMarker,
+    r: Restrictions,
+    bp: u8,
+) -> CompletedMarker {
     loop {
         let (op_bp, op, associativity) = current_op(p);
         if op_bp < bp {
@@ -262,8 +295,12 @@ fn expr_bp(
             Associativity::Right => op_bp,
         };
         //        expr_bp(p, None, Restrictions { prefer_stmt: false, ..r }, op_bp);
-        expr_bp(p, None, r, op_bp);
-        if matches!(op, T![=]) {
+        if ASSIGNMENT_OPS.contains(op) && p.at(T!['{']) {
+            array_literal(p);
+        } else {
+            expr_bp(p, None, r, op_bp);
+        }
+        if ASSIGNMENT_OPS.contains(op) {
             if matches!(lhs_kind, IDENTIFIER | INDEXED_IDENTIFIER) {
                 lhs = m.complete(p, ASSIGNMENT_STMT);
             } else {
@@ -274,11 +311,44 @@ fn expr_bp(
             lhs = m.complete(p, BIN_EXPR);
         }
     }
-    Some((lhs, BlockLike::NotBlock))
+    lhs
 }
 
-const LHS_FIRST: TokenSet =
-    atom::ATOM_EXPR_FIRST.union(TokenSet::new(&[T![&], T![*], T![!], T![.], T![-], T![_]]));
+// `=` and the compound assignment operators.
+const ASSIGNMENT_OP_KINDS: [SyntaxKind; 12] = [
+    T![=],
+    T![+=],
+    T![-=],
+    T![*=],
+    T![/=],
+    T![%=],
+    T![**=],
+    T![&=],
+    T![|=],
+    T![^=],
+    T![<<=],
+    T![>>=],
+];
+
+const ASSIGNMENT_OPS: TokenSet = TokenSet::new(&ASSIGNMENT_OP_KINDS);
+
+// Return the assignment operator at position `n`, if there is one.
+pub(crate) fn nth_assignment_op(p: &Parser<'_>, n: usize) -> Option<SyntaxKind> {
+    if p.nth_at(n, T![==]) || p.nth_at(n, T![=>]) {
+        return None;
+    }
+    ASSIGNMENT_OP_KINDS.into_iter().find(|&op| p.nth_at(n, op))
+}
+
+const LHS_FIRST: TokenSet = atom::ATOM_EXPR_FIRST.union(TokenSet::new(&[
+    T![&],
+    T![*],
+    T![!],
+    T![~],
+    T![.],
+    T![-],
+    T![_],
+]));
 
 // Handles only prefix and postfix expressions?? Not binary infix?
 fn lhs(p: &mut Parser<'_>, r: Restrictions) -> Option<(CompletedMarker, BlockLike, bool)> {
@@ -297,8 +367,9 @@ fn lhs(p: &mut Parser<'_>, r: Restrictions) -> Option<(CompletedMarker, BlockLik
             return Some((cm, block_like, got_call));
         }
     };
-    // parse the interior of the unary expression
-    expr_bp(p, None, r, 255);
+    // parse the interior of the unary expression. Only `**` binds more tightly
+    // than a unary operator, so `-x ** 2` is `-(x ** 2)`.
+    expr_bp(p, None, r, 13);
     let cm = m.complete(p, kind);
     Some((cm, BlockLike::NotBlock, false))
 }
@@ -323,9 +394,11 @@ fn postfix_expr(
             //         [] => {}
             //     }
             // }
+            // Only a gate call ends the expression. A function call may be an operand.
             T!['('] if allow_calls => {
-                got_call = true;
-                call_expr(p, lhs)
+                let cm = call_expr(p, lhs);
+                got_call = cm.kind() == GATE_CALL_STMT;
+                cm
             }
             T!['['] if allow_calls => match lhs.kind() {
                 IDENTIFIER => indexed_identifer(p, lhs),
@@ -375,8 +448,17 @@ fn type_name(p: &mut Parser<'_>) {
 
 pub(crate) fn type_spec(p: &mut Parser<'_>) -> bool {
     let m = p.start();
+    let is_complex = p.at(T![complex]);
     type_name(p);
-    if p.at(T!['[']) {
+    // The type of the components of a complex number, as in `complex[float[64]]`.
+    if is_complex && p.eat(T!['[']) {
+        if p.current().is_scalar_type() {
+            type_spec(p);
+        } else {
+            p.error("expected scalar type of complex components");
+        }
+        p.expect(T![']']);
+    } else if p.at(T!['[']) {
         designator(p);
     }
     m.complete(p, SCALAR_TYPE);
@@ -393,7 +475,35 @@ pub(crate) fn quantum_type_spec(p: &mut Parser<'_>) -> bool {
     true
 }
 
-fn designator(p: &mut Parser<'_>) -> bool {
+// An array type, for example `array[int[32], 3, 4]`.
+// When preceded by `readonly` or `mutable` this is an array reference,
+// which may specify the number of dimensions instead, as in `#dim = 2`.
+pub(crate) fn array_type_spec(p: &mut Parser<'_>) {
+    let m = p.start();
+    let is_reference = p.eat(T![readonly]) || p.eat(T![mutable]);
+    p.expect(T![array]);
+    p.expect(T!['[']);
+    if p.current().is_scalar_type() {
+        type_spec(p);
+    } else {
+        p.error("expected scalar type of array elements");
+    }
+    p.expect(T![,]);
+    if is_reference && p.at(T![#]) {
+        let m1 = p.start();
+        p.bump(T![#]);
+        var_name(p);
+        p.expect(T![=]);
+        expr(p);
+        m1.complete(p, DIM_EXPR);
+    } else {
+        params::expression_list(p);
+    }
+    p.expect(T![']']);
+    m.complete(p, ARRAY_TYPE);
+}
+
+pub(crate) fn designator(p: &mut Parser<'_>) -> bool {
     let m = p.start();
     p.eat(T!['[']);
     expr(p);
@@ -410,23 +520,31 @@ pub(crate) fn var_name(p: &mut Parser<'_>) {
 
 pub(crate) fn _returns_bool_classical_declaration_stmt(p: &mut Parser<'_>, m: Marker) -> bool {
     p.eat(T![const]); // FIXME. Fix this in ungram and then move this to type_spec
-    let mstmt = p.start();
+    if p.at(T![array]) {
+        array_type_spec(p);
+        var_name(p);
+        if p.eat(T![=]) {
+            expr_or_array_literal(p);
+        }
+        p.expect(T![;]);
+        m.complete(p, CLASSICAL_DECLARATION_STATEMENT);
+        return true;
+    }
+    // A type with a designator may begin a cast rather than a declaration, as in `int[32](x);`.
+    // This is only known after the designator is parsed.
+    let mcast = p.start();
     type_spec(p);
-    if p.current() == T!['('] {
-        p.expect(T!['(']);
+    if p.at(T!['(']) {
+        p.bump(T!['(']);
         expr(p);
         p.expect(T![')']);
-        m.complete(p, CAST_EXPRESSION);
-        if p.at(SEMICOLON) {
-            p.expect(SEMICOLON);
-            mstmt.complete(p, EXPR_STMT);
-        } else {
-            mstmt.abandon(p);
-        }
+        let cast = mcast.complete(p, CAST_EXPRESSION);
+        binary_expr_rhs(p, cast, Restrictions { prefer_stmt: true }, 1);
+        p.expect(T![;]);
+        m.complete(p, EXPR_STMT);
         return true;
-    } else {
-        mstmt.abandon(p);
     }
+    mcast.abandon(p);
     var_name(p);
     if p.eat(T![;]) {
         m.complete(p, CLASSICAL_DECLARATION_STATEMENT);
@@ -436,12 +554,38 @@ pub(crate) fn _returns_bool_classical_declaration_stmt(p: &mut Parser<'_>, m: Ma
         m.abandon(p);
         return false;
     }
-    expr(p);
+    expr_or_array_literal(p);
     p.expect(T![;]); // why did I suddenly have to include this?
     m.complete(p, CLASSICAL_DECLARATION_STATEMENT);
     true
 }
 
+// An array literal may appear only as the value of a declaration or assignment.
+// Elsewhere `{` begins a block.
+pub(crate) fn expr_or_array_literal(p: &mut Parser<'_>) {
+    if p.at(T!['{']) {
+        array_literal(p);
+    } else {
+        expr(p);
+    }
+}
+
+// For example `{1, 2}` or `{{1, 2}, {3, 4}}`.
+fn array_literal(p: &mut Parser<'_>) {
+    let m = p.start();
+    p.bump(T!['{']);
+    let list = p.start();
+    while !p.at(T!['}']) && !p.at(EOF) {
+        expr_or_array_literal(p);
+        if !p.at(T!['}']) && !p.expect(T![,]) {
+            break;
+        }
+    }
+    list.complete(p, EXPRESSION_LIST);
+    p.expect(T!['}']);
+    m.complete(p, ARRAY_LITERAL);
+}
+
 pub(crate) fn classical_declaration_stmt(p: &mut Parser<'_>, m: Marker) {
     _returns_bool_classical_declaration_stmt(p, m);
 }
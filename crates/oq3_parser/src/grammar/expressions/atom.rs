@@ -18,6 +18,9 @@ pub(crate) const LITERAL_FIRST: TokenSet = TokenSet::new(&[
     CHAR,
     STRING,
     BIT_STRING,
+    TIMING_INT_NUMBER,
+    TIMING_FLOAT_NUMBER,
+    IMAGINARY_NUMBER,
 ]);
 
 pub(crate) fn literal(p: &mut Parser<'_>) -> Option<CompletedMarker> {
@@ -36,7 +39,7 @@ pub(super) const ATOM_EXPR_FIRST: TokenSet =
         T!['{'],
         T!['['],
         T![|],
-        T![box],
+        T![durationof],
         T![const],
         T![for],
         T![if],
@@ -57,7 +60,6 @@ pub(super) fn atom_expr(
     if let Some(m) = literal(p) {
         return Some((m, BlockLike::NotBlock));
     }
-    let la = p.nth(1);
     // Do we need to check la == T!['('] ?
     if p.current().is_classical_type() {
         let m = expressions::cast_expr(p);
@@ -71,16 +73,17 @@ pub(super) fn atom_expr(
         // Need to distinguish these
         T!['['] => array_expr(p),
         //        T![if] => if_expr(p),
-        T![box] => box_expr(p, None),
+        T![durationof] => durationof_expr(p),
         //        T![while] => while_expr(p, None),
         T![measure] => measure_expression(p),
         T![return] => return_expr(p),
         T!['{'] => block_expr(p),
-        T![for] => for_expr(p, None),
         // FIXME: This is the simplest gate call. Need to cover
         // `mygate(myparam) q1, q2;` as well.
         //        IDENT if la == IDENT => gate_call_expr(p),
-        IDENT if (la == T![=] && p.nth(2) != T![=]) => grammar::items::assignment_statement(p),
+        IDENT if expressions::nth_assignment_op(p, 1).is_some() => {
+            grammar::items::assignment_statement(p)
+        }
         // FIXME: An identifer bound by the user in the program.
         // Need to handle more than identifier.
         // Also `NAME` is probably not correct.
@@ -98,12 +101,12 @@ pub(super) fn atom_expr(
     Some((done, blocklike))
 }
 
-fn measure_expression(p: &mut Parser<'_>) -> CompletedMarker {
+pub(crate) fn measure_expression(p: &mut Parser<'_>) -> CompletedMarker {
     let m = p.start();
     p.bump(T![measure]);
     match p.current() {
         IDENT | HARDWAREIDENT => {
-            items::ident_or_index_expr(p);
+            params::gate_operand(p);
         }
         _ => {
             p.error("expecting qubit(s) to measure");
@@ -254,20 +257,7 @@ fn array_expr(p: &mut Parser<'_>) -> CompletedMarker {
 //     m.complete(p, WHILE_STMT)
 // }
 
-// test for_expr
-// fn foo() {
-//     for x in [] {};
-// }
-fn for_expr(p: &mut Parser<'_>, m: Option<Marker>) -> CompletedMarker {
-    assert!(p.at(T![for]));
-    let m = m.unwrap_or_else(|| p.start());
-    p.bump(T![for]);
-    p.expect(IDENT);
-    p.expect(T![in]);
-    expr_no_struct(p);
-    block_expr(p);
-    m.complete(p, FOR_STMT)
-}
+// `for` is a statement. It is parsed in items::for_stmt.
 
 pub(crate) fn block_expr(p: &mut Parser<'_>) -> CompletedMarker {
     // FIXME: can't use this check in refactor.
@@ -284,9 +274,6 @@ pub(crate) fn block_expr(p: &mut Parser<'_>) -> CompletedMarker {
     m.complete(p, BLOCK_EXPR)
 }
 
-// FIXME: This idiom for handling "measure" does not occur
-// in the original r-a crates. Need to refactor to avoid
-// starting a new Marker.
 // test return_expr
 // fn foo() {
 //     return;
@@ -296,27 +283,24 @@ fn return_expr(p: &mut Parser<'_>) -> CompletedMarker {
     assert!(p.at(T![return]));
     let m = p.start();
     p.bump(T![return]);
+    // `measure` is in `EXPR_FIRST`, so this includes `return measure q;`
     if p.at_ts(EXPR_FIRST) {
         expr(p);
-    } else if p.at(T![measure]) {
-        let m1 = p.start();
-        expressions::items::measure_(p, m1);
     }
     m.complete(p, RETURN_EXPR)
 }
 
-// test box_expr
-// fn foo() {
-//     let x = box 1i32;
-//     let y = (box 1i32, box 2i32);
-//     let z = Foo(box 1i32, box 2i32);
-// }
-fn box_expr(p: &mut Parser<'_>, m: Option<Marker>) -> CompletedMarker {
-    assert!(p.at(T![box]));
-    let m = m.unwrap_or_else(|| p.start());
-    p.bump(T![box]);
-    if p.at_ts(EXPR_FIRST) {
-        expr(p);
+// `durationof({ ... })`
+fn durationof_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![durationof]));
+    let m = p.start();
+    p.bump(T![durationof]);
+    p.expect(T!['(']);
+    if p.at(T!['{']) {
+        block_expr(p);
+    } else {
+        p.error("expected a block");
     }
-    m.complete(p, BOX_EXPR)
+    p.expect(T![')']);
+    m.complete(p, DURATION_OF_EXPR)
 }
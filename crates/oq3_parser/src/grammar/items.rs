@@ -16,14 +16,21 @@ pub(super) fn source_file_contents(p: &mut Parser<'_>, stop_on_r_curly: bool) {
 pub(super) const ITEM_RECOVERY_SET: TokenSet = TokenSet::new(&[
     T![gate],
     T![def],
+    T![extern],
     T![defcal],
     T![defcalgrammar],
     T![include],
     T![cal],
     T![reset],
-    //    T![measure],
+    T![delay],
+    T![box],
+    T![measure],
     T![barrier],
     T![const],
+    T![input],
+    T![output],
+    T![qreg],
+    T![creg],
     T![let],
     T![OPENQASM],
     T![;],
@@ -100,26 +107,46 @@ pub(super) fn opt_item(p: &mut Parser<'_>, m: Marker) -> Result<(), Marker> {
 
     match p.current() {
         T![qubit] => qubit_declaration_stmt(p, m),
+        T![qreg] | T![creg] => old_style_declaration_stmt(p, m),
+        IDENT
+            if p.at_contextual_kw(T![opaque])
+                && la == IDENT
+                && matches!(p.nth(2), IDENT | T!['(']) =>
+        {
+            opaque_gate_declaration(p, m)
+        }
         T![const] => expressions::classical_declaration_stmt(p, m),
+        T![input] | T![output] => io_declaration_stmt(p, m),
         IDENT if (la == IDENT || la == HARDWAREIDENT) => gate_call_stmt(p, m),
-        IDENT if (la == T![=] && p.nth(2) != T![=]) => assignment_statement_with_marker(p, m),
+        T![inv] | T![pow] | T![ctrl] | T![negctrl] => modified_gate_call_stmt(p, m),
+        IDENT if expressions::nth_assignment_op(p, 1).is_some() => {
+            assignment_statement_with_marker(p, m)
+        }
         T![gate] => gate_definition(p, m),
         T![break] => break_(p, m),
         T![continue] => continue_(p, m),
         T![end] => end_(p, m),
         T![if] => if_stmt(p, m),
         T![while] => while_stmt(p, m),
+        T![switch] => switch_case_stmt(p, m),
+        T![for] => for_stmt(p, m),
         T![def] => def_(p, m),
+        T![extern] => extern_(p, m),
         T![defcal] => defcal_(p, m),
         T![cal] => cal_(p, m),
         T![defcalgrammar] => defcalgrammar_(p, m),
         T![reset] => reset_(p, m),
-        // measure is not a statement, does not belong here.
-        //            T![measure] => measure_(p, m),
+        T![delay] => delay_(p, m),
+        T![box] => box_(p, m),
+        T![measure] => measure_(p, m),
         T![barrier] => barrier_(p, m),
         T![OPENQASM] => version_string(p, m),
         T![include] => include(p, m),
         T![gphase] => gphase_call(p, m),
+        PRAGMA_TEXT => {
+            p.bump(PRAGMA_TEXT);
+            m.complete(p, PRAGMA);
+        }
         // This is already done elsewhere
         //            T![let] => let_stmt(p, m),
         _ => return Err(m),
@@ -142,6 +169,42 @@ fn gate_call_stmt(p: &mut Parser<'_>, m: Marker) {
     m.complete(p, GATE_CALL_STMT);
 }
 
+// A gate call preceded by modifiers, for example `ctrl(2) @ inv @ U(a, b, c) q0, q1, q2;`
+fn modified_gate_call_stmt(p: &mut Parser<'_>, m: Marker) {
+    let list_marker = p.start();
+    while matches!(p.current(), T![inv] | T![pow] | T![ctrl] | T![negctrl]) {
+        gate_modifier(p);
+    }
+    list_marker.complete(p, MODIFIER_LIST);
+    if !p.at(IDENT) {
+        p.error("expected name of gate after modifiers");
+        m.abandon(p);
+        return;
+    }
+    expressions::atom::identifier(p); // name of gate
+    if p.at(T!['(']) {
+        expressions::call_arg_list(p);
+    }
+    params::arg_list_gate_call_qubits(p);
+    p.expect(SEMICOLON);
+    m.complete(p, GATE_CALL_STMT);
+}
+
+fn gate_modifier(p: &mut Parser<'_>) {
+    let m = p.start();
+    let kind = p.current();
+    p.bump_any();
+    if p.at(T!['(']) {
+        p.bump(T!['(']);
+        expressions::expr(p);
+        p.expect(T![')']);
+    } else if kind == T![pow] {
+        p.error("expected exponent in parentheses after `pow`");
+    }
+    p.expect(T![@]);
+    m.complete(p, GATE_MODIFIER);
+}
+
 fn gphase_call(p: &mut Parser<'_>, m: Marker) {
     assert!(p.at(T![gphase]));
     p.bump(T![gphase]);
@@ -155,19 +218,32 @@ fn if_stmt(p: &mut Parser<'_>, m: Marker) {
     //    let m = p.start();
     p.bump(T![if]);
     expressions::expr_no_struct(p);
-    expressions::block_expr(p);
+    block_or_stmt(p);
     if p.at(T![else]) {
         p.bump(T![else]);
         if p.at(T![if]) {
             let m = p.start();
             if_stmt(p, m);
         } else {
-            expressions::block_expr(p);
+            block_or_stmt(p);
         }
     }
     m.complete(p, IF_STMT);
 }
 
+// The branch of an `if` statement may be a single statement rather than a block,
+// as in the OpenQASM 2 form `if (c==1) x q;`. We wrap the statement in a `BLOCK_EXPR`
+// without braces so that the branch has the same shape in both cases.
+fn block_or_stmt(p: &mut Parser<'_>) {
+    if p.at(T!['{']) {
+        expressions::block_expr(p);
+    } else {
+        let m = p.start();
+        expressions::stmt(p, expressions::Semicolon::Required);
+        m.complete(p, BLOCK_EXPR);
+    }
+}
+
 //fn while_stmt(p: &mut Parser<'_>, m: Option<Marker>) -> CompletedMarker {
 fn while_stmt(p: &mut Parser<'_>, m: Marker) {
     assert!(p.at(T![while]));
@@ -177,14 +253,114 @@ fn while_stmt(p: &mut Parser<'_>, m: Marker) {
     m.complete(p, WHILE_STMT);
 }
 
+// For example `switch (i) { case 0, 1 { } default { } }`
+fn switch_case_stmt(p: &mut Parser<'_>, m: Marker) {
+    assert!(p.at(T![switch]));
+    p.bump(T![switch]);
+    expressions::expr_no_struct(p);
+    if !p.eat(T!['{']) {
+        p.error("expected `{` after switch control expression");
+        m.complete(p, SWITCH_CASE_STMT);
+        return;
+    }
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if !matches!(p.current(), T![case] | T![default]) {
+            if p.at(T!['{']) {
+                // `err_and_bump` does not consume `{`, so skip the whole block.
+                p.error("expected `case` or `default`");
+                expressions::block_expr(p);
+            } else {
+                p.err_and_bump("expected `case` or `default`");
+            }
+            continue;
+        }
+        case_item(p);
+    }
+    p.expect(T!['}']);
+    m.complete(p, SWITCH_CASE_STMT);
+}
+
+fn case_item(p: &mut Parser<'_>) {
+    let m = p.start();
+    if p.eat(T![default]) {
+        // `default` takes no labels.
+    } else {
+        p.bump(T![case]);
+        case_labels(p);
+    }
+    if p.at(T!['{']) {
+        expressions::block_expr(p);
+    } else {
+        p.error("expected a block");
+    }
+    m.complete(p, CASE_ITEM);
+}
+
+// A comma-separated list of labels terminated by `{`.
+fn case_labels(p: &mut Parser<'_>) {
+    let m = p.start();
+    while !p.at(EOF) && !p.at(T!['{']) {
+        if expressions::expr(p).is_none() {
+            break;
+        }
+        if !p.eat(T![,]) {
+            break;
+        }
+    }
+    m.complete(p, EXPRESSION_LIST);
+}
+
+// The loop variable is typed, for example `for int i in [0:3] { }`.
+// The iterable is a set expression `{1, 3, 5}`, a range in brackets, or an expression.
+fn for_stmt(p: &mut Parser<'_>, m: Marker) {
+    assert!(p.at(T![for]));
+    p.bump(T![for]);
+    if p.current().is_scalar_type() {
+        expressions::type_spec(p);
+    } else {
+        p.error("expected type of loop variable");
+    }
+    expressions::var_name(p);
+    p.expect(T![in]);
+    for_iterable(p);
+    if p.at(T!['{']) {
+        expressions::block_expr(p);
+    } else {
+        p.error("expected a block");
+    }
+    m.complete(p, FOR_STMT);
+}
+
+fn for_iterable(p: &mut Parser<'_>) {
+    let m = p.start();
+    match p.current() {
+        T!['{'] => {
+            let m1 = p.start();
+            p.bump(T!['{']);
+            params::expression_list(p);
+            p.expect(T!['}']);
+            m1.complete(p, SET_EXPRESSION);
+        }
+        T!['['] => {
+            p.bump(T!['[']);
+            expressions::expr_or_range_expr(p);
+            p.expect(T![']']);
+        }
+        _ => {
+            expressions::expr_no_struct(p);
+        }
+    }
+    m.complete(p, FOR_ITERABLE);
+}
+
 // Called from atom::atom_expr
 // FIXME: return CompletedMarker because this is called from `atom_expr`
 // Where functions are called and what they return should be made more uniform.
 pub(crate) fn assignment_statement(p: &mut Parser<'_>) -> CompletedMarker {
     let m = p.start();
     name(p);
-    p.bump(T![=]);
-    expressions::expr(p);
+    bump_assignment_op(p);
+    expressions::expr_or_array_literal(p);
     p.expect(SEMICOLON);
     m.complete(p, ASSIGNMENT_STMT)
 }
@@ -192,12 +368,18 @@ pub(crate) fn assignment_statement(p: &mut Parser<'_>) -> CompletedMarker {
 // Called from items::opt_item
 pub(crate) fn assignment_statement_with_marker(p: &mut Parser<'_>, m: Marker) {
     name(p);
-    p.bump(T![=]);
-    expressions::expr(p);
+    bump_assignment_op(p);
+    expressions::expr_or_array_literal(p);
     p.expect(SEMICOLON);
     m.complete(p, ASSIGNMENT_STMT);
 }
 
+// Consume `=` or a compound assignment operator such as `+=`.
+fn bump_assignment_op(p: &mut Parser<'_>) {
+    let op = expressions::nth_assignment_op(p, 0).unwrap();
+    p.bump(op);
+}
+
 fn qubit_declaration_stmt(p: &mut Parser<'_>, m: Marker) {
     assert!(p.at(T![qubit]));
     expressions::quantum_type_spec(p);
@@ -216,44 +398,11 @@ fn qubit_declaration_stmt(p: &mut Parser<'_>, m: Marker) {
 //     Ok(())
 // }
 
-// We call index_expr, which backtracks. So we have to
-// complete the first IDENT.
-// An alternative would be to do the logic here and
-// avoid backtracking.
-//
-// I don't know why this kind of thing is evidently used for parsing rust.
-// It should be used heavily for OQ3. This could be because expressions
-// can be used in so many places in Rust. Eg. `myfunc(arg1, arg2)[ind1]`.
-// But in OQ3, a qubit needs to
-// Be an identifier or an identifier followed by '[', etc.
-pub(crate) fn ident_or_index_expr(p: &mut Parser<'_>) {
-    let m = p.start();
-    match p.current() {
-        IDENT => {
-            p.bump(IDENT);
-            match p.current() {
-                T!['['] => {
-                    let newm = m.complete(p, IDENT);
-                    expressions::index_expr(p, newm);
-                }
-                _ => {
-                    m.complete(p, IDENT);
-                }
-            }
-        }
-        HARDWAREIDENT => {
-            p.bump(HARDWAREIDENT);
-            m.complete(p, HARDWARE_QUBIT);
-        }
-        _ => panic!(),
-    }
-}
-
 fn reset_(p: &mut Parser<'_>, m: Marker) {
     p.bump(T![reset]);
     match p.current() {
         IDENT | HARDWAREIDENT => {
-            ident_or_index_expr(p);
+            params::gate_operand(p);
         }
         _ => {
             p.error("expecting name of qubit to reset");
@@ -261,25 +410,79 @@ fn reset_(p: &mut Parser<'_>, m: Marker) {
             return;
         }
     }
+    p.expect(T![;]);
     m.complete(p, RESET);
 }
 
-// FIXME: this has underscore, so should be private.
-// We should be able to refactor to keep this private.
-pub(crate) fn measure_(p: &mut Parser<'_>, m: Marker) {
-    p.bump(T![measure]);
-    match p.current() {
-        IDENT | HARDWAREIDENT => {
-            ident_or_index_expr(p);
-        }
-        _ => {
-            p.error("expecting qubit(s) to measure");
-            m.abandon(p);
-            return;
+// `qreg q[5];` or `creg c;` Here, unlike `qubit[5] q;`, the size follows the name.
+fn old_style_declaration_stmt(p: &mut Parser<'_>, m: Marker) {
+    p.bump_any(); // `qreg` or `creg`
+    expressions::var_name(p);
+    if p.at(T!['[']) {
+        expressions::designator(p);
+    }
+    p.expect(T![;]);
+    m.complete(p, OLD_STYLE_DECLARATION_STATEMENT);
+}
+
+// `input angle theta;` or `output array[bit, 4] result;`
+fn io_declaration_stmt(p: &mut Parser<'_>, m: Marker) {
+    p.bump_any(); // `input` or `output`
+    if p.at(T![array]) {
+        expressions::array_type_spec(p);
+    } else if p.current().is_scalar_type() {
+        expressions::type_spec(p);
+    } else {
+        p.error("expecting a classical type");
+    }
+    expressions::var_name(p);
+    p.expect(T![;]);
+    m.complete(p, I_O_DECLARATION_STATEMENT);
+}
+
+// `box[duration] { ... }` or `box { ... }`
+fn box_(p: &mut Parser<'_>, m: Marker) {
+    p.bump(T![box]);
+    if p.at(T!['[']) {
+        expressions::designator(p);
+    }
+    if p.at(T!['{']) {
+        expressions::atom::block_expr(p);
+    } else {
+        p.error("expected a block");
+    }
+    m.complete(p, BOX_STMT);
+}
+
+// `delay[duration] q0, q1;` or `delay[duration];`
+fn delay_(p: &mut Parser<'_>, m: Marker) {
+    p.bump(T![delay]);
+    if p.at(T!['[']) {
+        expressions::designator(p);
+    } else {
+        p.error("expecting duration of delay");
+    }
+    if !p.at(T![;]) {
+        params::arg_list_gate_call_qubits(p);
+    }
+    p.expect(T![;]);
+    m.complete(p, DELAY_STMT);
+}
+
+// `measure q;` or the older form `measure q -> c;`
+// The measure expression is a child of the statement.
+fn measure_(p: &mut Parser<'_>, m: Marker) {
+    expressions::atom::measure_expression(p);
+    if p.eat(T![->]) {
+        match p.current() {
+            IDENT => {
+                params::gate_operand(p);
+            }
+            _ => p.error("expecting target of measurement"),
         }
     }
     p.expect(T![;]);
-    m.complete(p, MEASURE);
+    m.complete(p, MEASURE_ARROW_ASSIGNMENT_STMT);
 }
 
 fn break_(p: &mut Parser<'_>, m: Marker) {
@@ -321,12 +524,31 @@ fn gate_definition(p: &mut Parser<'_>, m: Marker) {
     m.complete(p, GATE);
 }
 
+// OpenQASM 2 `opaque g(theta) a, b;` declares a gate with no body.
+// `opaque` is a contextual keyword, so that it may still be used as an identifier.
+fn opaque_gate_declaration(p: &mut Parser<'_>, m: Marker) {
+    p.bump_remap(T![opaque]);
+    name_r(p, ITEM_RECOVERY_SET);
+    if p.at(T!['(']) {
+        params::param_list_gate_params(p);
+    }
+    params::param_list_gate_qubits(p);
+    p.expect(T![;]);
+    m.complete(p, GATE);
+}
+
 fn defcal_(p: &mut Parser<'_>, m: Marker) {
-    // Must read the keyword `gate`
     p.bump(T![defcal]);
 
-    // Read the name of the gate. (This records an error message on failure.)
-    name_r(p, ITEM_RECOVERY_SET);
+    // Read the name of the gate. Calibrations may also be given for `measure` and `reset`.
+    // (This records an error message on failure.)
+    if p.at(T![measure]) || p.at(T![reset]) {
+        let name = p.start();
+        p.bump_remap(IDENT);
+        name.complete(p, NAME);
+    } else {
+        name_r(p, ITEM_RECOVERY_SET);
+    }
 
     // Read optional gate parameters (not qubit and params)
     if p.at(T!['(']) {
@@ -336,12 +558,35 @@ fn defcal_(p: &mut Parser<'_>, m: Marker) {
     params::param_list_defcal_qubits(p);
 
     opt_ret_type(p);
-    // Read the code block.
-    expressions::block_expr(p);
+    // Read the calibration block, which is not parsed as OpenQASM.
+    if !p.eat(T![;]) {
+        calibration_block(p);
+    }
     // Mark this attempt at reading an item as complete.
     m.complete(p, DEF_CAL);
 }
 
+// The body of `cal` and `defcal` is written in the calibration grammar, for example
+// OpenPulse, rather than in OpenQASM. We keep its tokens without parsing them. Nested
+// braces are only matched up, so that we can find the end of the block.
+fn calibration_block(p: &mut Parser<'_>) {
+    if !p.at(T!['{']) {
+        p.error("expected a calibration block");
+        return;
+    }
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if p.at(T!['{']) {
+            calibration_block(p);
+        } else {
+            p.bump_any();
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, CALIBRATION_BLOCK);
+}
+
 fn def_(p: &mut Parser<'_>, m: Marker) {
     p.bump(T![def]);
 
@@ -361,6 +606,20 @@ fn def_(p: &mut Parser<'_>, m: Marker) {
     m.complete(p, DEF);
 }
 
+// For example `extern f(int[32], float[64]) -> bit;`
+fn extern_(p: &mut Parser<'_>, m: Marker) {
+    p.bump(T![extern]);
+    name_r(p, ITEM_RECOVERY_SET);
+    if p.at(T!['(']) {
+        params::param_list_extern_params(p);
+    } else {
+        p.error("expected parameter types in extern");
+    }
+    opt_ret_type(p);
+    p.expect(SEMICOLON);
+    m.complete(p, EXTERN_ITEM);
+}
+
 fn filepath_r(p: &mut Parser<'_>, recovery: TokenSet) {
     if p.at(STRING) {
         let m = p.start();
@@ -390,7 +649,7 @@ fn include(p: &mut Parser<'_>, m: Marker) {
 // Should we implement recovery?
 fn cal_(p: &mut Parser<'_>, m: Marker) {
     p.bump(T![cal]);
-    expressions::block_expr(p);
+    calibration_block(p);
     m.complete(p, CAL);
 }
 
@@ -414,7 +673,7 @@ fn version_(p: &mut Parser<'_>) -> bool {
 fn barrier_(p: &mut Parser<'_>, m: Marker) {
     p.bump(T![barrier]);
     if !p.at(T![;]) {
-        params::param_list_gate_qubits(p);
+        params::arg_list_gate_call_qubits(p);
     }
     p.expect(SEMICOLON);
     m.complete(p, BARRIER);
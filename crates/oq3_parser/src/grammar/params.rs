@@ -41,6 +41,9 @@ pub(super) fn arg_list_gate_call_qubits(p: &mut Parser<'_>) {
 pub(super) fn param_list_def_params(p: &mut Parser<'_>) {
     _param_list_openqasm(p, DefFlavor::DefParams, None);
 }
+pub(super) fn param_list_extern_params(p: &mut Parser<'_>) {
+    _param_list_openqasm(p, DefFlavor::ExternParams, None);
+}
 pub(super) fn param_list_defcal_params(p: &mut Parser<'_>) {
     _param_list_openqasm(p, DefFlavor::DefCalParams, None);
 }
@@ -67,8 +70,9 @@ enum DefFlavor {
     GateQubits,     // no parens, no type, '{' terminates
     GateCallQubits, // no parens, no type, ';' terminates
     DefParams,      // parens,    type
-    DefCalParams,   // parens,    opt type
-    DefCalQubits,   // no parens, no type, '{' or '->' terminates
+    ExternParams,   // parens,    type, no name
+    DefCalParams,   // parens,    typed param or constant expression
+    DefCalQubits,   // no parens, no type, '{' or '->' terminates, hardware qubits allowed
     //    SetExpression,
     ExpressionList,
 }
@@ -78,9 +82,9 @@ enum DefFlavor {
 fn _param_list_openqasm(p: &mut Parser<'_>, flavor: DefFlavor, m: Option<Marker>) {
     use DefFlavor::*;
     let list_marker = p.start();
-    let want_parens = matches!(flavor, GateParams | DefParams | DefCalParams);
+    let want_parens = matches!(flavor, GateParams | DefParams | ExternParams | DefCalParams);
     match flavor {
-        GateParams | DefParams | DefCalParams => p.bump(T!['(']),
+        GateParams | DefParams | ExternParams | DefCalParams => p.bump(T!['(']),
         //        SetExpression => p.bump(T!['{']),
         _ => (),
     }
@@ -92,7 +96,7 @@ fn _param_list_openqasm(p: &mut Parser<'_>, flavor: DefFlavor, m: Option<Marker>
         // GateQubits => {TokenSet::new(&[T!['{']])},
         // DefCalQubits => {TokenSet::new(&[T!['{'], T![->]])},
         ExpressionList => [T![']'], T![']']],
-        GateParams | DefParams | DefCalParams => [T![')'], T![')']],
+        GateParams | DefParams | ExternParams | DefCalParams => [T![')'], T![')']],
         // When no parens are present `{` terminates the list of parameters.
         GateQubits => [T!['{'], T!['{']],
         GateCallQubits => [SEMICOLON, SEMICOLON],
@@ -106,7 +110,8 @@ fn _param_list_openqasm(p: &mut Parser<'_>, flavor: DefFlavor, m: Option<Marker>
     //  while !p.at(EOF) && !p.at_ts(list_end_tokens) {
     while !p.at(EOF) && !list_end_tokens.iter().any(|x| p.at(*x)) {
         let m = param_marker.take().unwrap_or_else(|| p.start());
-        if !(p.current().is_type_name() || p.at_ts(PARAM_FIRST)) {
+        let at_extern_creg = matches!(flavor, ExternParams) && p.at(T![creg]);
+        if !(p.current().is_type_name() || p.at_ts(PARAM_FIRST) || at_extern_creg) {
             p.error("expected value parameter");
             println!("!!!! Got {:?}", p.current());
             m.abandon(p);
@@ -120,7 +125,10 @@ fn _param_list_openqasm(p: &mut Parser<'_>, flavor: DefFlavor, m: Option<Marker>
             }
             //            SetExpression => { m.abandon(p); expressions::expr(p); true }
             GateCallQubits => arg_gate_call_qubit(p, m),
-            DefParams | DefCalParams => param_typed(p, m),
+            DefParams => param_def_typed(p, m),
+            ExternParams => param_extern_typed(p, m),
+            DefCalParams => param_defcal(p, m),
+            DefCalQubits => arg_gate_call_qubit(p, m),
             _ => param_untyped(p, m),
         };
         if !found_param {
@@ -169,6 +177,9 @@ fn _param_list_openqasm(p: &mut Parser<'_>, flavor: DefFlavor, m: Option<Marker>
         DefCalQubits => QUBIT_LIST,
         GateCallQubits => QUBIT_LIST,
         ExpressionList => EXPRESSION_LIST,
+        DefParams => TYPED_PARAM_LIST,
+        ExternParams => EXTERN_PARAM_LIST,
+        DefCalParams => DEF_CAL_PARAM_LIST,
         _ => PARAM_LIST,
     };
     list_marker.complete(p, kind);
@@ -198,6 +209,8 @@ const TYPE_FIRST: TokenSet = expressions::atom::PATH_FIRST.union(TokenSet::new(&
     T![&],
     T![_],
     T![extern],
+    T![readonly],
+    T![mutable],
 ]));
 
 const PARAM_FIRST: TokenSet = PATTERN_FIRST.union(TYPE_FIRST);
@@ -216,24 +229,82 @@ fn param_untyped(p: &mut Parser<'_>, m: Marker) -> bool {
     true
 }
 
-fn param_typed(p: &mut Parser<'_>, m: Marker) -> bool {
+// A parameter of a `defcal` is either typed, as in `angle[20] theta`, or a constant
+// argument, as in `pi / 2`, which restricts the calibration to that value.
+fn param_defcal(p: &mut Parser<'_>, m: Marker) -> bool {
+    if p.current().is_scalar_type() {
+        expressions::type_spec(p);
+        if !p.at(IDENT) {
+            p.error("expected parameter name");
+            m.abandon(p);
+            return false;
+        }
+        expressions::var_name(p);
+        m.complete(p, TYPED_PARAM);
+        return true;
+    }
+    m.abandon(p);
+    expressions::expr(p).is_some()
+}
+
+// Parameters of a subroutine definition. The type may include a designator, for
+// example `int[32] x` or `qubit[2] q`. Array parameters are references, for example
+// `readonly array[int[8], 2] a`.
+fn param_def_typed(p: &mut Parser<'_>, m: Marker) -> bool {
     let mut success = true;
-    if p.current().is_type_name() {
-        p.bump_any();
-    } else {
-        p.error("expected type annotation");
-        success = false;
+    match p.current() {
+        T![qubit] => {
+            expressions::quantum_type_spec(p);
+        }
+        T![readonly] | T![mutable] | T![array] => expressions::array_type_spec(p),
+        kind if kind.is_scalar_type() => {
+            expressions::type_spec(p);
+        }
+        _ => {
+            p.error("expected type annotation");
+            success = false;
+        }
     }
     if !p.at(IDENT) {
         p.error("expected parameter name");
         m.abandon(p);
         return false;
     }
-    p.bump(IDENT);
-    m.complete(p, PARAM);
+    expressions::var_name(p);
+    m.complete(p, TYPED_PARAM);
     success
 }
 
+// Parameters of an extern declaration are types only, for example `int[32]`,
+// `readonly array[int[8], 2]`, or old-style `creg[8]`.
+fn param_extern_typed(p: &mut Parser<'_>, m: Marker) -> bool {
+    match p.current() {
+        T![creg] => {
+            p.bump(T![creg]);
+            if p.at(T!['[']) {
+                expressions::designator(p);
+            }
+        }
+        T![readonly] | T![mutable] | T![array] => expressions::array_type_spec(p),
+        kind if kind.is_scalar_type() => {
+            expressions::type_spec(p);
+        }
+        _ => {
+            p.error("expected type annotation");
+            m.abandon(p);
+            return false;
+        }
+    }
+    m.complete(p, EXTERN_PARAM);
+    true
+}
+
+// A single gate operand, for example the operand of `measure`.
+pub(super) fn gate_operand(p: &mut Parser<'_>) -> bool {
+    let m = p.start();
+    arg_gate_call_qubit(p, m)
+}
+
 fn arg_gate_call_qubit(p: &mut Parser<'_>, m: Marker) -> bool {
     if p.at(HARDWAREIDENT) {
         p.bump(HARDWAREIDENT);
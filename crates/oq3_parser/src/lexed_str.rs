@@ -192,6 +192,15 @@ fn extend_literal_func(len: usize, kind: &oq3_lexer::LiteralKind) -> (&str, Synt
             TIMING_FLOAT_NUMBER
         }
         oq3_lexer::LiteralKind::SimpleFloat => SIMPLE_FLOAT_NUMBER,
+        oq3_lexer::LiteralKind::Imaginary { empty_digits, base } => {
+            if empty_digits {
+                err = "Missing digits in imaginary literal";
+            }
+            if base != oq3_lexer::Base::Decimal {
+                err = "Base of imaginary literal is not decimal";
+            }
+            IMAGINARY_NUMBER
+        }
         oq3_lexer::LiteralKind::Byte { terminated } => {
             if !terminated {
                 err = "Missing trailing `'` symbol to terminate the byte literal";
@@ -254,6 +263,9 @@ fn inner_extend_token<'a>(
                 SyntaxKind::from_keyword(token_text).unwrap_or(HARDWAREIDENT)
             }
 
+            oq3_lexer::TokenKind::Annotation => ANNOTATION_TEXT,
+            oq3_lexer::TokenKind::Pragma => PRAGMA_TEXT,
+
             oq3_lexer::TokenKind::InvalidIdent => {
                 err = "Ident contains invalid characters";
                 IDENT
@@ -86,6 +86,11 @@ impl<'t> Parser<'t> {
         self.nth_at(0, kind)
     }
 
+    /// Checks if the current token is contextual keyword `kw`.
+    pub(crate) fn at_contextual_kw(&self, kw: SyntaxKind) -> bool {
+        self.inp.contextual_kind(self.pos) == kw
+    }
+
     pub(crate) fn nth_at(&self, n: usize, kind: SyntaxKind) -> bool {
         match kind {
             T![-=] => self.at_composite2(n, T![-], T![=]),
@@ -94,6 +99,7 @@ impl<'t> Parser<'t> {
             T![!=] => self.at_composite2(n, T![!], T![=]),
             T![..] => self.at_composite2(n, T![.], T![.]),
             T![*=] => self.at_composite2(n, T![*], T![=]),
+            T![**] => self.at_composite2(n, T![*], T![*]),
             T![/=] => self.at_composite2(n, T![/], T![=]),
             T![&&] => self.at_composite2(n, T![&], T![&]),
             T![&=] => self.at_composite2(n, T![&], T![=]),
@@ -114,6 +120,7 @@ impl<'t> Parser<'t> {
             T![..=] => self.at_composite3(n, T![.], T![.], T![=]),
             T![<<=] => self.at_composite3(n, T![<], T![<], T![=]),
             T![>>=] => self.at_composite3(n, T![>], T![>], T![=]),
+            T![**=] => self.at_composite3(n, T![*], T![*], T![=]),
 
             _ => self.inp.kind(self.pos + n) == kind,
         }
@@ -132,6 +139,7 @@ impl<'t> Parser<'t> {
             | T![!=]
             | T![..]
             | T![*=]
+            | T![**]
             | T![/=]
             | T![&&]
             | T![&=]
@@ -148,7 +156,7 @@ impl<'t> Parser<'t> {
             | T![|=]
             | T![||] => 2,
 
-            T![...] | T![..=] | T![<<=] | T![>>=] => 3,
+            T![...] | T![..=] | T![<<=] | T![>>=] | T![**=] => 3,
             _ => 1,
         };
         self.do_bump(kind, n_raw_tokens);
@@ -229,11 +237,11 @@ impl<'t> Parser<'t> {
 
     /// Advances the parser by one token, remapping its kind.
     /// This is useful to create contextual keywords from
-    /// identifiers. For example, the lexer creates a `union`
+    /// identifiers. For example, the lexer creates an `opaque`
     /// *identifier* token, but the parser remaps it to the
-    /// `union` keyword, and keyword is what ends up in the
+    /// `opaque` keyword, and keyword is what ends up in the
     /// final tree.
-    pub(crate) fn _bump_remap(&mut self, kind: SyntaxKind) {
+    pub(crate) fn bump_remap(&mut self, kind: SyntaxKind) {
         if self.nth(0) == EOF {
             // FIXME: panic!?
             return;
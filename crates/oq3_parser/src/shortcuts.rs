@@ -37,7 +37,8 @@ impl LexedStr<'_> {
                 was_joint = false
             } else {
                 if kind == SyntaxKind::IDENT {
-                    let contextual_kw = SyntaxKind::IDENT;
+                    let contextual_kw =
+                        SyntaxKind::from_contextual_keyword(self.text(i)).unwrap_or(kind);
                     res.push_ident(contextual_kw);
                 } else {
                     if was_joint {
@@ -66,6 +66,8 @@ pub enum SyntaxKind {
     SHR,
     SHLEQ,
     SHREQ,
+    STAR2,
+    STAR2EQ,
     #[doc = r" all_keywords"]
     O_P_E_N_Q_A_S_M_KW,
     INCLUDE_KW,
@@ -84,12 +86,20 @@ pub enum SyntaxKind {
     EXTERN_KW,
     CONST_KW,
     BARRIER_KW,
+    DURATIONOF_KW,
     GPHASE_KW,
+    INV_KW,
+    POW_KW,
+    CTRL_KW,
+    NEGCTRL_KW,
     IF_KW,
     ELSE_KW,
     FOR_KW,
     IN_KW,
     WHILE_KW,
+    SWITCH_KW,
+    CASE_KW,
+    DEFAULT_KW,
     CONTINUE_KW,
     RETURN_KW,
     BREAK_KW,
@@ -104,6 +114,7 @@ pub enum SyntaxKind {
     ARRAY_KW,
     FALSE_KW,
     TRUE_KW,
+    OPAQUE_KW,
     #[doc = r" literals"]
     INT_NUMBER,
     FLOAT_NUMBER,
@@ -114,6 +125,7 @@ pub enum SyntaxKind {
     BIT_STRING,
     TIMING_FLOAT_NUMBER,
     TIMING_INT_NUMBER,
+    IMAGINARY_NUMBER,
     #[doc = r" scalar_types"]
     FLOAT_TY,
     INT_TY,
@@ -130,16 +142,21 @@ pub enum SyntaxKind {
     HARDWAREIDENT,
     WHITESPACE,
     COMMENT,
+    ANNOTATION_TEXT,
+    PRAGMA_TEXT,
     #[doc = r" nodes"]
     SOURCE_FILE,
     GATE,
     DEF_CAL,
+    DEF_CAL_PARAM_LIST,
+    CALIBRATION_BLOCK,
     CAL,
     DEF_CAL_GRAMMAR,
-    MEASURE,
+    MEASURE_ARROW_ASSIGNMENT_STMT,
     BARRIER,
     DEF,
     RESET,
+    DELAY_STMT,
     RET_TYPE,
     CONST,
     PAREN_TYPE,
@@ -151,7 +168,10 @@ pub enum SyntaxKind {
     PATH_EXPR,
     IF_STMT,
     WHILE_STMT,
+    SWITCH_CASE_STMT,
+    CASE_ITEM,
     FOR_STMT,
+    FOR_ITERABLE,
     END_STMT,
     CONTINUE_STMT,
     BREAK_STMT,
@@ -162,17 +182,22 @@ pub enum SyntaxKind {
     LET_STMT,
     ALIAS_EXPR,
     CONCATENATION_EXPR,
-    BOX_EXPR,
+    BOX_STMT,
+    DURATION_OF_EXPR,
     CALL_EXPR,
     CAST_EXPRESSION,
     GATE_CALL_STMT,
     G_PHASE_CALL_STMT,
+    MODIFIER_LIST,
+    GATE_MODIFIER,
     INDEX_EXPR,
     PREFIX_EXPR,
     RANGE_EXPR,
     BIN_EXPR,
     SET_NUM,
     EXTERN_ITEM,
+    EXTERN_PARAM_LIST,
+    EXTERN_PARAM,
     ITEM_LIST,
     PATH,
     PATH_SEGMENT,
@@ -180,6 +205,9 @@ pub enum SyntaxKind {
     NAME,
     NAME_REF,
     EXPR_STMT,
+    ANNOTATED_STMT,
+    ANNOTATION,
+    PRAGMA,
     TYPE_SPEC,
     TYPE_ARG,
     TYPE,
@@ -190,9 +218,11 @@ pub enum SyntaxKind {
     CONST_PARAM,
     CONST_ARG,
     PARAM_LIST,
+    TYPED_PARAM_LIST,
     QUBIT_LIST,
     FILE_PATH,
     PARAM,
+    TYPED_PARAM,
     ARG_LIST,
     GATE_ARG_LIST,
     VERSION,
@@ -203,6 +233,7 @@ pub enum SyntaxKind {
     SCALAR_TYPE,
     SCALAR_TYPE_NAME,
     ARRAY_TYPE,
+    DIM_EXPR,
     QUBIT_TYPE,
     EXPRESSION_LIST,
     RETURN_SIGNATURE,
@@ -250,12 +281,20 @@ impl SyntaxKind {
                 | EXTERN_KW
                 | CONST_KW
                 | BARRIER_KW
+                | DURATIONOF_KW
                 | GPHASE_KW
+                | INV_KW
+                | POW_KW
+                | CTRL_KW
+                | NEGCTRL_KW
                 | IF_KW
                 | ELSE_KW
                 | FOR_KW
                 | IN_KW
                 | WHILE_KW
+                | SWITCH_KW
+                | CASE_KW
+                | DEFAULT_KW
                 | CONTINUE_KW
                 | RETURN_KW
                 | BREAK_KW
@@ -270,6 +309,7 @@ impl SyntaxKind {
                 | ARRAY_KW
                 | FALSE_KW
                 | TRUE_KW
+                | OPAQUE_KW
         )
     }
     pub fn is_punct(self) -> bool {
@@ -328,6 +368,8 @@ impl SyntaxKind {
                 | SHR
                 | SHLEQ
                 | SHREQ
+                | STAR2
+                | STAR2EQ
         )
     }
     pub fn is_literal(self) -> bool {
@@ -342,6 +384,7 @@ impl SyntaxKind {
                 | BIT_STRING
                 | TIMING_FLOAT_NUMBER
                 | TIMING_INT_NUMBER
+                | IMAGINARY_NUMBER
         )
     }
     pub fn is_scalar_type(self) -> bool {
@@ -377,12 +420,20 @@ impl SyntaxKind {
             "extern" => EXTERN_KW,
             "const" => CONST_KW,
             "barrier" => BARRIER_KW,
+            "durationof" => DURATIONOF_KW,
             "gphase" => GPHASE_KW,
+            "inv" => INV_KW,
+            "pow" => POW_KW,
+            "ctrl" => CTRL_KW,
+            "negctrl" => NEGCTRL_KW,
             "if" => IF_KW,
             "else" => ELSE_KW,
             "for" => FOR_KW,
             "in" => IN_KW,
             "while" => WHILE_KW,
+            "switch" => SWITCH_KW,
+            "case" => CASE_KW,
+            "default" => DEFAULT_KW,
             "continue" => CONTINUE_KW,
             "return" => RETURN_KW,
             "break" => BREAK_KW,
@@ -401,6 +452,13 @@ impl SyntaxKind {
         };
         Some(kw)
     }
+    pub fn from_contextual_keyword(ident: &str) -> Option<SyntaxKind> {
+        let kw = match ident {
+            "opaque" => OPAQUE_KW,
+            _ => return None,
+        };
+        Some(kw)
+    }
     pub fn from_scalar_type(type_name: &str) -> Option<SyntaxKind> {
         let ty = match type_name {
             "float" => FLOAT_TY,
@@ -452,5 +510,5 @@ impl SyntaxKind {
     }
 }
 #[macro_export]
-macro_rules ! T { [++] => { $ crate :: SyntaxKind :: DOUBLE_PLUS } ; [;] => { $ crate :: SyntaxKind :: SEMICOLON } ; [,] => { $ crate :: SyntaxKind :: COMMA } ; ['('] => { $ crate :: SyntaxKind :: L_PAREN } ; [')'] => { $ crate :: SyntaxKind :: R_PAREN } ; ['{'] => { $ crate :: SyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: SyntaxKind :: R_CURLY } ; ['['] => { $ crate :: SyntaxKind :: L_BRACK } ; [']'] => { $ crate :: SyntaxKind :: R_BRACK } ; [<] => { $ crate :: SyntaxKind :: L_ANGLE } ; [>] => { $ crate :: SyntaxKind :: R_ANGLE } ; [@] => { $ crate :: SyntaxKind :: AT } ; [#] => { $ crate :: SyntaxKind :: POUND } ; [~] => { $ crate :: SyntaxKind :: TILDE } ; [?] => { $ crate :: SyntaxKind :: QUESTION } ; [$] => { $ crate :: SyntaxKind :: DOLLAR } ; [&] => { $ crate :: SyntaxKind :: AMP } ; [|] => { $ crate :: SyntaxKind :: PIPE } ; [+] => { $ crate :: SyntaxKind :: PLUS } ; [*] => { $ crate :: SyntaxKind :: STAR } ; [/] => { $ crate :: SyntaxKind :: SLASH } ; [^] => { $ crate :: SyntaxKind :: CARET } ; [%] => { $ crate :: SyntaxKind :: PERCENT } ; [_] => { $ crate :: SyntaxKind :: UNDERSCORE } ; [.] => { $ crate :: SyntaxKind :: DOT } ; [..] => { $ crate :: SyntaxKind :: DOT2 } ; [...] => { $ crate :: SyntaxKind :: DOT3 } ; [..=] => { $ crate :: SyntaxKind :: DOT2EQ } ; [:] => { $ crate :: SyntaxKind :: COLON } ; [::] => { $ crate :: SyntaxKind :: COLON2 } ; [=] => { $ crate :: SyntaxKind :: EQ } ; [==] => { $ crate :: SyntaxKind :: EQ2 } ; [=>] => { $ crate :: SyntaxKind :: FAT_ARROW } ; [!] => { $ crate :: SyntaxKind :: BANG } ; [!=] => { $ crate :: SyntaxKind :: NEQ } ; [-] => { $ crate :: SyntaxKind :: MINUS } ; [->] => { $ crate :: SyntaxKind :: THIN_ARROW } ; [<=] => { $ crate :: SyntaxKind :: LTEQ } ; [>=] => { $ crate :: SyntaxKind :: GTEQ } ; [+=] => { $ crate :: SyntaxKind :: PLUSEQ } ; [-=] => { $ crate :: SyntaxKind :: MINUSEQ } ; [|=] => { $ crate :: SyntaxKind :: PIPEEQ } ; [&=] => { $ crate :: SyntaxKind :: AMPEQ } ; [^=] => { $ crate :: SyntaxKind :: CARETEQ } ; [/=] => { $ crate :: SyntaxKind :: SLASHEQ } ; [*=] => { $ crate :: SyntaxKind :: STAREQ } ; [%=] => { $ crate :: SyntaxKind :: PERCENTEQ } ; [&&] => { $ crate :: SyntaxKind :: AMP2 } ; [||] => { $ crate :: SyntaxKind :: PIPE2 } ; [<<] => { $ crate :: SyntaxKind :: SHL } ; [>>] => { $ crate :: SyntaxKind :: SHR } ; [<<=] => { $ crate :: SyntaxKind :: SHLEQ } ; [>>=] => { $ crate :: SyntaxKind :: SHREQ } ; [OPENQASM] => { $ crate :: SyntaxKind :: O_P_E_N_Q_A_S_M_KW } ; [include] => { $ crate :: SyntaxKind :: INCLUDE_KW } ; [def] => { $ crate :: SyntaxKind :: DEF_KW } ; [defcalgrammar] => { $ crate :: SyntaxKind :: DEFCALGRAMMAR_KW } ; [cal] => { $ crate :: SyntaxKind :: CAL_KW } ; [defcal] => { $ crate :: SyntaxKind :: DEFCAL_KW } ; [gate] => { $ crate :: SyntaxKind :: GATE_KW } ; [delay] => { $ crate :: SyntaxKind :: DELAY_KW } ; [reset] => { $ crate :: SyntaxKind :: RESET_KW } ; [measure] => { $ crate :: SyntaxKind :: MEASURE_KW } ; [pragma] => { $ crate :: SyntaxKind :: PRAGMA_KW } ; [end] => { $ crate :: SyntaxKind :: END_KW } ; [let] => { $ crate :: SyntaxKind :: LET_KW } ; [box] => { $ crate :: SyntaxKind :: BOX_KW } ; [extern] => { $ crate :: SyntaxKind :: EXTERN_KW } ; [const] => { $ crate :: SyntaxKind :: CONST_KW } ; [barrier] => { $ crate :: SyntaxKind :: BARRIER_KW } ; [gphase] => { $ crate :: SyntaxKind :: GPHASE_KW } ; [if] => { $ crate :: SyntaxKind :: IF_KW } ; [else] => { $ crate :: SyntaxKind :: ELSE_KW } ; [for] => { $ crate :: SyntaxKind :: FOR_KW } ; [in] => { $ crate :: SyntaxKind :: IN_KW } ; [while] => { $ crate :: SyntaxKind :: WHILE_KW } ; [continue] => { $ crate :: SyntaxKind :: CONTINUE_KW } ; [return] => { $ crate :: SyntaxKind :: RETURN_KW } ; [break] => { $ crate :: SyntaxKind :: BREAK_KW } ; [input] => { $ crate :: SyntaxKind :: INPUT_KW } ; [output] => { $ crate :: SyntaxKind :: OUTPUT_KW } ; [readonly] => { $ crate :: SyntaxKind :: READONLY_KW } ; [mutable] => { $ crate :: SyntaxKind :: MUTABLE_KW } ; [qreg] => { $ crate :: SyntaxKind :: QREG_KW } ; [creg] => { $ crate :: SyntaxKind :: CREG_KW } ; [qubit] => { $ crate :: SyntaxKind :: QUBIT_KW } ; [void] => { $ crate :: SyntaxKind :: VOID_KW } ; [array] => { $ crate :: SyntaxKind :: ARRAY_KW } ; [false] => { $ crate :: SyntaxKind :: FALSE_KW } ; [true] => { $ crate :: SyntaxKind :: TRUE_KW } ; [float] => { $ crate :: SyntaxKind :: FLOAT_TY } ; [int] => { $ crate :: SyntaxKind :: INT_TY } ; [uint] => { $ crate :: SyntaxKind :: UINT_TY } ; [complex] => { $ crate :: SyntaxKind :: COMPLEX_TY } ; [bool] => { $ crate :: SyntaxKind :: BOOL_TY } ; [bit] => { $ crate :: SyntaxKind :: BIT_TY } ; [duration] => { $ crate :: SyntaxKind :: DURATION_TY } ; [stretch] => { $ crate :: SyntaxKind :: STRETCH_TY } ; [angle] => { $ crate :: SyntaxKind :: ANGLE_TY } ; [ident] => { $ crate :: SyntaxKind :: IDENT } ; }
+macro_rules ! T { [++] => { $ crate :: SyntaxKind :: DOUBLE_PLUS } ; [;] => { $ crate :: SyntaxKind :: SEMICOLON } ; [,] => { $ crate :: SyntaxKind :: COMMA } ; ['('] => { $ crate :: SyntaxKind :: L_PAREN } ; [')'] => { $ crate :: SyntaxKind :: R_PAREN } ; ['{'] => { $ crate :: SyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: SyntaxKind :: R_CURLY } ; ['['] => { $ crate :: SyntaxKind :: L_BRACK } ; [']'] => { $ crate :: SyntaxKind :: R_BRACK } ; [<] => { $ crate :: SyntaxKind :: L_ANGLE } ; [>] => { $ crate :: SyntaxKind :: R_ANGLE } ; [@] => { $ crate :: SyntaxKind :: AT } ; [#] => { $ crate :: SyntaxKind :: POUND } ; [~] => { $ crate :: SyntaxKind :: TILDE } ; [?] => { $ crate :: SyntaxKind :: QUESTION } ; [$] => { $ crate :: SyntaxKind :: DOLLAR } ; [&] => { $ crate :: SyntaxKind :: AMP } ; [|] => { $ crate :: SyntaxKind :: PIPE } ; [+] => { $ crate :: SyntaxKind :: PLUS } ; [*] => { $ crate :: SyntaxKind :: STAR } ; [/] => { $ crate :: SyntaxKind :: SLASH } ; [^] => { $ crate :: SyntaxKind :: CARET } ; [%] => { $ crate :: SyntaxKind :: PERCENT } ; [_] => { $ crate :: SyntaxKind :: UNDERSCORE } ; [.] => { $ crate :: SyntaxKind :: DOT } ; [..] => { $ crate :: SyntaxKind :: DOT2 } ; [...] => { $ crate :: SyntaxKind :: DOT3 } ; [..=] => { $ crate :: SyntaxKind :: DOT2EQ } ; [:] => { $ crate :: SyntaxKind :: COLON } ; [::] => { $ crate :: SyntaxKind :: COLON2 } ; [=] => { $ crate :: SyntaxKind :: EQ } ; [==] => { $ crate :: SyntaxKind :: EQ2 } ; [=>] => { $ crate :: SyntaxKind :: FAT_ARROW } ; [!] => { $ crate :: SyntaxKind :: BANG } ; [!=] => { $ crate :: SyntaxKind :: NEQ } ; [-] => { $ crate :: SyntaxKind :: MINUS } ; [->] => { $ crate :: SyntaxKind :: THIN_ARROW } ; [<=] => { $ crate :: SyntaxKind :: LTEQ } ; [>=] => { $ crate :: SyntaxKind :: GTEQ } ; [+=] => { $ crate :: SyntaxKind :: PLUSEQ } ; [-=] => { $ crate :: SyntaxKind :: MINUSEQ } ; [|=] => { $ crate :: SyntaxKind :: PIPEEQ } ; [&=] => { $ crate :: SyntaxKind :: AMPEQ } ; [^=] => { $ crate :: SyntaxKind :: CARETEQ } ; [/=] => { $ crate :: SyntaxKind :: SLASHEQ } ; [*=] => { $ crate :: SyntaxKind :: STAREQ } ; [%=] => { $ crate :: SyntaxKind :: PERCENTEQ } ; [&&] => { $ crate :: SyntaxKind :: AMP2 } ; [||] => { $ crate :: SyntaxKind :: PIPE2 } ; [<<] => { $ crate :: SyntaxKind :: SHL } ; [>>] => { $ crate :: SyntaxKind :: SHR } ; [<<=] => { $ crate :: SyntaxKind :: SHLEQ } ; [>>=] => { $ crate :: SyntaxKind :: SHREQ } ; [**] => { $ crate :: SyntaxKind :: STAR2 } ; [**=] => { $ crate :: SyntaxKind :: STAR2EQ } ; [OPENQASM] => { $ crate :: SyntaxKind :: O_P_E_N_Q_A_S_M_KW } ; [include] => { $ crate :: SyntaxKind :: INCLUDE_KW } ; [def] => { $ crate :: SyntaxKind :: DEF_KW } ; [defcalgrammar] => { $ crate :: SyntaxKind :: DEFCALGRAMMAR_KW } ; [cal] => { $ crate :: SyntaxKind :: CAL_KW } ; [defcal] => { $ crate :: SyntaxKind :: DEFCAL_KW } ; [gate] => { $ crate :: SyntaxKind :: GATE_KW } ; [delay] => { $ crate :: SyntaxKind :: DELAY_KW } ; [reset] => { $ crate :: SyntaxKind :: RESET_KW } ; [measure] => { $ crate :: SyntaxKind :: MEASURE_KW } ; [pragma] => { $ crate :: SyntaxKind :: PRAGMA_KW } ; [end] => { $ crate :: SyntaxKind :: END_KW } ; [let] => { $ crate :: SyntaxKind :: LET_KW } ; [box] => { $ crate :: SyntaxKind :: BOX_KW } ; [extern] => { $ crate :: SyntaxKind :: EXTERN_KW } ; [const] => { $ crate :: SyntaxKind :: CONST_KW } ; [barrier] => { $ crate :: SyntaxKind :: BARRIER_KW } ; [durationof] => { $ crate :: SyntaxKind :: DURATIONOF_KW } ; [gphase] => { $ crate :: SyntaxKind :: GPHASE_KW } ; [inv] => { $ crate :: SyntaxKind :: INV_KW } ; [pow] => { $ crate :: SyntaxKind :: POW_KW } ; [ctrl] => { $ crate :: SyntaxKind :: CTRL_KW } ; [negctrl] => { $ crate :: SyntaxKind :: NEGCTRL_KW } ; [if] => { $ crate :: SyntaxKind :: IF_KW } ; [else] => { $ crate :: SyntaxKind :: ELSE_KW } ; [for] => { $ crate :: SyntaxKind :: FOR_KW } ; [in] => { $ crate :: SyntaxKind :: IN_KW } ; [while] => { $ crate :: SyntaxKind :: WHILE_KW } ; [switch] => { $ crate :: SyntaxKind :: SWITCH_KW } ; [case] => { $ crate :: SyntaxKind :: CASE_KW } ; [default] => { $ crate :: SyntaxKind :: DEFAULT_KW } ; [continue] => { $ crate :: SyntaxKind :: CONTINUE_KW } ; [return] => { $ crate :: SyntaxKind :: RETURN_KW } ; [break] => { $ crate :: SyntaxKind :: BREAK_KW } ; [input] => { $ crate :: SyntaxKind :: INPUT_KW } ; [output] => { $ crate :: SyntaxKind :: OUTPUT_KW } ; [readonly] => { $ crate :: SyntaxKind :: READONLY_KW } ; [mutable] => { $ crate :: SyntaxKind :: MUTABLE_KW } ; [qreg] => { $ crate :: SyntaxKind :: QREG_KW } ; [creg] => { $ crate :: SyntaxKind :: CREG_KW } ; [qubit] => { $ crate :: SyntaxKind :: QUBIT_KW } ; [void] => { $ crate :: SyntaxKind :: VOID_KW } ; [array] => { $ crate :: SyntaxKind :: ARRAY_KW } ; [false] => { $ crate :: SyntaxKind :: FALSE_KW } ; [true] => { $ crate :: SyntaxKind :: TRUE_KW } ; [opaque] => { $ crate :: SyntaxKind :: OPAQUE_KW } ; [float] => { $ crate :: SyntaxKind :: FLOAT_TY } ; [int] => { $ crate :: SyntaxKind :: INT_TY } ; [uint] => { $ crate :: SyntaxKind :: UINT_TY } ; [complex] => { $ crate :: SyntaxKind :: COMPLEX_TY } ; [bool] => { $ crate :: SyntaxKind :: BOOL_TY } ; [bit] => { $ crate :: SyntaxKind :: BIT_TY } ; [duration] => { $ crate :: SyntaxKind :: DURATION_TY } ; [stretch] => { $ crate :: SyntaxKind :: STRETCH_TY } ; [angle] => { $ crate :: SyntaxKind :: ANGLE_TY } ; [ident] => { $ crate :: SyntaxKind :: IDENT } ; }
 pub use T;
@@ -9,17 +9,79 @@
 // Because, although this ASG can assume synactic correctness, we need to track
 // semantic errors and continue to build the semantic ASG.
 
-use crate::symbols::SymbolIdResult; // SymbolIdResult = Result<SymbolId, SymbolError>
+use crate::builtins::BuiltinFunction;
+use crate::const_eval::{eval_const, ConstValue, ConstValues};
+use crate::span::{FileId, Span};
+use crate::symbols::{SymbolIdResult, SymbolTable}; // SymbolIdResult = Result<SymbolId, SymbolError>
 use crate::types;
-use crate::types::{ArrayDims, IsConst, Type};
+use crate::types::{ArrayDims, IOType, IsConst, Type};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+pub mod visit;
+
+/// The version of the serialized form of the ASG, the symbol table, and the semantic errors.
+/// It is written as the `schema_version` field of a `Versioned` value. See the crate
+/// documentation for the schema.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A value, such as a `Program`, serialized together with `SCHEMA_VERSION`. To serialize
+/// without copying, wrap a reference. Deserializing fails if the schema version is not
+/// `SCHEMA_VERSION`.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Versioned<T> {
+    #[serde(deserialize_with = "deserialize_schema_version")]
+    schema_version: u32,
+    value: T,
+}
+
+#[cfg(feature = "serde")]
+impl<T> Versioned<T> {
+    pub fn new(value: T) -> Versioned<T> {
+        Versioned {
+            schema_version: SCHEMA_VERSION,
+            value,
+        }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_schema_version<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let schema_version = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+    if schema_version != SCHEMA_VERSION {
+        return Err(serde::de::Error::custom(format!(
+            "unsupported schema version {schema_version}, expected {SCHEMA_VERSION}"
+        )));
+    }
+    Ok(schema_version)
+}
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Program {
     pub version: Option<OpenQASMVersion>,
+    pub calibration_grammar: Option<String>,
     pub stmts: Vec<Stmt>,
+    // Spans of the statements in `stmts`, in the same order.
+    stmt_spans: Vec<Option<Span>>,
+    // Paths of the source files, indexed by `FileId`.
+    file_paths: Vec<PathBuf>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OpenQASMVersion {
     major: usize,
     minor: usize,
@@ -43,7 +105,10 @@ impl Program {
     pub fn new() -> Program {
         Program {
             version: None,
+            calibration_grammar: None,
             stmts: Vec::<Stmt>::new(),
+            stmt_spans: Vec::new(),
+            file_paths: Vec::new(),
         }
     }
 
@@ -52,7 +117,32 @@ impl Program {
     }
 
     pub fn insert_stmt(&mut self, stmt: Stmt) {
-        let _ = &self.stmts.push(stmt);
+        // `stmts` is public, so statements may have been pushed without spans.
+        self.stmt_spans.resize(self.stmts.len(), None);
+        self.stmts.push(stmt);
+        self.stmt_spans.push(None);
+    }
+
+    /// Insert `stmt`, recording `span`, the location of its source text.
+    pub fn insert_stmt_with_span(&mut self, stmt: Stmt, span: Span) {
+        self.insert_stmt(stmt);
+        self.stmt_spans[self.stmts.len() - 1] = Some(span);
+    }
+
+    /// The span of the statement at `index` in `stmts`, if it was recorded.
+    pub fn stmt_span(&self, index: usize) -> Option<&Span> {
+        self.stmt_spans.get(index).and_then(Option::as_ref)
+    }
+
+    /// Record the path of a source file, and return the `FileId` of the file.
+    pub fn add_file_path<T: AsRef<Path>>(&mut self, file_path: T) -> FileId {
+        self.file_paths.push(file_path.as_ref().to_path_buf());
+        FileId::new((self.file_paths.len() - 1) as u32)
+    }
+
+    /// The path of the source file identified by `file_id`.
+    pub fn file_path(&self, file_id: FileId) -> Option<&PathBuf> {
+        self.file_paths.get(usize::from(file_id))
     }
 
     // The check should be done when checking syntax.
@@ -70,6 +160,129 @@ impl Program {
         &self.version
     }
 
+    /// The grammar of calibration blocks declared with `defcalgrammar`, for example `openpulse`.
+    pub fn calibration_grammar(&self) -> Option<&str> {
+        self.calibration_grammar.as_deref()
+    }
+
+    pub fn set_calibration_grammar<T: ToString>(&mut self, grammar: T) {
+        self.calibration_grammar = Some(grammar.to_string());
+    }
+
+    /// Return the `defcal` declarations in the order in which they appear.
+    pub fn defcals(&self) -> Vec<&DefCal> {
+        // Calibrations are only allowed in the global scope.
+        self.unannotated_stmts()
+            .filter_map(|stmt| match stmt {
+                Stmt::DefCal(defcal) => Some(defcal),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Return the `defcal` for the gate `name` with arguments `args` on the hardware qubits
+    /// `qubits`. `args` holds the value of each argument that is a constant expression, and
+    /// `None` for the others. A constant parameter, as in `defcal rx(pi / 2) $0`, matches only
+    /// an argument with the same value. A typed parameter matches any argument.
+    /// `const_values` holds the values of constant symbols, such as `pi`.
+    /// A `defcal` on hardware qubits is preferred over one with identifiers as operands, and
+    /// then one with more constant parameters is preferred. Among equally specific candidates,
+    /// the last one declared wins.
+    pub fn find_defcal(
+        &self,
+        name: &str,
+        args: &[Option<ConstValue>],
+        qubits: &[usize],
+        const_values: &ConstValues,
+    ) -> Option<&DefCal> {
+        self.defcals()
+            .into_iter()
+            .filter(|defcal| {
+                defcal.name() == name
+                    && defcal.params().len() == args.len()
+                    && defcal
+                        .params()
+                        .iter()
+                        .zip(args)
+                        .all(|(param, arg)| param.matches(arg.as_ref(), const_values))
+                    && defcal.qubits().len() == qubits.len()
+                    && defcal
+                        .qubits()
+                        .iter()
+                        .zip(qubits)
+                        .all(|(operand, qubit)| operand.matches(*qubit))
+            })
+            .max_by_key(|defcal| (defcal.num_hardware_qubits(), defcal.num_constant_params()))
+    }
+
+    /// Return the `defcal` that implements `gate_call`, if all of its operands are
+    /// hardware qubits and a matching `defcal` exists.
+    pub fn find_defcal_for_gate_call(
+        &self,
+        gate_call: &GateCall,
+        symbol_table: &SymbolTable,
+        const_values: &ConstValues,
+    ) -> Option<&DefCal> {
+        let name = symbol_table[gate_call.name().as_ref().ok()?].name();
+        let qubits = gate_call
+            .qubits()
+            .iter()
+            .map(|qubit| match qubit.expression() {
+                Expr::GateOperand(GateOperand::HardwareQubit(hwq)) => hwq.index(),
+                Expr::HardwareQubit(hwq) => hwq.index(),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let args = gate_call.params().as_ref().map_or_else(Vec::new, |params| {
+            params
+                .iter()
+                .map(|param| eval_const(param, const_values))
+                .collect()
+        });
+        self.find_defcal(name, &args, &qubits, const_values)
+    }
+
+    /// Return the declarations of `input` variables in the order in which they appear.
+    pub fn inputs(&self) -> Vec<&IODeclaration> {
+        self.io_declarations(IOType::Input)
+    }
+
+    /// Return the declarations of `output` variables in the order in which they appear.
+    pub fn outputs(&self) -> Vec<&IODeclaration> {
+        self.io_declarations(IOType::Output)
+    }
+
+    // I/O declarations are only allowed in the global scope, so we need not
+    // search nested blocks.
+    fn io_declarations(&self, io_type: IOType) -> Vec<&IODeclaration> {
+        self.unannotated_stmts()
+            .filter_map(|stmt| match stmt {
+                Stmt::IODeclaration(io_decl) if io_decl.io_type() == &io_type => Some(io_decl),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Return the `extern` declarations in the order in which they appear.
+    pub fn externs(&self) -> Vec<&Extern> {
+        // Externs are only allowed in the global scope.
+        self.unannotated_stmts()
+            .filter_map(|stmt| match stmt {
+                Stmt::Extern(extern_decl) => Some(extern_decl),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // The statements in the global scope, with an annotated statement replaced by the
+    // statement that it annotates.
+    fn unannotated_stmts(&self) -> impl Iterator<Item = &Stmt> {
+        self.stmts.iter().map(|stmt| match stmt {
+            Stmt::AnnotatedStmt(annotated) => annotated.statement(),
+            stmt => stmt,
+        })
+    }
+
     // FIXME: must exist idiomatic rust for managing these modes
     /// Print the ASG using the pretty print `Debug` trait.
     pub fn print_asg_debug_pretty(&self) {
@@ -114,6 +327,7 @@ impl std::ops::Deref for Program {
 //
 // Note the variant Ident(Ident)
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Expr {
     ArraySlice(ArraySlice),
     BinaryExpr(BinaryExpr),
@@ -129,9 +343,13 @@ pub enum Expr {
     // But we need to handle these in a consistent way. Is there any situation where the "type" of Range is meaningful or useful?
     // For example, in syntax_to_semantics, have a routine that handles out-of-tree expressions.
     Range(Range),
-    Call,    // stub function (def) call
-    Set,     // stub
-    Measure, // stub
+    Call(Call),
+    BuiltinCall(BuiltinCall),
+    SizeOf(SizeOf),
+    Set, // stub
+    Measure(MeasureExpression),
+    DurationOf(DurationOf),
+    Concatenation(Concatenation),
 }
 
 /// Typed expression implemented by tagging an `Expr` with a `Type`.
@@ -145,15 +363,31 @@ pub enum Expr {
 /// (or more precisely, the class of representable invalid programs is much smaller.)
 /// But that would increase complexity. The link above and several like it discuss this problem.
 /// Here is a link that sketches several solutions in Rust: https://lukasatkinson.de/dump/2023-09-02-ast-phases/
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// A `TExpr` lowered from source also records the span of its source text. The span is
+/// ignored when comparing and hashing expressions. So, for example, two occurrences of `x + 1`
+/// are equal.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TExpr {
     expression: Expr,
     ty: Type,
+    span: Option<Span>,
 }
 
 impl TExpr {
     pub fn new(expression: Expr, ty: Type) -> TExpr {
-        TExpr { expression, ty }
+        TExpr {
+            expression,
+            ty,
+            span: None,
+        }
+    }
+
+    /// Return `self` with the span of its source text set to `span`.
+    pub fn with_span(mut self, span: Span) -> TExpr {
+        self.span = Some(span);
+        self
     }
 
     pub fn get_type(&self) -> &Type {
@@ -163,43 +397,64 @@ impl TExpr {
     pub fn expression(&self) -> &Expr {
         &(self.expression)
     }
+
+    pub fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl PartialEq for TExpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.expression == other.expression && self.ty == other.ty
+    }
+}
+
+impl Eq for TExpr {}
+
+impl Hash for TExpr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.expression.hash(state);
+        self.ty.hash(state);
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Stmt {
-    Alias, // stub
+    Alias(Alias),
     AnnotatedStmt(AnnotatedStmt),
     Assignment(Assignment),
-    Barrier, // stub
+    Barrier(Barrier),
     Block(Block),
-    Box, // stub
+    Box(BoxStmt),
     Break,
-    Cal, // stub
+    Cal(Cal),
     Continue,
     DeclareClassical(DeclareClassical),
-    Def,    // stub
-    DefCal, // stub
-    Delay,  // stub
+    Def(Def),
+    DefCal(DefCal),
+    Delay(Delay),
     End,
     ExprStmt(TExpr),
-    Extern, // stub
-    For,    // stub
+    Extern(Extern),
+    For(For),
     GateDeclaration(GateDeclaration),
     GateCall(GateCall), // A statement because a gate call does not return anything
     GPhaseCall(GPhaseCall),
-    IODeclaration, // stub
+    IODeclaration(IODeclaration),
     If(If),
     Include(Include),
-    NullStmt,            // for testing
-    OldStyleDeclaration, // stub
+    NullStmt, // for testing
     Pragma(Pragma),
     DeclareQuantum(DeclareQuantum),
-    Reset,  // stub
-    Return, // stub
+    Reset(Reset),
+    Return(Return),
+    Switch(SwitchCaseStmt),
     While(While),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Include {
     file_path: String,
 }
@@ -221,6 +476,7 @@ impl Include {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Annotation {
     kind: String,
     body: Option<String>,
@@ -245,6 +501,7 @@ impl Annotation {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnnotatedStmt {
     stmt: Box<Stmt>,
     annotations: Vec<Annotation>,
@@ -271,9 +528,14 @@ impl AnnotatedStmt {
     pub fn annotations(&self) -> &Vec<Annotation> {
         &self.annotations
     }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::AnnotatedStmt(self)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndexExpression {
     expr: Box<TExpr>,
     index: IndexOperator,
@@ -290,9 +552,18 @@ impl IndexExpression {
     pub fn to_texpr(self) -> TExpr {
         TExpr::new(Expr::IndexExpression(self), Type::ToDo)
     }
+
+    pub fn expr(&self) -> &TExpr {
+        &self.expr
+    }
+
+    pub fn index(&self) -> &IndexOperator {
+        &self.index
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndexedIdentifier {
     identifier: SymbolIdResult,
     indexes: Vec<IndexOperator>,
@@ -306,8 +577,8 @@ impl IndexedIdentifier {
         }
     }
 
-    pub fn to_texpr(self) -> TExpr {
-        TExpr::new(Expr::IndexedIdentifier(self), Type::ToDo)
+    pub fn to_texpr(self, typ: Type) -> TExpr {
+        TExpr::new(Expr::IndexedIdentifier(self), typ)
     }
 
     pub fn identifier(&self) -> &SymbolIdResult {
@@ -320,6 +591,7 @@ impl IndexedIdentifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IndexOperator {
     SetExpression(SetExpression),
     ExpressionList(ExpressionList),
@@ -327,12 +599,14 @@ pub enum IndexOperator {
 
 // FIXME: probably want an interface on this.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpressionList {
     pub expressions: Vec<TExpr>,
 }
 
 // The rules regarding hardware qubits are not clear
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HardwareQubit {
     identifier: String,
 }
@@ -344,6 +618,15 @@ impl HardwareQubit {
         }
     }
 
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    /// The number of the physical qubit, for example `3` for `$3`.
+    pub fn index(&self) -> Option<usize> {
+        self.identifier.strip_prefix('$')?.parse().ok()
+    }
+
     pub fn to_texpr(self) -> TExpr {
         TExpr::new(Expr::HardwareQubit(self), Type::HardwareQubit)
     }
@@ -356,29 +639,57 @@ impl ExpressionList {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LValue {
     Identifier(SymbolIdResult),
+    IndexedIdentifier(IndexedIdentifier),
     ArraySlice(ArraySlice),
     RegisterSlice(RegisterSlice),
 }
 
 // For example `expr` in `v[expr]`, or `1:3` in `v[1:3]`
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArraySliceIndex {
     Expr(TExpr),
     Range(TExpr),
 }
 
-// FIXME: Is this the slice, or just the index ???
 // Same form as ArraySliceIndex, but they have different semantics.
+// For example `1` in `c[1]`, `0:3` in `c[0:3]`, or `{0, 2}` in `c[{0, 2}]`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub enum RegisterSlice {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RegisterIndex {
     Expr(TExpr),
     Range(Range),
+    Set(SetExpression),
+}
+
+// example: c[0:3], where `c` is a bit or qubit register.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegisterSlice {
+    name: SymbolIdResult,
+    index: RegisterIndex,
+}
+
+impl RegisterSlice {
+    pub fn new(name: SymbolIdResult, index: RegisterIndex) -> RegisterSlice {
+        RegisterSlice { name, index }
+    }
+
+    pub fn name(&self) -> &SymbolIdResult {
+        &self.name
+    }
+
+    pub fn index(&self) -> &RegisterIndex {
+        &self.index
+    }
 }
 
 // example: v[3:4]. Includes multidimensional index
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArraySlice {
     name: SymbolIdResult,
     pub indices: Vec<ArraySliceIndex>,
@@ -389,12 +700,21 @@ impl ArraySlice {
         ArraySlice { name, indices }
     }
 
+    pub fn name(&self) -> &SymbolIdResult {
+        &self.name
+    }
+
+    pub fn indices(&self) -> &[ArraySliceIndex] {
+        &self.indices
+    }
+
     pub fn to_texpr(self, base_type: Type) -> TExpr {
         TExpr::new(Expr::ArraySlice(self), base_type)
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetExpression {
     expressions: Vec<TExpr>,
 }
@@ -409,15 +729,25 @@ impl SetExpression {
     }
 }
 
+// `=`, or a compound assignment operator. For example, `+=` is `Compound(ArithOp::Add)`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssignOp {
+    Assign,
+    Compound(ArithOp),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Assignment {
     lvalue: LValue,
+    op: AssignOp,
     rvalue: TExpr,
 }
 
 impl Assignment {
-    pub fn new(lvalue: LValue, rvalue: TExpr) -> Assignment {
-        Assignment { lvalue, rvalue }
+    pub fn new(lvalue: LValue, op: AssignOp, rvalue: TExpr) -> Assignment {
+        Assignment { lvalue, op, rvalue }
     }
 
     pub fn to_stmt(self) -> Stmt {
@@ -428,12 +758,17 @@ impl Assignment {
         &self.lvalue
     }
 
+    pub fn op(&self) -> &AssignOp {
+        &self.op
+    }
+
     pub fn rvalue(&self) -> &TExpr {
         &self.rvalue
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeclareClassical {
     name: SymbolIdResult, // The name and type can be retrieved from SymbolId
     initializer: Option<Box<TExpr>>,
@@ -460,7 +795,90 @@ impl DeclareClassical {
     }
 }
 
+// The name and type are stored here as well as in the symbol table so that
+// a `Program`'s inputs and outputs can be inspected without the symbol table.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IODeclaration {
+    name: String,
+    typ: Type,
+    symbol: SymbolIdResult,
+    io_type: IOType,
+}
+
+impl IODeclaration {
+    pub fn new<T: ToString>(
+        name: T,
+        typ: Type,
+        symbol: SymbolIdResult,
+        io_type: IOType,
+    ) -> IODeclaration {
+        IODeclaration {
+            name: name.to_string(),
+            typ,
+            symbol,
+            io_type,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_type(&self) -> &Type {
+        &self.typ
+    }
+
+    pub fn symbol(&self) -> &SymbolIdResult {
+        &self.symbol
+    }
+
+    pub fn io_type(&self) -> &IOType {
+        &self.io_type
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::IODeclaration(self)
+    }
+}
+
+// `let name = rvalue;` The type of `name` is the type of `rvalue`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alias {
+    name: SymbolIdResult,
+    rvalue: TExpr,
+}
+
+impl Alias {
+    pub fn new(name: SymbolIdResult, rvalue: TExpr) -> Alias {
+        Alias { name, rvalue }
+    }
+
+    pub fn name(&self) -> &SymbolIdResult {
+        &self.name
+    }
+
+    pub fn rvalue(&self) -> &TExpr {
+        &self.rvalue
+    }
+
+    /// Return the registers, slices of registers, and single qubits or bits that
+    /// are aliased, in order. Each of these refers to the symbol of its declaration.
+    pub fn operands(&self) -> &[TExpr] {
+        match self.rvalue.expression() {
+            Expr::Concatenation(concatenation) => concatenation.operands(),
+            _ => std::slice::from_ref(&self.rvalue),
+        }
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Alias(self)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeclareQuantum {
     name: SymbolIdResult,
 }
@@ -475,25 +893,57 @@ impl DeclareQuantum {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// A sequence of statements. As for `TExpr`, the spans of the statements are ignored
+/// when comparing and hashing blocks.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Block {
     statements: Vec<Stmt>,
+    // Spans of the statements in `statements`, in the same order.
+    stmt_spans: Vec<Option<Span>>,
 }
 
 impl Block {
     pub fn new() -> Block {
         Block {
             statements: Vec::<Stmt>::new(),
+            stmt_spans: Vec::new(),
         }
     }
 
     pub fn insert_stmt(&mut self, stmt: Stmt) {
         self.statements.push(stmt);
+        self.stmt_spans.push(None);
+    }
+
+    /// Insert `stmt`, recording `span`, the location of its source text.
+    pub fn insert_stmt_with_span(&mut self, stmt: Stmt, span: Span) {
+        self.statements.push(stmt);
+        self.stmt_spans.push(Some(span));
     }
 
     pub fn statements(&self) -> &Vec<Stmt> {
         &self.statements
     }
+
+    /// The span of the statement at `index` in `statements`, if it was recorded.
+    pub fn stmt_span(&self, index: usize) -> Option<&Span> {
+        self.stmt_spans.get(index).and_then(Option::as_ref)
+    }
+}
+
+impl PartialEq for Block {
+    fn eq(&self, other: &Self) -> bool {
+        self.statements == other.statements
+    }
+}
+
+impl Eq for Block {}
+
+impl Hash for Block {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.statements.hash(state);
+    }
 }
 
 impl Default for Block {
@@ -511,11 +961,13 @@ impl Default for Block {
 // }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GateDeclaration {
     name: SymbolIdResult,
     params: Option<Vec<SymbolIdResult>>,
     qubits: Vec<SymbolIdResult>,
     block: Block,
+    is_opaque: bool,
 }
 
 impl GateDeclaration {
@@ -530,6 +982,19 @@ impl GateDeclaration {
             params,
             qubits,
             block,
+            is_opaque: false,
+        }
+    }
+
+    /// An OpenQASM 2 `opaque` gate, which is declared without a body.
+    pub fn new_opaque(
+        name: SymbolIdResult,
+        params: Option<Vec<SymbolIdResult>>,
+        qubits: Vec<SymbolIdResult>,
+    ) -> GateDeclaration {
+        GateDeclaration {
+            is_opaque: true,
+            ..GateDeclaration::new(name, params, qubits, Block::new())
         }
     }
 
@@ -560,134 +1025,660 @@ impl GateDeclaration {
     pub fn block(&self) -> &Block {
         &self.block
     }
-}
-
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct GateCall {
-    name: SymbolIdResult,
-    params: Option<Vec<TExpr>>,
-    qubits: Vec<TExpr>,
-    modifier: Option<GateModifier>,
-}
-
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub enum GateModifier {
-    Inv,
-    Pow(TExpr),
-    Ctrl(Option<TExpr>),
-    NegCtrl(Option<TExpr>),
-}
 
-// Following naming in ref parser instead
-// We ~~will~~ should try to use the distinction between "parameter", which appears in the signature,
-// and "argument", which appears in the call expression.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub enum QubitArgument {
-    Identifier(SymbolIdResult),
-    ArraySlice(ArraySlice),
+    /// Return `true` if the gate was declared `opaque`.
+    pub fn is_opaque(&self) -> bool {
+        self.is_opaque
+    }
 }
 
+// Subroutine definition. The signature, including the return type, is also
+// recorded in the type of the symbol `name`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub enum GateOperand {
-    Identifier(Identifier),
-    HardwareQubit(HardwareQubit),
-    IndexedIdentifier(IndexedIdentifier),
-}
-
-impl GateOperand {
-    pub fn to_texpr(self, typ: Type) -> TExpr {
-        TExpr::new(Expr::GateOperand(self), typ)
-    }
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Def {
+    name: SymbolIdResult,
+    params: Vec<SymbolIdResult>,
+    block: Block,
+    return_type: Option<Type>,
 }
 
-impl GateCall {
+impl Def {
     pub fn new(
         name: SymbolIdResult,
-        params: Option<Vec<TExpr>>,
-        qubits: Vec<TExpr>,
-        modifier: Option<GateModifier>,
-    ) -> GateCall {
-        GateCall {
+        params: Vec<SymbolIdResult>,
+        block: Block,
+        return_type: Option<Type>,
+    ) -> Def {
+        Def {
             name,
             params,
-            qubits,
-            modifier,
+            block,
+            return_type,
         }
     }
 
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Def(self)
+    }
+
     pub fn name(&self) -> &SymbolIdResult {
         &self.name
     }
 
-    pub fn qubits(&self) -> &Vec<TExpr> {
-        &self.qubits
+    pub fn params(&self) -> &Vec<SymbolIdResult> {
+        &self.params
     }
 
-    pub fn params(&self) -> &Option<Vec<TExpr>> {
-        &self.params
+    pub fn block(&self) -> &Block {
+        &self.block
     }
 
-    pub fn modifier(&self) -> &Option<GateModifier> {
-        &self.modifier
+    pub fn return_type(&self) -> Option<&Type> {
+        self.return_type.as_ref()
     }
 }
 
+// `cal { ... }`. The body is written in the calibration grammar, so we keep it as text.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct GPhaseCall {
-    arg: TExpr,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cal {
+    body: String,
 }
 
-impl GPhaseCall {
-    pub fn new(arg: TExpr) -> GPhaseCall {
-        GPhaseCall { arg }
+impl Cal {
+    pub fn new<T: ToString>(body: T) -> Cal {
+        Cal {
+            body: body.to_string(),
+        }
     }
-    pub fn arg(&self) -> &TExpr {
-        &self.arg
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Cal(self)
     }
 }
 
-// This is one of several examples where instead of `enum Literal`
-// we could do something like `struct Literal<T>`.
-// However, if the dispatch must occur at runtime (In Rust called "dynamic",
-// but that can mean something else as well in other languages), then tagged-union dispatch (ie `enum`)
-// is likely much faster.
-//
-// If I understand correctly, if dynamic dispatch is needed, then
-// the keyword `dyn` is required.
-//
-// The function syntax_to_semantics::from_literal matches the variant of the enum
-// `oq3_syntax::ast::LiteralKind`. So, in the match arms, the variant of `enum Literal` (below)
-// is known and the code therein can be devirtualized, inline, optimized, etc.
-// In this case `struct Literal<T>` might be an advantage because there would be no dynamic dispatch.
-// However when consuming the semantic ASG at later stage, dynamic dispatch may be required.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DefCalParam {
+    /// A typed parameter, for example `angle[20] theta`.
+    Typed(String, Type),
+    /// A constant argument, for example `pi / 2`.
+    Constant(TExpr),
+}
+
+impl DefCalParam {
+    /// Return `true` if this parameter accepts an argument whose constant value is `arg`.
+    /// An argument that is not a constant expression matches only a typed parameter.
+    pub fn matches(&self, arg: Option<&ConstValue>, const_values: &ConstValues) -> bool {
+        match self {
+            DefCalParam::Typed(..) => true,
+            DefCalParam::Constant(texpr) => match (eval_const(texpr, const_values), arg) {
+                (Some(value), Some(arg)) => value.equals_number(arg),
+                _ => false,
+            },
+        }
+    }
+}
 
-// BitStringLiteral and ArrayLiteral have data of size that can't be known at compile time.
-// What effect does this have on the size of the enum.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub enum Literal {
-    Bool(BoolLiteral),
-    Int(IntLiteral),
-    Float(FloatLiteral),
-    BitString(BitStringLiteral),
-    Array, // stub
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DefCalQubit {
+    /// A physical qubit, for example `$0`.
+    Hardware(usize),
+    /// An identifier, which matches any qubit.
+    Identifier(String),
+}
+
+impl DefCalQubit {
+    /// Return `true` if this operand applies to the physical qubit `qubit`.
+    pub fn matches(&self, qubit: usize) -> bool {
+        match self {
+            DefCalQubit::Hardware(n) => *n == qubit,
+            DefCalQubit::Identifier(_) => true,
+        }
+    }
 }
 
+// `defcal name(params) qubits -> return_type { ... }`. As for `cal`, the body is kept as text.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct Cast {
-    operand: Box<TExpr>,
-    typ: Type,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefCal {
+    name: String,
+    params: Vec<DefCalParam>,
+    qubits: Vec<DefCalQubit>,
+    return_type: Option<Type>,
+    body: Option<String>,
 }
 
-impl Cast {
-    pub fn new(operand: TExpr, typ: Type) -> Cast {
-        Cast {
-            operand: Box::new(operand),
-            typ,
+impl DefCal {
+    pub fn new<T: ToString>(
+        name: T,
+        params: Vec<DefCalParam>,
+        qubits: Vec<DefCalQubit>,
+        return_type: Option<Type>,
+        body: Option<String>,
+    ) -> DefCal {
+        DefCal {
+            name: name.to_string(),
+            params,
+            qubits,
+            return_type,
+            body,
         }
     }
 
-    pub fn get_type(&self) -> &Type {
-        &self.typ
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn params(&self) -> &[DefCalParam] {
+        &self.params
+    }
+
+    pub fn qubits(&self) -> &[DefCalQubit] {
+        &self.qubits
+    }
+
+    pub fn return_type(&self) -> Option<&Type> {
+        self.return_type.as_ref()
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    fn num_constant_params(&self) -> usize {
+        self.params
+            .iter()
+            .filter(|param| matches!(param, DefCalParam::Constant(_)))
+            .count()
+    }
+
+    fn num_hardware_qubits(&self) -> usize {
+        self.qubits
+            .iter()
+            .filter(|qubit| matches!(qubit, DefCalQubit::Hardware(_)))
+            .count()
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::DefCal(self)
+    }
+}
+
+// `extern name(params) -> return_type;` The implementation is supplied by the backend,
+// so we record the name and signature for linking.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Extern {
+    name: String,
+    symbol: SymbolIdResult,
+    params: Vec<Type>,
+    return_type: Option<Type>,
+}
+
+impl Extern {
+    pub fn new<T: ToString>(
+        name: T,
+        symbol: SymbolIdResult,
+        params: Vec<Type>,
+        return_type: Option<Type>,
+    ) -> Extern {
+        Extern {
+            name: name.to_string(),
+            symbol,
+            params,
+            return_type,
+        }
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Extern(self)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn symbol(&self) -> &SymbolIdResult {
+        &self.symbol
+    }
+
+    pub fn params(&self) -> &[Type] {
+        &self.params
+    }
+
+    pub fn return_type(&self) -> Option<&Type> {
+        self.return_type.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Return {
+    value: Option<Box<TExpr>>,
+}
+
+impl Return {
+    pub fn new(value: Option<TExpr>) -> Return {
+        Return {
+            value: value.map(Box::new),
+        }
+    }
+
+    pub fn value(&self) -> Option<&TExpr> {
+        self.value.as_deref()
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Return(self)
+    }
+}
+
+// Call of a subroutine. The type of the call expression is the return type
+// of the subroutine.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Call {
+    name: SymbolIdResult,
+    args: Vec<TExpr>,
+}
+
+impl Call {
+    pub fn new(name: SymbolIdResult, args: Vec<TExpr>) -> Call {
+        Call { name, args }
+    }
+
+    pub fn name(&self) -> &SymbolIdResult {
+        &self.name
+    }
+
+    pub fn args(&self) -> &Vec<TExpr> {
+        &self.args
+    }
+
+    pub fn to_texpr(self, typ: Type) -> TExpr {
+        TExpr::new(Expr::Call(self), typ)
+    }
+}
+
+/// A call to a built-in function, such as `sin(x)` or `popcount(b)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BuiltinCall {
+    function: BuiltinFunction,
+    args: Vec<TExpr>,
+}
+
+impl BuiltinCall {
+    pub fn new(function: BuiltinFunction, args: Vec<TExpr>) -> BuiltinCall {
+        BuiltinCall { function, args }
+    }
+
+    pub fn function(&self) -> &BuiltinFunction {
+        &self.function
+    }
+
+    pub fn args(&self) -> &Vec<TExpr> {
+        &self.args
+    }
+
+    pub fn to_texpr(self, typ: Type) -> TExpr {
+        TExpr::new(Expr::BuiltinCall(self), typ)
+    }
+}
+
+/// `sizeof(array, dim)`, the size of dimension `dim` of `array`. If `dim` is
+/// omitted, it is the size of the first dimension. The sizes of the dimensions of an
+/// array are known at compile time, so the value is a `const uint`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SizeOf {
+    array: Box<TExpr>,
+    dim: Option<Box<TExpr>>,
+}
+
+impl SizeOf {
+    pub fn new(array: TExpr, dim: Option<TExpr>) -> SizeOf {
+        SizeOf {
+            array: Box::new(array),
+            dim: dim.map(Box::new),
+        }
+    }
+
+    pub fn array(&self) -> &TExpr {
+        &self.array
+    }
+
+    pub fn dim(&self) -> Option<&TExpr> {
+        self.dim.as_deref()
+    }
+
+    pub fn to_texpr(self) -> TExpr {
+        TExpr::new(Expr::SizeOf(self), Type::UInt(None, IsConst::True))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GateCall {
+    name: SymbolIdResult,
+    params: Option<Vec<TExpr>>,
+    qubits: Vec<TExpr>,
+    modifiers: Vec<GateModifier>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GateModifier {
+    Inv,
+    Pow(TExpr),
+    Ctrl(Option<TExpr>),
+    NegCtrl(Option<TExpr>),
+}
+
+// Following naming in ref parser instead
+// We ~~will~~ should try to use the distinction between "parameter", which appears in the signature,
+// and "argument", which appears in the call expression.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QubitArgument {
+    Identifier(SymbolIdResult),
+    ArraySlice(ArraySlice),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GateOperand {
+    Identifier(Identifier),
+    HardwareQubit(HardwareQubit),
+    IndexedIdentifier(IndexedIdentifier),
+}
+
+impl GateOperand {
+    pub fn to_texpr(self, typ: Type) -> TExpr {
+        TExpr::new(Expr::GateOperand(self), typ)
+    }
+}
+
+impl GateCall {
+    pub fn new(
+        name: SymbolIdResult,
+        params: Option<Vec<TExpr>>,
+        qubits: Vec<TExpr>,
+        modifiers: Vec<GateModifier>,
+    ) -> GateCall {
+        GateCall {
+            name,
+            params,
+            qubits,
+            modifiers,
+        }
+    }
+
+    pub fn name(&self) -> &SymbolIdResult {
+        &self.name
+    }
+
+    pub fn qubits(&self) -> &Vec<TExpr> {
+        &self.qubits
+    }
+
+    pub fn params(&self) -> &Option<Vec<TExpr>> {
+        &self.params
+    }
+
+    // Modifiers in the order they appear in the source, outermost first.
+    pub fn modifiers(&self) -> &Vec<GateModifier> {
+        &self.modifiers
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeasureExpression {
+    operand: Box<TExpr>,
+}
+
+impl MeasureExpression {
+    pub fn new(operand: TExpr) -> MeasureExpression {
+        MeasureExpression {
+            operand: Box::new(operand),
+        }
+    }
+
+    pub fn operand(&self) -> &TExpr {
+        &self.operand
+    }
+
+    // The type is `Bit` or `BitArray`, depending on the operand.
+    pub fn to_texpr(self, typ: Type) -> TExpr {
+        TExpr::new(Expr::Measure(self), typ)
+    }
+}
+
+// `a ++ b ++ c` is represented by a single node with three operands.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Concatenation {
+    operands: Vec<TExpr>,
+}
+
+impl Concatenation {
+    pub fn new(operands: Vec<TExpr>) -> Concatenation {
+        Concatenation { operands }
+    }
+
+    pub fn operands(&self) -> &[TExpr] {
+        &self.operands
+    }
+
+    pub fn to_texpr(self, typ: Type) -> TExpr {
+        TExpr::new(Expr::Concatenation(self), typ)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reset {
+    operand: Box<TExpr>,
+}
+
+impl Reset {
+    pub fn new(operand: TExpr) -> Reset {
+        Reset {
+            operand: Box::new(operand),
+        }
+    }
+
+    pub fn operand(&self) -> &TExpr {
+        &self.operand
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Reset(self)
+    }
+}
+
+// `qubits` is `None` if the barrier applies to all qubits.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Barrier {
+    qubits: Option<Vec<TExpr>>,
+}
+
+impl Barrier {
+    pub fn new(qubits: Option<Vec<TExpr>>) -> Barrier {
+        Barrier { qubits }
+    }
+
+    pub fn qubits(&self) -> &Option<Vec<TExpr>> {
+        &self.qubits
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Barrier(self)
+    }
+}
+
+// `qubits` is `None` if the delay applies to all qubits.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Delay {
+    duration: TExpr,
+    qubits: Option<Vec<TExpr>>,
+}
+
+impl Delay {
+    pub fn new(duration: TExpr, qubits: Option<Vec<TExpr>>) -> Delay {
+        Delay { duration, qubits }
+    }
+
+    pub fn duration(&self) -> &TExpr {
+        &self.duration
+    }
+
+    pub fn qubits(&self) -> &Option<Vec<TExpr>> {
+        &self.qubits
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Delay(self)
+    }
+}
+
+// `duration` is `None` if the box has no designator.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoxStmt {
+    duration: Option<TExpr>,
+    body: Block,
+}
+
+impl BoxStmt {
+    pub fn new(duration: Option<TExpr>, body: Block) -> BoxStmt {
+        BoxStmt { duration, body }
+    }
+
+    pub fn duration(&self) -> &Option<TExpr> {
+        &self.duration
+    }
+
+    pub fn body(&self) -> &Block {
+        &self.body
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Box(self)
+    }
+}
+
+// The duration of `scope` is not known until the program is scheduled.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DurationOf {
+    scope: Block,
+}
+
+impl DurationOf {
+    pub fn new(scope: Block) -> DurationOf {
+        DurationOf { scope }
+    }
+
+    pub fn scope(&self) -> &Block {
+        &self.scope
+    }
+
+    pub fn to_expr(self) -> Expr {
+        Expr::DurationOf(self)
+    }
+
+    pub fn to_texpr(self) -> TExpr {
+        TExpr::new(self.to_expr(), Type::Duration(IsConst::False))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GPhaseCall {
+    arg: TExpr,
+}
+
+impl GPhaseCall {
+    pub fn new(arg: TExpr) -> GPhaseCall {
+        GPhaseCall { arg }
+    }
+    pub fn arg(&self) -> &TExpr {
+        &self.arg
+    }
+}
+
+// This is one of several examples where instead of `enum Literal`
+// we could do something like `struct Literal<T>`.
+// However, if the dispatch must occur at runtime (In Rust called "dynamic",
+// but that can mean something else as well in other languages), then tagged-union dispatch (ie `enum`)
+// is likely much faster.
+//
+// If I understand correctly, if dynamic dispatch is needed, then
+// the keyword `dyn` is required.
+//
+// The function syntax_to_semantics::from_literal matches the variant of the enum
+// `oq3_syntax::ast::LiteralKind`. So, in the match arms, the variant of `enum Literal` (below)
+// is known and the code therein can be devirtualized, inline, optimized, etc.
+// In this case `struct Literal<T>` might be an advantage because there would be no dynamic dispatch.
+// However when consuming the semantic ASG at later stage, dynamic dispatch may be required.
+
+// BitStringLiteral and ArrayLiteral have data of size that can't be known at compile time.
+// What effect does this have on the size of the enum.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Literal {
+    Bool(BoolLiteral),
+    Int(IntLiteral),
+    Float(FloatLiteral),
+    Imaginary(ImaginaryLiteral),
+    BitString(BitStringLiteral),
+    Duration(DurationLiteral),
+    Array(ArrayLiteral),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cast {
+    operand: Box<TExpr>,
+    typ: Type,
+    is_implicit: bool,
+}
+
+impl Cast {
+    pub fn new(operand: TExpr, typ: Type) -> Cast {
+        Cast {
+            operand: Box::new(operand),
+            typ,
+            is_implicit: false,
+        }
+    }
+
+    /// A cast that does not appear in the source, but is inserted by type promotion.
+    pub fn new_implicit(operand: TExpr, typ: Type) -> Cast {
+        Cast {
+            operand: Box::new(operand),
+            typ,
+            is_implicit: true,
+        }
+    }
+
+    pub fn get_type(&self) -> &Type {
+        &self.typ
+    }
+
+    pub fn is_implicit(&self) -> bool {
+        self.is_implicit
     }
 
     pub fn operand(&self) -> &TExpr {
@@ -705,6 +1696,7 @@ impl Cast {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoolLiteral {
     value: bool,
 }
@@ -732,6 +1724,7 @@ impl BoolLiteral {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntLiteral {
     value: u128,
 }
@@ -762,6 +1755,7 @@ impl IntLiteral {
 // This is float as a string.
 // For example, trait Eq is implemented here, but not for actual f64.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FloatLiteral {
     value: String,
 }
@@ -791,7 +1785,102 @@ impl FloatLiteral {
     }
 }
 
+/// An imaginary literal, such as `1.5im`. The value is the imaginary part.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImaginaryLiteral {
+    value: String,
+}
+
+impl ImaginaryLiteral {
+    pub fn new<T: ToString>(value: T) -> ImaginaryLiteral {
+        ImaginaryLiteral {
+            value: value.to_string(),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn to_expr(self) -> Expr {
+        Expr::Literal(Literal::Imaginary(self))
+    }
+
+    pub fn to_texpr(self) -> TExpr {
+        TExpr::new(self.to_expr(), Type::Complex(Some(64), IsConst::True))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeUnit {
+    Second,
+    MilliSecond,
+    MicroSecond,
+    NanoSecond,
+    Cycle, // `dt`, a backend-dependent unit
+}
+
+impl TimeUnit {
+    /// Return the unit corresponding to the suffix of a timing literal.
+    pub fn from_suffix(suffix: &str) -> Option<TimeUnit> {
+        match suffix {
+            "s" => Some(TimeUnit::Second),
+            "ms" => Some(TimeUnit::MilliSecond),
+            "us" | "µs" => Some(TimeUnit::MicroSecond),
+            "ns" => Some(TimeUnit::NanoSecond),
+            "dt" => Some(TimeUnit::Cycle),
+            _ => None,
+        }
+    }
+
+    /// The suffix of a timing literal in this unit. The inverse of `from_suffix`.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            TimeUnit::Second => "s",
+            TimeUnit::MilliSecond => "ms",
+            TimeUnit::MicroSecond => "us",
+            TimeUnit::NanoSecond => "ns",
+            TimeUnit::Cycle => "dt",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DurationLiteral {
+    value: String,
+    unit: TimeUnit,
+}
+
+impl DurationLiteral {
+    pub fn new<T: ToString>(value: T, unit: TimeUnit) -> DurationLiteral {
+        DurationLiteral {
+            value: value.to_string(),
+            unit,
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    pub fn to_expr(self) -> Expr {
+        Expr::Literal(Literal::Duration(self))
+    }
+
+    pub fn to_texpr(self) -> TExpr {
+        TExpr::new(self.to_expr(), Type::Duration(IsConst::True))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BitStringLiteral {
     value: String,
 }
@@ -820,8 +1909,35 @@ impl BitStringLiteral {
     }
 }
 
+/// An array literal `{...}`. Each element is either a scalar or, for arrays
+/// of more than one dimension, itself an array literal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArrayLiteral {
+    elements: Vec<TExpr>,
+}
+
+impl ArrayLiteral {
+    pub fn new(elements: Vec<TExpr>) -> ArrayLiteral {
+        ArrayLiteral { elements }
+    }
+
+    pub fn elements(&self) -> &[TExpr] {
+        &self.elements
+    }
+
+    pub fn to_expr(self) -> Expr {
+        Expr::Literal(Literal::Array(self))
+    }
+
+    pub fn to_texpr(self, typ: Type) -> TExpr {
+        TExpr::new(self.to_expr(), typ)
+    }
+}
+
 // String literal appears in restricted contexts. It is not in the expression tree.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringLiteral {
     value: String,
 }
@@ -841,6 +1957,7 @@ impl StringLiteral {
 // Unary Expressions
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnaryOp {
     Minus,
     Not,
@@ -848,6 +1965,7 @@ pub enum UnaryOp {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnaryExpr {
     op: UnaryOp,
     operand: Box<TExpr>,
@@ -868,9 +1986,29 @@ impl UnaryExpr {
     pub fn op(&self) -> &UnaryOp {
         &self.op
     }
+
+    pub fn to_expr(self) -> Expr {
+        Expr::UnaryExpr(self)
+    }
+
+    pub fn to_texpr(self, typ: Type) -> TExpr {
+        TExpr::new(self.to_expr(), typ)
+    }
+
+    /// Return the typed expression `op operand`. Negation and bitwise negation
+    /// preserve the type of the operand. Logical negation yields a `bool`.
+    pub fn new_texpr(op: UnaryOp, operand: TExpr) -> TExpr {
+        let operand_type = operand.get_type();
+        let typ = match op {
+            UnaryOp::Minus | UnaryOp::BitNot => operand_type.clone(),
+            UnaryOp::Not => Type::Bool(IsConst::from(operand_type.is_const())),
+        };
+        UnaryExpr::new(op, operand).to_texpr(typ)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BinaryExpr {
     pub(crate) op: BinaryOp,
     pub(crate) left: Box<TExpr>,
@@ -878,12 +2016,15 @@ pub struct BinaryExpr {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BinaryOp {
     ArithOp(ArithOp),
     CmpOp(CmpOp),
+    LogicOp(LogicOp),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArithOp {
     Add,
     Sub,
@@ -891,15 +2032,30 @@ pub enum ArithOp {
     Div,
     Mod,
     Rem,
+    Pow,
     Shl,
     Shr,
     BitXOr,
+    BitOr,
     BitAnd,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CmpOp {
     Eq,
+    Neq,
+    Lt,
+    Gt,
+    Leq,
+    Geq,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LogicOp {
+    And,
+    Or,
 }
 
 // FIXME: ?? Code would be easier, maybe more performant since we
@@ -933,18 +2089,80 @@ impl BinaryExpr {
     pub fn new_texpr_with_cast(op: BinaryOp, left: TExpr, right: TExpr) -> TExpr {
         let left_type = left.get_type();
         let right_type = right.get_type();
-        let promoted_type = types::promote_types(left_type, right_type);
-        let new_left = if &promoted_type == left_type {
+        // Operands of timing expressions are not cast. For example, in `d * 2`
+        // the `int` scales the `duration`, and the result is a `duration`.
+        if let (BinaryOp::ArithOp(arith_op), true) =
+            (&op, left_type.is_timing() || right_type.is_timing())
+        {
+            let typ = match arith_op {
+                ArithOp::Add | ArithOp::Sub => types::promote_timing_sum(left_type, right_type),
+                ArithOp::Mul => types::promote_timing_product(left_type, right_type),
+                ArithOp::Div => types::promote_timing_quotient(left_type, right_type),
+                _ => None,
+            };
+            return BinaryExpr::new(op, left, right).to_texpr(typ.unwrap_or(Type::Undefined));
+        }
+        let isconst = IsConst::from(left_type.is_const() && right_type.is_const());
+        let promoted_type = match &op {
+            // A shift has the type of the value shifted. The shift amount is not cast.
+            BinaryOp::ArithOp(ArithOp::Shl | ArithOp::Shr) => {
+                let typ = types::promote_shift(left_type, right_type).unwrap_or(Type::Undefined);
+                return BinaryExpr::new(op, left, right).to_texpr(typ);
+            }
+            // The operands of `&&` and `||` are converted to `bool`.
+            BinaryOp::LogicOp(_) => {
+                let left = cast_to_bool(left);
+                let right = cast_to_bool(right);
+                return BinaryExpr::new(op, left, right).to_texpr(Type::Bool(isconst));
+            }
+            BinaryOp::ArithOp(ArithOp::BitAnd | ArithOp::BitOr | ArithOp::BitXOr) => {
+                types::promote_bitwise(left_type, right_type).unwrap_or(Type::Void)
+            }
+            // The remainder of complex numbers is not defined.
+            BinaryOp::ArithOp(ArithOp::Mod | ArithOp::Rem)
+                if matches!(left_type, Type::Complex(..))
+                    || matches!(right_type, Type::Complex(..)) =>
+            {
+                Type::Void
+            }
+            _ => types::promote_types(left_type, right_type),
+        };
+        // A comparison is a `bool`. If there is no common type, as when comparing a
+        // `bit` register with an integer in `if (c == 1)`, the operands are not cast.
+        // An arithmetic operation without a common type, or on two `bool`s, is an error,
+        // and its type is `Undefined`.
+        let typ = match op {
+            BinaryOp::CmpOp(_) | BinaryOp::LogicOp(_) => Type::Bool(isconst),
+            BinaryOp::ArithOp(_) if matches!(promoted_type, Type::Void | Type::Bool(_)) => {
+                Type::Undefined
+            }
+            BinaryOp::ArithOp(_) => promoted_type.clone(),
+        };
+        if promoted_type == Type::Void {
+            return BinaryExpr::new(op, left, right).to_texpr(typ);
+        }
+        // An operand that differs from the promoted type only in `const` is not cast.
+        let new_left = if types::equal_up_to_constness(&promoted_type, left_type) {
             left
         } else {
-            Cast::new(left, promoted_type.clone()).to_texpr()
+            Cast::new_implicit(left, promoted_type.clone()).to_texpr()
         };
-        let new_right = if &promoted_type == right_type {
+        let new_right = if types::equal_up_to_constness(&promoted_type, right_type) {
             right
         } else {
-            Cast::new(right, promoted_type.clone()).to_texpr()
+            Cast::new_implicit(right, promoted_type).to_texpr()
         };
-        BinaryExpr::new(op, new_left, new_right).to_texpr(promoted_type)
+        BinaryExpr::new(op, new_left, new_right).to_texpr(typ)
+    }
+}
+
+fn cast_to_bool(texpr: TExpr) -> TExpr {
+    match texpr.get_type() {
+        Type::Bool(..) | Type::Undefined | Type::ToDo => texpr,
+        typ => {
+            let isconst = IsConst::from(typ.is_const());
+            Cast::new_implicit(texpr, Type::Bool(isconst)).to_texpr()
+        }
     }
 }
 
@@ -961,6 +2179,7 @@ impl BinaryExpr {
 // And in that case, the variant `Ident(Ident)` in `enum Expr` above could be replaced with
 // `Ident(SymbolId)`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Identifier {
     name: String,
     symbol: SymbolIdResult,
@@ -992,6 +2211,7 @@ impl Identifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Range {
     start: Box<TExpr>,
     step: Box<Option<TExpr>>,
@@ -1025,6 +2245,7 @@ impl Range {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct If {
     condition: TExpr,
     then_branch: Block,
@@ -1057,7 +2278,68 @@ impl If {
     }
 }
 
+// `switch (control) { case labels { } ... default { } }`
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwitchCaseStmt {
+    control: TExpr,
+    cases: Vec<CaseExpr>,
+    default_block: Option<Block>,
+}
+
+impl SwitchCaseStmt {
+    pub fn new(
+        control: TExpr,
+        cases: Vec<CaseExpr>,
+        default_block: Option<Block>,
+    ) -> SwitchCaseStmt {
+        SwitchCaseStmt {
+            control,
+            cases,
+            default_block,
+        }
+    }
+
+    pub fn control(&self) -> &TExpr {
+        &self.control
+    }
+
+    pub fn cases(&self) -> &[CaseExpr] {
+        &self.cases
+    }
+
+    pub fn default_block(&self) -> Option<&Block> {
+        self.default_block.as_ref()
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Switch(self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaseExpr {
+    labels: Vec<TExpr>,
+    block: Block,
+}
+
+impl CaseExpr {
+    pub fn new(labels: Vec<TExpr>, block: Block) -> CaseExpr {
+        CaseExpr { labels, block }
+    }
+
+    pub fn labels(&self) -> &[TExpr] {
+        &self.labels
+    }
+
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct While {
     condition: TExpr,
     loop_body: Block,
@@ -1084,8 +2366,51 @@ impl While {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ForIterable {
+    SetExpression(SetExpression),
+    RangeExpression(Range),
+    Expr(TExpr),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct For {
+    loop_var: SymbolIdResult,
+    iterable: ForIterable,
+    loop_body: Block,
+}
+
+impl For {
+    pub fn new(loop_var: SymbolIdResult, iterable: ForIterable, loop_body: Block) -> For {
+        For {
+            loop_var,
+            iterable,
+            loop_body,
+        }
+    }
+
+    pub fn loop_var(&self) -> &SymbolIdResult {
+        &self.loop_var
+    }
+
+    pub fn iterable(&self) -> &ForIterable {
+        &self.iterable
+    }
+
+    pub fn loop_body(&self) -> &Block {
+        &self.loop_body
+    }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::For(self)
+    }
+}
+
 // Might make sense for this, and others to be like `struct Pragma(String)`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pragma {
     pragma: String,
 }
@@ -1100,4 +2425,8 @@ impl Pragma {
     pub fn pragma(&self) -> &str {
         self.pragma.as_ref()
     }
+
+    pub fn to_stmt(self) -> Stmt {
+        Stmt::Pragma(self)
+    }
 }
@@ -0,0 +1,646 @@
+// Copyright contributors to the openqasm-parser project
+// SPDX-License-Identifier: Apache-2.0
+
+//! Traversal of the ASG.
+//!
+//! [`Visitor`] walks an ASG through shared references, and [`VisitorMut`] walks it through
+//! mutable references, so that a pass may rewrite nodes in place. The two traits have the same
+//! methods. For each kind of node there is a method `visit_<node>`, which by default calls
+//! `super_<node>`. The `super_<node>` methods visit the children of the node, in the order in
+//! which they appear in the source. A pass overrides the `visit_` methods for the nodes it is
+//! interested in, and calls the corresponding `super_` method if it also wants the children of
+//! the node to be visited. Every symbol in the ASG is passed to `visit_symbol`.
+//!
+//! ```ignore
+//! // Collect the names of the gates called in a program.
+//! struct GateCalls<'a>(&'a SymbolTable, Vec<String>);
+//!
+//! impl Visitor for GateCalls<'_> {
+//!     fn visit_gate_call(&mut self, gate_call: &GateCall) {
+//!         if let Ok(symbol_id) = gate_call.name() {
+//!             self.1.push(self.0[symbol_id].name().to_string());
+//!         }
+//!         self.super_gate_call(gate_call);
+//!     }
+//! }
+//! ```
+
+// The two traits are generated by one macro, as is done for the MIR visitors in `rustc`.
+// In the macro, `& $($mutability)? node.field` is either a shared or a mutable borrow of a
+// field. This module is a child of `asg`, so the private fields of the nodes are visible here.
+
+use super::*;
+
+macro_rules! make_visitor {
+    ($visitor_trait_name:ident, $($mutability:ident)?) => {
+        pub trait $visitor_trait_name {
+            fn visit_program(&mut self, program: & $($mutability)? Program) {
+                self.super_program(program);
+            }
+
+            fn visit_stmt(&mut self, stmt: & $($mutability)? Stmt) {
+                self.super_stmt(stmt);
+            }
+
+            fn visit_block(&mut self, block: & $($mutability)? Block) {
+                self.super_block(block);
+            }
+
+            fn visit_texpr(&mut self, texpr: & $($mutability)? TExpr) {
+                self.super_texpr(texpr);
+            }
+
+            fn visit_expr(&mut self, expr: & $($mutability)? Expr) {
+                self.super_expr(expr);
+            }
+
+            /// Every symbol in the ASG, whether declared or referenced, is passed to this method.
+            fn visit_symbol(&mut self, _symbol: & $($mutability)? SymbolIdResult) {}
+
+            // Statements
+
+            fn visit_alias(&mut self, alias: & $($mutability)? Alias) {
+                self.super_alias(alias);
+            }
+
+            fn visit_annotated_stmt(&mut self, annotated_stmt: & $($mutability)? AnnotatedStmt) {
+                self.super_annotated_stmt(annotated_stmt);
+            }
+
+            fn visit_annotation(&mut self, _annotation: & $($mutability)? Annotation) {}
+
+            fn visit_assignment(&mut self, assignment: & $($mutability)? Assignment) {
+                self.super_assignment(assignment);
+            }
+
+            fn visit_lvalue(&mut self, lvalue: & $($mutability)? LValue) {
+                self.super_lvalue(lvalue);
+            }
+
+            fn visit_barrier(&mut self, barrier: & $($mutability)? Barrier) {
+                self.super_barrier(barrier);
+            }
+
+            fn visit_box_stmt(&mut self, box_stmt: & $($mutability)? BoxStmt) {
+                self.super_box_stmt(box_stmt);
+            }
+
+            fn visit_cal(&mut self, _cal: & $($mutability)? Cal) {}
+
+            fn visit_declare_classical(&mut self, declare: & $($mutability)? DeclareClassical) {
+                self.super_declare_classical(declare);
+            }
+
+            fn visit_declare_quantum(&mut self, declare: & $($mutability)? DeclareQuantum) {
+                self.super_declare_quantum(declare);
+            }
+
+            fn visit_def(&mut self, def: & $($mutability)? Def) {
+                self.super_def(def);
+            }
+
+            fn visit_defcal(&mut self, defcal: & $($mutability)? DefCal) {
+                self.super_defcal(defcal);
+            }
+
+            fn visit_delay(&mut self, delay: & $($mutability)? Delay) {
+                self.super_delay(delay);
+            }
+
+            fn visit_extern(&mut self, extern_: & $($mutability)? Extern) {
+                self.super_extern(extern_);
+            }
+
+            fn visit_for(&mut self, for_stmt: & $($mutability)? For) {
+                self.super_for(for_stmt);
+            }
+
+            fn visit_for_iterable(&mut self, iterable: & $($mutability)? ForIterable) {
+                self.super_for_iterable(iterable);
+            }
+
+            fn visit_gate_declaration(&mut self, gate: & $($mutability)? GateDeclaration) {
+                self.super_gate_declaration(gate);
+            }
+
+            fn visit_gate_call(&mut self, gate_call: & $($mutability)? GateCall) {
+                self.super_gate_call(gate_call);
+            }
+
+            fn visit_gate_modifier(&mut self, modifier: & $($mutability)? GateModifier) {
+                self.super_gate_modifier(modifier);
+            }
+
+            fn visit_gphase_call(&mut self, gphase_call: & $($mutability)? GPhaseCall) {
+                self.super_gphase_call(gphase_call);
+            }
+
+            fn visit_io_declaration(&mut self, declare: & $($mutability)? IODeclaration) {
+                self.super_io_declaration(declare);
+            }
+
+            fn visit_if(&mut self, if_stmt: & $($mutability)? If) {
+                self.super_if(if_stmt);
+            }
+
+            fn visit_include(&mut self, _include: & $($mutability)? Include) {}
+
+            fn visit_pragma(&mut self, _pragma: & $($mutability)? Pragma) {}
+
+            fn visit_reset(&mut self, reset: & $($mutability)? Reset) {
+                self.super_reset(reset);
+            }
+
+            fn visit_return(&mut self, return_stmt: & $($mutability)? Return) {
+                self.super_return(return_stmt);
+            }
+
+            fn visit_switch(&mut self, switch: & $($mutability)? SwitchCaseStmt) {
+                self.super_switch(switch);
+            }
+
+            fn visit_case(&mut self, case: & $($mutability)? CaseExpr) {
+                self.super_case(case);
+            }
+
+            fn visit_while(&mut self, while_stmt: & $($mutability)? While) {
+                self.super_while(while_stmt);
+            }
+
+            // Expressions
+
+            fn visit_array_slice(&mut self, array_slice: & $($mutability)? ArraySlice) {
+                self.super_array_slice(array_slice);
+            }
+
+            fn visit_binary_expr(&mut self, binary_expr: & $($mutability)? BinaryExpr) {
+                self.super_binary_expr(binary_expr);
+            }
+
+            fn visit_unary_expr(&mut self, unary_expr: & $($mutability)? UnaryExpr) {
+                self.super_unary_expr(unary_expr);
+            }
+
+            fn visit_literal(&mut self, literal: & $($mutability)? Literal) {
+                self.super_literal(literal);
+            }
+
+            fn visit_cast(&mut self, cast: & $($mutability)? Cast) {
+                self.super_cast(cast);
+            }
+
+            fn visit_identifier(&mut self, identifier: & $($mutability)? Identifier) {
+                self.super_identifier(identifier);
+            }
+
+            fn visit_hardware_qubit(&mut self, _hardware_qubit: & $($mutability)? HardwareQubit) {}
+
+            fn visit_index_expression(&mut self, index_expr: & $($mutability)? IndexExpression) {
+                self.super_index_expression(index_expr);
+            }
+
+            fn visit_indexed_identifier(
+                &mut self,
+                indexed_identifier: & $($mutability)? IndexedIdentifier,
+            ) {
+                self.super_indexed_identifier(indexed_identifier);
+            }
+
+            fn visit_index_operator(&mut self, index: & $($mutability)? IndexOperator) {
+                self.super_index_operator(index);
+            }
+
+            fn visit_register_slice(&mut self, register_slice: & $($mutability)? RegisterSlice) {
+                self.super_register_slice(register_slice);
+            }
+
+            fn visit_gate_operand(&mut self, gate_operand: & $($mutability)? GateOperand) {
+                self.super_gate_operand(gate_operand);
+            }
+
+            fn visit_range(&mut self, range: & $($mutability)? Range) {
+                self.super_range(range);
+            }
+
+            fn visit_set_expression(&mut self, set_expr: & $($mutability)? SetExpression) {
+                self.super_set_expression(set_expr);
+            }
+
+            fn visit_call(&mut self, call: & $($mutability)? Call) {
+                self.super_call(call);
+            }
+
+            fn visit_builtin_call(&mut self, call: & $($mutability)? BuiltinCall) {
+                self.super_builtin_call(call);
+            }
+
+            fn visit_sizeof(&mut self, sizeof: & $($mutability)? SizeOf) {
+                self.super_sizeof(sizeof);
+            }
+
+            fn visit_measure(&mut self, measure: & $($mutability)? MeasureExpression) {
+                self.super_measure(measure);
+            }
+
+            fn visit_duration_of(&mut self, duration_of: & $($mutability)? DurationOf) {
+                self.super_duration_of(duration_of);
+            }
+
+            fn visit_concatenation(&mut self, concatenation: & $($mutability)? Concatenation) {
+                self.super_concatenation(concatenation);
+            }
+
+            // The `super_` methods visit the children of a node. They are not meant
+            // to be overridden.
+
+            fn super_program(&mut self, program: & $($mutability)? Program) {
+                for stmt in & $($mutability)? program.stmts {
+                    self.visit_stmt(stmt);
+                }
+            }
+
+            fn super_stmt(&mut self, stmt: & $($mutability)? Stmt) {
+                match stmt {
+                    Stmt::Alias(alias) => self.visit_alias(alias),
+                    Stmt::AnnotatedStmt(annotated_stmt) => {
+                        self.visit_annotated_stmt(annotated_stmt)
+                    }
+                    Stmt::Assignment(assignment) => self.visit_assignment(assignment),
+                    Stmt::Barrier(barrier) => self.visit_barrier(barrier),
+                    Stmt::Block(block) => self.visit_block(block),
+                    Stmt::Box(box_stmt) => self.visit_box_stmt(box_stmt),
+                    Stmt::Cal(cal) => self.visit_cal(cal),
+                    Stmt::DeclareClassical(declare) => self.visit_declare_classical(declare),
+                    Stmt::Def(def) => self.visit_def(def),
+                    Stmt::DefCal(defcal) => self.visit_defcal(defcal),
+                    Stmt::Delay(delay) => self.visit_delay(delay),
+                    Stmt::ExprStmt(texpr) => self.visit_texpr(texpr),
+                    Stmt::Extern(extern_) => self.visit_extern(extern_),
+                    Stmt::For(for_stmt) => self.visit_for(for_stmt),
+                    Stmt::GateDeclaration(gate) => self.visit_gate_declaration(gate),
+                    Stmt::GateCall(gate_call) => self.visit_gate_call(gate_call),
+                    Stmt::GPhaseCall(gphase_call) => self.visit_gphase_call(gphase_call),
+                    Stmt::IODeclaration(declare) => self.visit_io_declaration(declare),
+                    Stmt::If(if_stmt) => self.visit_if(if_stmt),
+                    Stmt::Include(include) => self.visit_include(include),
+                    Stmt::Pragma(pragma) => self.visit_pragma(pragma),
+                    Stmt::DeclareQuantum(declare) => self.visit_declare_quantum(declare),
+                    Stmt::Reset(reset) => self.visit_reset(reset),
+                    Stmt::Return(return_stmt) => self.visit_return(return_stmt),
+                    Stmt::Switch(switch) => self.visit_switch(switch),
+                    Stmt::While(while_stmt) => self.visit_while(while_stmt),
+                    Stmt::Break | Stmt::Continue | Stmt::End | Stmt::NullStmt => (),
+                }
+            }
+
+            fn super_block(&mut self, block: & $($mutability)? Block) {
+                for stmt in & $($mutability)? block.statements {
+                    self.visit_stmt(stmt);
+                }
+            }
+
+            fn super_texpr(&mut self, texpr: & $($mutability)? TExpr) {
+                self.visit_expr(& $($mutability)? texpr.expression);
+            }
+
+            fn super_expr(&mut self, expr: & $($mutability)? Expr) {
+                match expr {
+                    Expr::ArraySlice(array_slice) => self.visit_array_slice(array_slice),
+                    Expr::BinaryExpr(binary_expr) => self.visit_binary_expr(binary_expr),
+                    Expr::UnaryExpr(unary_expr) => self.visit_unary_expr(unary_expr),
+                    Expr::Literal(literal) => self.visit_literal(literal),
+                    Expr::Cast(cast) => self.visit_cast(cast),
+                    Expr::Identifier(identifier) => self.visit_identifier(identifier),
+                    Expr::HardwareQubit(hardware_qubit) => {
+                        self.visit_hardware_qubit(hardware_qubit)
+                    }
+                    Expr::IndexExpression(index_expr) => self.visit_index_expression(index_expr),
+                    Expr::IndexedIdentifier(indexed_identifier) => {
+                        self.visit_indexed_identifier(indexed_identifier)
+                    }
+                    Expr::GateOperand(gate_operand) => self.visit_gate_operand(gate_operand),
+                    Expr::Range(range) => self.visit_range(range),
+                    Expr::Call(call) => self.visit_call(call),
+                    Expr::BuiltinCall(call) => self.visit_builtin_call(call),
+                    Expr::SizeOf(sizeof) => self.visit_sizeof(sizeof),
+                    Expr::Measure(measure) => self.visit_measure(measure),
+                    Expr::DurationOf(duration_of) => self.visit_duration_of(duration_of),
+                    Expr::Concatenation(concatenation) => self.visit_concatenation(concatenation),
+                    Expr::Set => (),
+                }
+            }
+
+            fn super_alias(&mut self, alias: & $($mutability)? Alias) {
+                self.visit_symbol(& $($mutability)? alias.name);
+                self.visit_texpr(& $($mutability)? alias.rvalue);
+            }
+
+            fn super_annotated_stmt(&mut self, annotated_stmt: & $($mutability)? AnnotatedStmt) {
+                for annotation in & $($mutability)? annotated_stmt.annotations {
+                    self.visit_annotation(annotation);
+                }
+                self.visit_stmt(& $($mutability)? annotated_stmt.stmt);
+            }
+
+            fn super_assignment(&mut self, assignment: & $($mutability)? Assignment) {
+                self.visit_lvalue(& $($mutability)? assignment.lvalue);
+                self.visit_texpr(& $($mutability)? assignment.rvalue);
+            }
+
+            fn super_lvalue(&mut self, lvalue: & $($mutability)? LValue) {
+                match lvalue {
+                    LValue::Identifier(symbol) => self.visit_symbol(symbol),
+                    LValue::IndexedIdentifier(indexed_identifier) => {
+                        self.visit_indexed_identifier(indexed_identifier)
+                    }
+                    LValue::ArraySlice(array_slice) => self.visit_array_slice(array_slice),
+                    LValue::RegisterSlice(register_slice) => {
+                        self.visit_register_slice(register_slice)
+                    }
+                }
+            }
+
+            fn super_barrier(&mut self, barrier: & $($mutability)? Barrier) {
+                for qubit in (& $($mutability)? barrier.qubits).into_iter().flatten() {
+                    self.visit_texpr(qubit);
+                }
+            }
+
+            fn super_box_stmt(&mut self, box_stmt: & $($mutability)? BoxStmt) {
+                if let Some(duration) = & $($mutability)? box_stmt.duration {
+                    self.visit_texpr(duration);
+                }
+                self.visit_block(& $($mutability)? box_stmt.body);
+            }
+
+            fn super_declare_classical(&mut self, declare: & $($mutability)? DeclareClassical) {
+                self.visit_symbol(& $($mutability)? declare.name);
+                if let Some(initializer) = & $($mutability)? declare.initializer {
+                    self.visit_texpr(initializer);
+                }
+            }
+
+            fn super_declare_quantum(&mut self, declare: & $($mutability)? DeclareQuantum) {
+                self.visit_symbol(& $($mutability)? declare.name);
+            }
+
+            fn super_def(&mut self, def: & $($mutability)? Def) {
+                self.visit_symbol(& $($mutability)? def.name);
+                for param in & $($mutability)? def.params {
+                    self.visit_symbol(param);
+                }
+                self.visit_block(& $($mutability)? def.block);
+            }
+
+            fn super_defcal(&mut self, defcal: & $($mutability)? DefCal) {
+                for param in & $($mutability)? defcal.params {
+                    if let DefCalParam::Constant(texpr) = param {
+                        self.visit_texpr(texpr);
+                    }
+                }
+            }
+
+            fn super_delay(&mut self, delay: & $($mutability)? Delay) {
+                self.visit_texpr(& $($mutability)? delay.duration);
+                for qubit in (& $($mutability)? delay.qubits).into_iter().flatten() {
+                    self.visit_texpr(qubit);
+                }
+            }
+
+            fn super_extern(&mut self, extern_: & $($mutability)? Extern) {
+                self.visit_symbol(& $($mutability)? extern_.symbol);
+            }
+
+            fn super_for(&mut self, for_stmt: & $($mutability)? For) {
+                self.visit_symbol(& $($mutability)? for_stmt.loop_var);
+                self.visit_for_iterable(& $($mutability)? for_stmt.iterable);
+                self.visit_block(& $($mutability)? for_stmt.loop_body);
+            }
+
+            fn super_for_iterable(&mut self, iterable: & $($mutability)? ForIterable) {
+                match iterable {
+                    ForIterable::SetExpression(set_expr) => self.visit_set_expression(set_expr),
+                    ForIterable::RangeExpression(range) => self.visit_range(range),
+                    ForIterable::Expr(texpr) => self.visit_texpr(texpr),
+                }
+            }
+
+            fn super_gate_declaration(&mut self, gate: & $($mutability)? GateDeclaration) {
+                self.visit_symbol(& $($mutability)? gate.name);
+                for param in (& $($mutability)? gate.params).into_iter().flatten() {
+                    self.visit_symbol(param);
+                }
+                for qubit in & $($mutability)? gate.qubits {
+                    self.visit_symbol(qubit);
+                }
+                self.visit_block(& $($mutability)? gate.block);
+            }
+
+            fn super_gate_call(&mut self, gate_call: & $($mutability)? GateCall) {
+                for modifier in & $($mutability)? gate_call.modifiers {
+                    self.visit_gate_modifier(modifier);
+                }
+                self.visit_symbol(& $($mutability)? gate_call.name);
+                for param in (& $($mutability)? gate_call.params).into_iter().flatten() {
+                    self.visit_texpr(param);
+                }
+                for qubit in & $($mutability)? gate_call.qubits {
+                    self.visit_texpr(qubit);
+                }
+            }
+
+            fn super_gate_modifier(&mut self, modifier: & $($mutability)? GateModifier) {
+                match modifier {
+                    GateModifier::Pow(texpr)
+                    | GateModifier::Ctrl(Some(texpr))
+                    | GateModifier::NegCtrl(Some(texpr)) => self.visit_texpr(texpr),
+                    GateModifier::Inv
+                    | GateModifier::Ctrl(None)
+                    | GateModifier::NegCtrl(None) => (),
+                }
+            }
+
+            fn super_gphase_call(&mut self, gphase_call: & $($mutability)? GPhaseCall) {
+                self.visit_texpr(& $($mutability)? gphase_call.arg);
+            }
+
+            fn super_io_declaration(&mut self, declare: & $($mutability)? IODeclaration) {
+                self.visit_symbol(& $($mutability)? declare.symbol);
+            }
+
+            fn super_if(&mut self, if_stmt: & $($mutability)? If) {
+                self.visit_texpr(& $($mutability)? if_stmt.condition);
+                self.visit_block(& $($mutability)? if_stmt.then_branch);
+                if let Some(else_branch) = & $($mutability)? if_stmt.else_branch {
+                    self.visit_block(else_branch);
+                }
+            }
+
+            fn super_reset(&mut self, reset: & $($mutability)? Reset) {
+                self.visit_texpr(& $($mutability)? reset.operand);
+            }
+
+            fn super_return(&mut self, return_stmt: & $($mutability)? Return) {
+                if let Some(value) = & $($mutability)? return_stmt.value {
+                    self.visit_texpr(value);
+                }
+            }
+
+            fn super_switch(&mut self, switch: & $($mutability)? SwitchCaseStmt) {
+                self.visit_texpr(& $($mutability)? switch.control);
+                for case in & $($mutability)? switch.cases {
+                    self.visit_case(case);
+                }
+                if let Some(default_block) = & $($mutability)? switch.default_block {
+                    self.visit_block(default_block);
+                }
+            }
+
+            fn super_case(&mut self, case: & $($mutability)? CaseExpr) {
+                for label in & $($mutability)? case.labels {
+                    self.visit_texpr(label);
+                }
+                self.visit_block(& $($mutability)? case.block);
+            }
+
+            fn super_while(&mut self, while_stmt: & $($mutability)? While) {
+                self.visit_texpr(& $($mutability)? while_stmt.condition);
+                self.visit_block(& $($mutability)? while_stmt.loop_body);
+            }
+
+            fn super_array_slice(&mut self, array_slice: & $($mutability)? ArraySlice) {
+                self.visit_symbol(& $($mutability)? array_slice.name);
+                for index in & $($mutability)? array_slice.indices {
+                    match index {
+                        ArraySliceIndex::Expr(texpr) | ArraySliceIndex::Range(texpr) => {
+                            self.visit_texpr(texpr)
+                        }
+                    }
+                }
+            }
+
+            fn super_binary_expr(&mut self, binary_expr: & $($mutability)? BinaryExpr) {
+                self.visit_texpr(& $($mutability)? binary_expr.left);
+                self.visit_texpr(& $($mutability)? binary_expr.right);
+            }
+
+            fn super_unary_expr(&mut self, unary_expr: & $($mutability)? UnaryExpr) {
+                self.visit_texpr(& $($mutability)? unary_expr.operand);
+            }
+
+            fn super_literal(&mut self, literal: & $($mutability)? Literal) {
+                if let Literal::Array(array_literal) = literal {
+                    for element in & $($mutability)? array_literal.elements {
+                        self.visit_texpr(element);
+                    }
+                }
+            }
+
+            fn super_cast(&mut self, cast: & $($mutability)? Cast) {
+                self.visit_texpr(& $($mutability)? cast.operand);
+            }
+
+            fn super_identifier(&mut self, identifier: & $($mutability)? Identifier) {
+                self.visit_symbol(& $($mutability)? identifier.symbol);
+            }
+
+            fn super_index_expression(&mut self, index_expr: & $($mutability)? IndexExpression) {
+                self.visit_texpr(& $($mutability)? index_expr.expr);
+                self.visit_index_operator(& $($mutability)? index_expr.index);
+            }
+
+            fn super_indexed_identifier(
+                &mut self,
+                indexed_identifier: & $($mutability)? IndexedIdentifier,
+            ) {
+                self.visit_symbol(& $($mutability)? indexed_identifier.identifier);
+                for index in & $($mutability)? indexed_identifier.indexes {
+                    self.visit_index_operator(index);
+                }
+            }
+
+            fn super_index_operator(&mut self, index: & $($mutability)? IndexOperator) {
+                match index {
+                    IndexOperator::SetExpression(set_expr) => self.visit_set_expression(set_expr),
+                    IndexOperator::ExpressionList(list) => {
+                        for texpr in & $($mutability)? list.expressions {
+                            self.visit_texpr(texpr);
+                        }
+                    }
+                }
+            }
+
+            fn super_register_slice(&mut self, register_slice: & $($mutability)? RegisterSlice) {
+                self.visit_symbol(& $($mutability)? register_slice.name);
+                match & $($mutability)? register_slice.index {
+                    RegisterIndex::Expr(texpr) => self.visit_texpr(texpr),
+                    RegisterIndex::Range(range) => self.visit_range(range),
+                    RegisterIndex::Set(set_expr) => self.visit_set_expression(set_expr),
+                }
+            }
+
+            fn super_gate_operand(&mut self, gate_operand: & $($mutability)? GateOperand) {
+                match gate_operand {
+                    GateOperand::Identifier(identifier) => self.visit_identifier(identifier),
+                    GateOperand::HardwareQubit(hardware_qubit) => {
+                        self.visit_hardware_qubit(hardware_qubit)
+                    }
+                    GateOperand::IndexedIdentifier(indexed_identifier) => {
+                        self.visit_indexed_identifier(indexed_identifier)
+                    }
+                }
+            }
+
+            fn super_range(&mut self, range: & $($mutability)? Range) {
+                self.visit_texpr(& $($mutability)? range.start);
+                if let Some(step) = & $($mutability)? *range.step {
+                    self.visit_texpr(step);
+                }
+                self.visit_texpr(& $($mutability)? range.stop);
+            }
+
+            fn super_set_expression(&mut self, set_expr: & $($mutability)? SetExpression) {
+                for texpr in & $($mutability)? set_expr.expressions {
+                    self.visit_texpr(texpr);
+                }
+            }
+
+            fn super_call(&mut self, call: & $($mutability)? Call) {
+                self.visit_symbol(& $($mutability)? call.name);
+                for arg in & $($mutability)? call.args {
+                    self.visit_texpr(arg);
+                }
+            }
+
+            fn super_builtin_call(&mut self, call: & $($mutability)? BuiltinCall) {
+                for arg in & $($mutability)? call.args {
+                    self.visit_texpr(arg);
+                }
+            }
+
+            fn super_sizeof(&mut self, sizeof: & $($mutability)? SizeOf) {
+                self.visit_texpr(& $($mutability)? sizeof.array);
+                if let Some(dim) = & $($mutability)? sizeof.dim {
+                    self.visit_texpr(dim);
+                }
+            }
+
+            fn super_measure(&mut self, measure: & $($mutability)? MeasureExpression) {
+                self.visit_texpr(& $($mutability)? measure.operand);
+            }
+
+            fn super_duration_of(&mut self, duration_of: & $($mutability)? DurationOf) {
+                self.visit_block(& $($mutability)? duration_of.scope);
+            }
+
+            fn super_concatenation(&mut self, concatenation: & $($mutability)? Concatenation) {
+                for operand in & $($mutability)? concatenation.operands {
+                    self.visit_texpr(operand);
+                }
+            }
+        }
+    };
+}
+
+make_visitor!(Visitor,);
+make_visitor!(VisitorMut, mut);
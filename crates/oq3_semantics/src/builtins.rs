@@ -0,0 +1,210 @@
+// Copyright contributors to the openqasm-parser project
+// SPDX-License-Identifier: Apache-2.0
+
+//! The constants and functions that are built into OpenQASM 3.
+//!
+//! These are bound in the global scope before a program is analyzed. See `define_builtins`
+//! in context.rs. Most of the functions accept arguments of more than one type, so the type
+//! of a call is computed from the types of its arguments by [`BuiltinFunction::return_type`].
+
+use crate::types::{self, ArrayDims, IsConst, Type};
+
+/// A built-in constant. Each has an ASCII name and a Unicode name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BuiltinConstant {
+    Pi,
+    Tau,
+    Euler,
+}
+
+impl BuiltinConstant {
+    pub const ALL: [BuiltinConstant; 3] = [
+        BuiltinConstant::Pi,
+        BuiltinConstant::Tau,
+        BuiltinConstant::Euler,
+    ];
+
+    /// The ASCII and Unicode names of the constant.
+    pub fn names(&self) -> [&'static str; 2] {
+        match self {
+            BuiltinConstant::Pi => ["pi", "π"],
+            BuiltinConstant::Tau => ["tau", "τ"],
+            BuiltinConstant::Euler => ["euler", "ℇ"],
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        match self {
+            BuiltinConstant::Pi => std::f64::consts::PI,
+            BuiltinConstant::Tau => std::f64::consts::TAU,
+            BuiltinConstant::Euler => std::f64::consts::E,
+        }
+    }
+
+    /// The built-in constants are of type `const float`.
+    pub fn get_type(&self) -> Type {
+        Type::Float(None, IsConst::True)
+    }
+}
+
+/// A built-in function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BuiltinFunction {
+    Arccos,
+    Arcsin,
+    Arctan,
+    Ceiling,
+    Cos,
+    Exp,
+    Floor,
+    Imag,
+    Log,
+    Mod,
+    Popcount,
+    Real,
+    Rotl,
+    Rotr,
+    Sin,
+    Sqrt,
+    Tan,
+}
+
+impl BuiltinFunction {
+    pub const ALL: [BuiltinFunction; 17] = [
+        BuiltinFunction::Arccos,
+        BuiltinFunction::Arcsin,
+        BuiltinFunction::Arctan,
+        BuiltinFunction::Ceiling,
+        BuiltinFunction::Cos,
+        BuiltinFunction::Exp,
+        BuiltinFunction::Floor,
+        BuiltinFunction::Imag,
+        BuiltinFunction::Log,
+        BuiltinFunction::Mod,
+        BuiltinFunction::Popcount,
+        BuiltinFunction::Real,
+        BuiltinFunction::Rotl,
+        BuiltinFunction::Rotr,
+        BuiltinFunction::Sin,
+        BuiltinFunction::Sqrt,
+        BuiltinFunction::Tan,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        use BuiltinFunction::*;
+        match self {
+            Arccos => "arccos",
+            Arcsin => "arcsin",
+            Arctan => "arctan",
+            Ceiling => "ceiling",
+            Cos => "cos",
+            Exp => "exp",
+            Floor => "floor",
+            Imag => "imag",
+            Log => "log",
+            Mod => "mod",
+            Popcount => "popcount",
+            Real => "real",
+            Rotl => "rotl",
+            Rotr => "rotr",
+            Sin => "sin",
+            Sqrt => "sqrt",
+            Tan => "tan",
+        }
+    }
+
+    pub fn num_args(&self) -> usize {
+        match self {
+            BuiltinFunction::Mod | BuiltinFunction::Rotl | BuiltinFunction::Rotr => 2,
+            _ => 1,
+        }
+    }
+
+    /// The type of the symbol bound to the function. This is the signature of the most
+    /// common use of the function. A call is checked with `return_type` instead.
+    pub fn signature(&self) -> Type {
+        use BuiltinFunction::*;
+        let float = Type::Float(None, IsConst::False);
+        let (params, return_type) = match self {
+            Imag | Real => (vec![Type::Complex(None, IsConst::False)], float),
+            Mod => (vec![float.clone(), float.clone()], float),
+            // A bit register of any length is accepted.
+            Popcount => (
+                vec![Type::BitArray(ArrayDims::D1(0), IsConst::False)],
+                Type::UInt(None, IsConst::False),
+            ),
+            Rotl | Rotr => (
+                vec![
+                    Type::UInt(None, IsConst::False),
+                    Type::Int(None, IsConst::False),
+                ],
+                Type::UInt(None, IsConst::False),
+            ),
+            _ => (vec![float.clone()], float),
+        };
+        Type::Subroutine(params, Box::new(return_type))
+    }
+
+    /// Return the type of a call to the function with arguments of types `arg_types`,
+    /// or `None` if the function does not accept arguments of these types. The call
+    /// is `const` if all of the arguments are `const`. It is assumed that the number of
+    /// arguments is correct.
+    pub fn return_type(&self, arg_types: &[Type]) -> Option<Type> {
+        use BuiltinFunction::*;
+        if arg_types
+            .iter()
+            .any(|typ| matches!(typ, Type::ToDo | Type::Undefined))
+        {
+            return Some(Type::ToDo);
+        }
+        let isconst = IsConst::from(arg_types.iter().all(Type::is_const));
+        let arg = &arg_types[0];
+        match self {
+            Arccos | Arcsin | Arctan | Ceiling | Floor | Log => real_result(arg, isconst),
+            // The trigonometric functions also accept an `angle`.
+            Cos | Sin | Tan => match arg {
+                Type::Angle(..) => Some(Type::Float(None, isconst)),
+                _ => real_result(arg, isconst),
+            },
+            Exp | Sqrt => match arg {
+                Type::Complex(width, _) => Some(Type::Complex(*width, isconst)),
+                _ => real_result(arg, isconst),
+            },
+            Imag | Real => match arg {
+                Type::Complex(width, _) => Some(Type::Float(*width, isconst)),
+                _ => None,
+            },
+            Mod => {
+                let typ = types::promote_types(arg, &arg_types[1]);
+                typ.is_real_numeric().then_some(typ)
+            }
+            Popcount => match arg {
+                Type::Bit(_) | Type::BitArray(ArrayDims::D1(_), _) => {
+                    Some(Type::UInt(None, isconst))
+                }
+                _ => None,
+            },
+            // The bits of a bit register or `uint` are rotated by an integer number of positions.
+            Rotl | Rotr => match arg {
+                Type::BitArray(ArrayDims::D1(_), _) | Type::UInt(..)
+                    if arg_types[1].is_integer() =>
+                {
+                    Some(types::with_constness(arg, isconst))
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+// The `float` result of a function of a real number. An integer argument is converted
+// to a `float` of unspecified width.
+fn real_result(arg: &Type, isconst: IsConst) -> Option<Type> {
+    match arg {
+        Type::Float(width, _) => Some(Type::Float(*width, isconst)),
+        Type::Int(..) | Type::UInt(..) => Some(Type::Float(None, isconst)),
+        _ => None,
+    }
+}
@@ -0,0 +1,277 @@
+// Copyright contributors to the openqasm-parser project
+// SPDX-License-Identifier: Apache-2.0
+
+//! Evaluation of constant expressions in the ASG.
+//!
+//! Designators, array dimensions, and the arguments of `ctrl` and `negctrl` must be
+//! known at compile time. The functions here compute the values of such expressions.
+//! An expression is constant if it is built from literals, symbols declared `const`,
+//! operators, casts, `sizeof`, and calls to built-in functions.
+
+use std::collections::HashMap;
+
+use crate::asg::{ArithOp, BinaryOp, CmpOp, Expr, Literal, LogicOp, TExpr, UnaryOp};
+use crate::builtins::BuiltinFunction;
+use crate::symbols::SymbolId;
+use crate::types::Type;
+
+/// The value of a constant expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConstValue {
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+}
+
+impl ConstValue {
+    /// Return the value as a `usize` if it is a non-negative integer.
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            ConstValue::Int(n) => usize::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+
+    /// Return `true` if the values are equal as numbers. For example, `1` equals `1.0`.
+    pub fn equals_number(&self, other: &ConstValue) -> bool {
+        self.as_f64() == other.as_f64()
+    }
+
+    fn as_f64(&self) -> f64 {
+        match self {
+            ConstValue::Bool(b) => f64::from(u8::from(*b)),
+            ConstValue::Int(n) => *n as f64,
+            ConstValue::Float(x) => *x,
+        }
+    }
+
+    fn as_bool(&self) -> bool {
+        match self {
+            ConstValue::Bool(b) => *b,
+            ConstValue::Int(n) => *n != 0,
+            ConstValue::Float(x) => *x != 0.0,
+        }
+    }
+
+    /// Convert the value to a value of type `typ`, as when it is assigned to a
+    /// variable of that type. Return `None` if there is no such conversion.
+    pub fn cast(&self, typ: &Type) -> Option<ConstValue> {
+        let value = match typ {
+            Type::Bool(_) | Type::Bit(_) => ConstValue::Bool(self.as_bool()),
+            Type::Int(..) | Type::UInt(..) => match self {
+                ConstValue::Bool(b) => ConstValue::Int(i128::from(*b)),
+                ConstValue::Int(n) => ConstValue::Int(*n),
+                ConstValue::Float(x) if x.is_finite() => ConstValue::Int(x.trunc() as i128),
+                ConstValue::Float(_) => return None,
+            },
+            Type::Float(..) | Type::Angle(..) => ConstValue::Float(self.as_f64()),
+            // The value of a bit register is the integer whose binary digits are its bits.
+            Type::BitArray(..) => {
+                let n = match self {
+                    ConstValue::Bool(b) => i128::from(*b),
+                    ConstValue::Int(n) => *n,
+                    ConstValue::Float(_) => return None,
+                };
+                match typ.dims()?[0] {
+                    width if width < 127 => ConstValue::Int(n & ((1 << width) - 1)),
+                    _ => ConstValue::Int(n),
+                }
+            }
+            _ => return None,
+        };
+        Some(value)
+    }
+}
+
+/// Values of the symbols declared `const` whose initializers are constant.
+pub type ConstValues = HashMap<SymbolId, ConstValue>;
+
+/// Return the value of `texpr` if it is a constant expression. Otherwise return `None`.
+/// `const_values` holds the values of the constant symbols in scope.
+pub fn eval_const(texpr: &TExpr, const_values: &ConstValues) -> Option<ConstValue> {
+    match texpr.expression() {
+        Expr::Literal(literal) => eval_literal(literal),
+        Expr::Identifier(identifier) => const_values
+            .get(identifier.symbol().as_ref().ok()?)
+            .cloned(),
+        Expr::Cast(cast) => eval_const(cast.operand(), const_values)?.cast(cast.get_type()),
+        Expr::UnaryExpr(unary_expr) => {
+            let operand = eval_const(unary_expr.operand(), const_values)?;
+            eval_unary(unary_expr.op(), operand, texpr.get_type())
+        }
+        Expr::BinaryExpr(binary_expr) => {
+            let left = eval_const(binary_expr.left(), const_values)?;
+            let right = eval_const(binary_expr.right(), const_values)?;
+            eval_binary(binary_expr.op(), left, right)
+        }
+        Expr::SizeOf(sizeof) => {
+            let dims = sizeof.array().get_type().dims()?;
+            let dim = match sizeof.dim() {
+                Some(dim) => eval_const(dim, const_values)?.as_usize()?,
+                None => 0,
+            };
+            dims.get(dim).map(|size| ConstValue::Int(*size as i128))
+        }
+        Expr::BuiltinCall(call) => {
+            // A call with the wrong number of arguments has been reported, but is kept in the ASG.
+            if call.args().len() != call.function().num_args() {
+                return None;
+            }
+            let args = call
+                .args()
+                .iter()
+                .map(|arg| eval_const(arg, const_values))
+                .collect::<Option<Vec<_>>>()?;
+            eval_builtin(call.function(), &args, call.args()[0].get_type())
+        }
+        _ => None,
+    }
+}
+
+fn eval_literal(literal: &Literal) -> Option<ConstValue> {
+    let value = match literal {
+        Literal::Bool(b) => ConstValue::Bool(*b.value()),
+        Literal::Int(n) => ConstValue::Int(i128::try_from(*n.value()).ok()?),
+        Literal::Float(x) => ConstValue::Float(x.value().parse().ok()?),
+        Literal::BitString(bits) => ConstValue::Int(i128::from_str_radix(bits.value(), 2).ok()?),
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn eval_unary(op: &UnaryOp, operand: ConstValue, typ: &Type) -> Option<ConstValue> {
+    use ConstValue::*;
+    let value = match (op, operand) {
+        (UnaryOp::Minus, Int(n)) => Int(n.checked_neg()?),
+        (UnaryOp::Minus, Float(x)) => Float(-x),
+        (UnaryOp::Not, operand) => Bool(!operand.as_bool()),
+        (UnaryOp::BitNot, Int(n)) => match typ {
+            // Only the low `width` bits of an unsigned integer are set.
+            Type::UInt(Some(width), _) if *width < 128 => Int(!n & ((1 << width) - 1)),
+            _ => Int(!n),
+        },
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn eval_binary(op: &BinaryOp, left: ConstValue, right: ConstValue) -> Option<ConstValue> {
+    use ConstValue::*;
+    let value = match op {
+        BinaryOp::ArithOp(op) => match (left, right) {
+            (Int(a), Int(b)) => eval_int_arith(op, a, b)?,
+            (Bool(_), _) | (_, Bool(_)) => return None,
+            (a, b) => eval_float_arith(op, a.as_f64(), b.as_f64())?,
+        },
+        BinaryOp::CmpOp(op) => {
+            let ordering = match (&left, &right) {
+                (Int(a), Int(b)) => a.partial_cmp(b),
+                (Bool(a), Bool(b)) => a.partial_cmp(b),
+                (a, b) => a.as_f64().partial_cmp(&b.as_f64()),
+            }?;
+            Bool(match op {
+                CmpOp::Eq => ordering.is_eq(),
+                CmpOp::Neq => ordering.is_ne(),
+                CmpOp::Lt => ordering.is_lt(),
+                CmpOp::Gt => ordering.is_gt(),
+                CmpOp::Leq => ordering.is_le(),
+                CmpOp::Geq => ordering.is_ge(),
+            })
+        }
+        BinaryOp::LogicOp(op) => Bool(match op {
+            LogicOp::And => left.as_bool() && right.as_bool(),
+            LogicOp::Or => left.as_bool() || right.as_bool(),
+        }),
+    };
+    Some(value)
+}
+
+fn eval_int_arith(op: &ArithOp, a: i128, b: i128) -> Option<ConstValue> {
+    let value = match op {
+        ArithOp::Add => a.checked_add(b)?,
+        ArithOp::Sub => a.checked_sub(b)?,
+        ArithOp::Mul => a.checked_mul(b)?,
+        ArithOp::Div => a.checked_div(b)?,
+        ArithOp::Mod | ArithOp::Rem => a.checked_rem(b)?,
+        // A negative exponent gives a `float`.
+        ArithOp::Pow if b < 0 => return eval_float_arith(op, a as f64, b as f64),
+        ArithOp::Pow => a.checked_pow(u32::try_from(b).ok()?)?,
+        ArithOp::Shl => a.checked_shl(u32::try_from(b).ok()?)?,
+        ArithOp::Shr => a.checked_shr(u32::try_from(b).ok()?)?,
+        ArithOp::BitXOr => a ^ b,
+        ArithOp::BitOr => a | b,
+        ArithOp::BitAnd => a & b,
+    };
+    Some(ConstValue::Int(value))
+}
+
+fn eval_float_arith(op: &ArithOp, a: f64, b: f64) -> Option<ConstValue> {
+    let value = match op {
+        ArithOp::Add => a + b,
+        ArithOp::Sub => a - b,
+        ArithOp::Mul => a * b,
+        ArithOp::Div => a / b,
+        ArithOp::Mod | ArithOp::Rem => a % b,
+        ArithOp::Pow => a.powf(b),
+        _ => return None,
+    };
+    Some(ConstValue::Float(value))
+}
+
+// The value of a call to `function`. `arg_type` is the type of the first argument, which
+// gives the width of the value rotated by `rotl` and `rotr`. An integer literal is a
+// `uint[128]`, so it is rotated within 128 bits. Functions of complex numbers are not evaluated.
+fn eval_builtin(
+    function: &BuiltinFunction,
+    args: &[ConstValue],
+    arg_type: &Type,
+) -> Option<ConstValue> {
+    use BuiltinFunction::*;
+    use ConstValue::*;
+    let value = match (function, args) {
+        (Mod, [Int(a), Int(b)]) => Int(a.checked_rem_euclid(*b)?),
+        (Mod, [a, b]) => Float(a.as_f64().rem_euclid(b.as_f64())),
+        (Popcount, [Int(n)]) => Int(i128::from(n.count_ones())),
+        (Popcount, [Bool(b)]) => Int(i128::from(*b)),
+        (Rotl | Rotr, [Int(n), Int(shift)]) => {
+            let width = match arg_type {
+                Type::BitArray(..) => arg_type.dims()?[0] as u32,
+                _ => arg_type.width()?,
+            };
+            if width == 0 || width > 128 {
+                return None;
+            }
+            let shift = shift.rem_euclid(i128::from(width)) as u32;
+            let shift = if *function == Rotl {
+                shift
+            } else {
+                (width - shift) % width
+            };
+            let mask = u128::MAX >> (128 - width);
+            let n = (*n as u128) & mask;
+            let rotated = ((n << shift) | n.checked_shr(width - shift).unwrap_or(0)) & mask;
+            // A value that needs all 128 bits is not represented.
+            Int(i128::try_from(rotated).ok()?)
+        }
+        (Rotl | Rotr | Popcount | Real | Imag, _) => return None,
+        (function, [x]) => {
+            let x = x.as_f64();
+            Float(match function {
+                Arccos => x.acos(),
+                Arcsin => x.asin(),
+                Arctan => x.atan(),
+                Ceiling => x.ceil(),
+                Cos => x.cos(),
+                Exp => x.exp(),
+                Floor => x.floor(),
+                Log => x.ln(),
+                Sin => x.sin(),
+                Sqrt => x.sqrt(),
+                Tan => x.tan(),
+                _ => return None,
+            })
+        }
+        _ => return None,
+    };
+    Some(value)
+}
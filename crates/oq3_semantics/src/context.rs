@@ -2,11 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::asg;
+use crate::builtins::{BuiltinConstant, BuiltinFunction};
+use crate::const_eval::{eval_const, ConstValue, ConstValues};
 use crate::semantic_error::SemanticErrorKind::*;
 use crate::semantic_error::{SemanticErrorKind, SemanticErrorList};
-use crate::symbols::{ScopeType, SymbolIdResult, SymbolRecordResult, SymbolTable};
+use crate::span::{FileId, Span};
+use crate::symbols::{ScopeType, SymbolId, SymbolIdResult, SymbolRecordResult, SymbolTable};
 use crate::types::{IsConst, Type};
 use oq3_syntax::ast::AstNode;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[derive(Clone, Debug)]
@@ -14,16 +18,32 @@ pub struct Context {
     pub program: asg::Program,
     pub semantic_errors: SemanticErrorList,
     pub symbol_table: SymbolTable,
+    // Return type of the subroutine whose body is being analyzed, or `None`
+    // outside of a subroutine definition.
+    pub(crate) return_type: Option<Type>,
+    // Values of the symbols declared `const` whose initializers are constant.
+    pub(crate) const_values: ConstValues,
+    // The built-in functions, by the symbols to which they are bound.
+    pub(crate) builtin_functions: HashMap<SymbolId, BuiltinFunction>,
+    // The file whose source is being analyzed. This changes while analyzing an included file.
+    pub(crate) file_id: FileId,
 }
 
 impl Context {
     pub(crate) fn new(file_path: PathBuf) -> Context {
+        let mut program = asg::Program::new();
+        let file_id = program.add_file_path(&file_path);
         let mut context = Context {
-            program: asg::Program::new(),
+            program,
             semantic_errors: SemanticErrorList::new(file_path),
             symbol_table: SymbolTable::new(),
+            return_type: None,
+            const_values: ConstValues::new(),
+            builtin_functions: HashMap::new(),
+            file_id,
         };
         define_U_gate(&mut context);
+        define_builtins(&mut context);
         context
     }
 
@@ -75,13 +95,44 @@ impl Context {
     where
         T: AstNode,
     {
-        //        let symbol_id_result = self.symbol_table.new_binding(name, typ, node.syntax());
-        let symbol_id_result = self.symbol_table.new_binding(name, typ);
+        let span = self.span(node);
+        let symbol_id_result = self.symbol_table.new_binding_with_span(name, typ, span);
         if symbol_id_result.is_err() {
             self.semantic_errors.insert(RedeclarationError, node);
         }
         symbol_id_result
     }
+
+    /// Return the span of the source text of `node` in the file being analyzed.
+    pub fn span<T>(&self, node: &T) -> Span
+    where
+        T: AstNode,
+    {
+        Span::new(self.file_id, node.syntax().text_range())
+    }
+
+    /// Return the value of `texpr` if it is a constant expression.
+    pub fn eval_const(&self, texpr: &asg::TExpr) -> Option<ConstValue> {
+        eval_const(texpr, &self.const_values)
+    }
+
+    /// Return the values of the constant symbols, including built-in constants such as `pi`.
+    pub fn const_values(&self) -> &ConstValues {
+        &self.const_values
+    }
+
+    /// Return the built-in function bound to `symbol_id`, if there is one.
+    pub(crate) fn builtin_function(&self, symbol_id: &SymbolIdResult) -> Option<BuiltinFunction> {
+        self.builtin_functions
+            .get(symbol_id.as_ref().ok()?)
+            .copied()
+    }
+
+    /// Define the gates that are builtin in OpenQASM 2 but not in OpenQASM 3.
+    /// `U` is builtin in both versions.
+    pub(crate) fn define_openqasm2_builtins(&mut self) {
+        define_CX_gate(self);
+    }
 }
 
 #[macro_export]
@@ -102,7 +153,7 @@ macro_rules! with_scope {
 
 #[allow(non_snake_case)]
 fn define_U_gate(context: &mut Context) {
-    let symbol_id_result = context.symbol_table.new_binding("U", &Type::Gate);
+    let symbol_id_result = context.symbol_table.new_binding("U", &Type::Gate(3, 1));
     if symbol_id_result.is_err() {
         panic!("U already defined when defining U gate");
     }
@@ -124,3 +175,43 @@ fn define_U_gate(context: &mut Context) {
     let ugate = asg::GateDeclaration::new(symbol_id_result, Some(params), qbits, block).to_stmt();
     context.program.insert_stmt(ugate);
 }
+
+// Bind the built-in constants and functions. The values of the constants are recorded so
+// that they may be used in constant expressions.
+fn define_builtins(context: &mut Context) {
+    for constant in BuiltinConstant::ALL {
+        for name in constant.names() {
+            let symbol_id = context
+                .symbol_table
+                .new_binding(name, &constant.get_type())
+                .unwrap_or_else(|_| panic!("{name} already defined when defining builtins"));
+            context
+                .const_values
+                .insert(symbol_id, ConstValue::Float(constant.value()));
+        }
+    }
+    for function in BuiltinFunction::ALL {
+        let name = function.name();
+        let symbol_id = context
+            .symbol_table
+            .new_binding(name, &function.signature())
+            .unwrap_or_else(|_| panic!("{name} already defined when defining builtins"));
+        context.builtin_functions.insert(symbol_id, function);
+    }
+}
+
+#[allow(non_snake_case)]
+fn define_CX_gate(context: &mut Context) {
+    let symbol_id_result = context.symbol_table.new_binding("CX", &Type::Gate(0, 2));
+    if symbol_id_result.is_err() {
+        panic!("CX already defined when defining CX gate");
+    }
+    context.symbol_table.enter_scope(ScopeType::Subroutine);
+    let c = context.symbol_table.new_binding("c", &Type::Qubit);
+    let t = context.symbol_table.new_binding("t", &Type::Qubit);
+    context.symbol_table.exit_scope();
+    let qbits = vec![c, t];
+    let block = asg::Block::new();
+    let cxgate = asg::GateDeclaration::new(symbol_id_result, None, qbits, block).to_stmt();
+    context.program.insert_stmt(cxgate);
+}
@@ -1,27 +1,862 @@
 // Copyright contributors to the openqasm-parser project
 // SPDX-License-Identifier: Apache-2.0
 
-// Unused at the moment.
-// This intent here is to print something like source code.
-// But this involves a ton of boiler plate.
+//! Printing the ASG as OpenQASM 3 source.
+//!
+//! Symbols are printed with the names recorded in the `SymbolTable`. Casts inserted by type
+//! promotion are not printed, because analyzing the printed source inserts them again.
+//! Parentheses are inserted only where the precedence of operators requires them. Analyzing
+//! the printed source gives the same program, which prints to the same text.
+//!
+//! A program with an `OPENQASM 2.0` header is printed with the syntax of OpenQASM 2: registers
+//! are declared with `qreg` and `creg`, measurements are written `measure q -> c;`, and no casts
+//! are printed.
+//!
+//! Some information is not recorded in the ASG, and so is not printed. Comments and
+//! formatting are lost. `include` statements are replaced by the statements of the included
+//! file. The gates that are built into the language, such as `U`, are not printed.
+
+use std::fmt::Write;
 
-use std::fmt;
 use crate::asg::*;
+use crate::symbols::{SymbolIdResult, SymbolTable, SymbolType};
+use crate::types::{ArrayDims, IOType, IsConst, Type};
+
+const INDENT: &str = "    ";
+
+/// Return the OpenQASM 3 source of `program`. `symbol_table` is the table
+/// constructed along with `program`.
+pub fn program_to_source(program: &Program, symbol_table: &SymbolTable) -> String {
+    let mut printer = Printer::new(symbol_table);
+    printer.program(program);
+    printer.out
+}
+
+/// Return the OpenQASM 3 source of the statement `stmt`.
+pub fn stmt_to_source(stmt: &Stmt, symbol_table: &SymbolTable) -> String {
+    let mut printer = Printer::new(symbol_table);
+    printer.stmt(stmt);
+    printer.out
+}
+
+/// Return the OpenQASM 3 source of the expression `texpr`.
+pub fn texpr_to_source(texpr: &TExpr, symbol_table: &SymbolTable) -> String {
+    let mut printer = Printer::new(symbol_table);
+    printer.texpr(texpr);
+    printer.out
+}
+
+/// Return the OpenQASM 3 spelling of `typ`, without `const`. For example `int[32]` or
+/// `array[float[64], 2, 3]`. Types that can't be written in source, such as the
+/// signature of a gate, are written in their debug representation.
+pub fn type_to_source(typ: &Type) -> String {
+    let scalar = |name: &str, width: &Option<u32>| match width {
+        Some(width) => format!("{name}[{width}]"),
+        None => name.to_string(),
+    };
+    let complex = |width: &Option<u32>| match width {
+        Some(width) => format!("complex[float[{width}]]"),
+        None => "complex".to_string(),
+    };
+    let array = |element: String, dims: &ArrayDims| {
+        let dims: Vec<_> = dims.dims().iter().map(ToString::to_string).collect();
+        format!("array[{element}, {}]", dims.join(", "))
+    };
+    match typ {
+        Type::Bit(_) => "bit".to_string(),
+        Type::Qubit => "qubit".to_string(),
+        Type::Int(width, _) => scalar("int", width),
+        Type::UInt(width, _) => scalar("uint", width),
+        Type::Float(width, _) => scalar("float", width),
+        Type::Angle(width, _) => scalar("angle", width),
+        Type::Complex(width, _) => complex(width),
+        Type::Bool(_) => "bool".to_string(),
+        Type::Duration(_) => "duration".to_string(),
+        Type::Stretch(_) => "stretch".to_string(),
+        Type::BitArray(ArrayDims::D1(len), _) => format!("bit[{len}]"),
+        Type::BitArray(dims, _) => array("bit".to_string(), dims),
+        Type::QubitArray(ArrayDims::D1(len)) => format!("qubit[{len}]"),
+        Type::IntArray(dims, width) => array(scalar("int", width), dims),
+        Type::UIntArray(dims, width) => array(scalar("uint", width), dims),
+        Type::FloatArray(dims, width) => array(scalar("float", width), dims),
+        Type::AngleArray(dims, width) => array(scalar("angle", width), dims),
+        Type::ComplexArray(dims, width) => array(complex(width), dims),
+        Type::BoolArray(dims) => array("bool".to_string(), dims),
+        Type::DurationArray(dims) => array("duration".to_string(), dims),
+        Type::UnsizedArray(element_type, num_dims, _) => {
+            format!("array[{}, #dim = {num_dims}]", type_to_source(element_type))
+        }
+        _ => format!("{typ:?}"),
+    }
+}
+
+// The binding power of an expression, as in the parser. An operand whose binding power
+// is lower than that of its operator must be parenthesized.
+fn precedence(expr: &Expr) -> u8 {
+    match expr {
+        Expr::Concatenation(_) => 2,
+        Expr::BinaryExpr(binary_expr) => match binary_expr.op() {
+            BinaryOp::LogicOp(LogicOp::Or) => 3,
+            BinaryOp::LogicOp(LogicOp::And) => 4,
+            BinaryOp::CmpOp(_) => 5,
+            BinaryOp::ArithOp(op) => match op {
+                ArithOp::BitOr => 6,
+                ArithOp::BitXOr => 7,
+                ArithOp::BitAnd => 8,
+                ArithOp::Shl | ArithOp::Shr => 9,
+                ArithOp::Add | ArithOp::Sub => 10,
+                ArithOp::Mul | ArithOp::Div | ArithOp::Mod | ArithOp::Rem => 11,
+                ArithOp::Pow => 13,
+            },
+        },
+        // Only `**` binds more tightly than a unary operator.
+        Expr::UnaryExpr(_) => 12,
+        _ => u8::MAX,
+    }
+}
 
-impl fmt::Display for Include {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "include \"{}\"",  self.file_path())
+fn arith_op_str(op: &ArithOp) -> &'static str {
+    match op {
+        ArithOp::Add => "+",
+        ArithOp::Sub => "-",
+        ArithOp::Mul => "*",
+        ArithOp::Div => "/",
+        ArithOp::Mod | ArithOp::Rem => "%",
+        ArithOp::Pow => "**",
+        ArithOp::Shl => "<<",
+        ArithOp::Shr => ">>",
+        ArithOp::BitXOr => "^",
+        ArithOp::BitOr => "|",
+        ArithOp::BitAnd => "&",
     }
 }
 
-impl fmt::Display for Stmt {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
+fn binary_op_str(op: &BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::ArithOp(op) => arith_op_str(op),
+        BinaryOp::CmpOp(op) => match op {
+            CmpOp::Eq => "==",
+            CmpOp::Neq => "!=",
+            CmpOp::Lt => "<",
+            CmpOp::Gt => ">",
+            CmpOp::Leq => "<=",
+            CmpOp::Geq => ">=",
+        },
+        BinaryOp::LogicOp(LogicOp::And) => "&&",
+        BinaryOp::LogicOp(LogicOp::Or) => "||",
+    }
+}
+
+// A float literal is stored without a decimal point if its value is integral.
+// It needs one to be read back as a float.
+fn float_str(value: &str) -> String {
+    if value.contains(|c: char| matches!(c, '.' | 'e' | 'E' | 'i' | 'N')) {
+        value.to_string()
+    } else {
+        format!("{value}.0")
+    }
+}
+
+struct Printer<'a> {
+    symbol_table: &'a SymbolTable,
+    out: String,
+    indent: usize,
+    // Print OpenQASM 2 syntax where it exists.
+    openqasm2: bool,
+}
+
+impl<'a> Printer<'a> {
+    fn new(symbol_table: &'a SymbolTable) -> Printer<'a> {
+        Printer {
+            symbol_table,
+            out: String::new(),
+            indent: 0,
+            openqasm2: false,
+        }
+    }
+
+    fn push(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    // Start a new line at the current indentation.
+    fn line(&mut self) {
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    // The name of a symbol. A name that failed to resolve is not recorded, and is printed as `?`.
+    fn name(&mut self, symbol_id: &SymbolIdResult) {
+        match symbol_id {
+            Ok(symbol_id) => {
+                let name = self.symbol_table[symbol_id].name().to_string();
+                self.push(&name);
+            }
+            Err(_) => self.push("?"),
+        }
+    }
+
+    fn symbol_type(&self, symbol_id: &SymbolIdResult) -> Type {
+        match symbol_id {
+            Ok(symbol_id) => self.symbol_table[symbol_id].symbol_type().clone(),
+            Err(_) => Type::Undefined,
+        }
+    }
+
+    // Items separated by commas.
+    fn list<T>(&mut self, items: &[T], mut item: impl FnMut(&mut Self, &T)) {
+        for (i, x) in items.iter().enumerate() {
+            if i > 0 {
+                self.push(", ");
+            }
+            item(self, x);
+        }
+    }
+
+    fn texprs(&mut self, texprs: &[TExpr]) {
+        self.list(texprs, Self::texpr);
+    }
+
+    fn names(&mut self, symbol_ids: &[SymbolIdResult]) {
+        self.list(symbol_ids, Self::name);
+    }
+
+    fn typ(&mut self, typ: &Type) {
+        self.push(&type_to_source(typ));
+    }
+
+    // A built-in gate is bound before the program is analyzed, so it has no span.
+    fn is_builtin_gate(&self, stmt: &Stmt) -> bool {
+        match stmt {
+            Stmt::GateDeclaration(gate) => gate.name().as_ref().is_ok_and(|symbol_id| {
+                let symbol = &self.symbol_table[symbol_id];
+                symbol.span().is_none() && matches!(symbol.name(), "U" | "CX")
+            }),
+            _ => false,
+        }
+    }
+
+    fn program(&mut self, program: &Program) {
+        self.openqasm2 = matches!(program.version(), Some(version) if version.major() == 2);
+        if let Some(version) = program.version() {
+            let _ = writeln!(
+                self.out,
+                "OPENQASM {}.{};",
+                version.major(),
+                version.minor()
+            );
+        }
+        if let Some(grammar) = program.calibration_grammar() {
+            let _ = writeln!(self.out, "defcalgrammar \"{grammar}\";");
+        }
+        for stmt in program.stmts() {
+            if !self.is_builtin_gate(stmt) {
+                self.stmt(stmt);
+            }
+        }
+    }
+
+    // Print `stmt` on its own line, or lines.
+    fn stmt(&mut self, stmt: &Stmt) {
+        self.line();
+        self.stmt_inner(stmt);
+        self.push("\n");
+    }
+
+    fn stmt_inner(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Alias(alias) => {
+                self.push("let ");
+                self.name(alias.name());
+                self.push(" = ");
+                self.texpr(alias.rvalue());
+                self.push(";");
+            }
+            Stmt::AnnotatedStmt(annotated) => {
+                for annotation in annotated.annotations() {
+                    self.push("@");
+                    self.push(annotation.kind());
+                    if let Some(body) = annotation.body() {
+                        self.push(" ");
+                        self.push(body);
+                    }
+                    self.push("\n");
+                    self.line();
+                }
+                self.stmt_inner(annotated.statement());
+            }
+            Stmt::Assignment(assignment)
+                if self.openqasm2
+                    && matches!(assignment.op(), AssignOp::Assign)
+                    && matches!(assignment.rvalue().expression(), Expr::Measure(_)) =>
+            {
+                self.texpr(assignment.rvalue());
+                self.push(" -> ");
+                self.lvalue(assignment.lvalue());
+                self.push(";");
+            }
+            Stmt::Assignment(assignment) => {
+                self.lvalue(assignment.lvalue());
+                match assignment.op() {
+                    AssignOp::Assign => self.push(" = "),
+                    AssignOp::Compound(op) => {
+                        let _ = write!(self.out, " {}= ", arith_op_str(op));
+                    }
+                }
+                self.texpr(assignment.rvalue());
+                self.push(";");
+            }
+            Stmt::Barrier(barrier) => {
+                self.push("barrier");
+                self.qubits(barrier.qubits());
+                self.push(";");
+            }
+            Stmt::Block(block) => self.block(block),
+            Stmt::Box(box_stmt) => {
+                self.push("box");
+                if let Some(duration) = box_stmt.duration() {
+                    self.designator(duration);
+                }
+                self.push(" ");
+                self.block(box_stmt.body());
+            }
+            Stmt::Break => self.push("break;"),
+            Stmt::Cal(cal) => {
+                let _ = write!(self.out, "cal {{{}}}", cal.body());
+            }
+            Stmt::Continue => self.push("continue;"),
+            Stmt::DeclareClassical(decl) if self.is_openqasm2_register(decl.name()) => {
+                self.push("creg ");
+                self.register(decl.name());
+            }
+            Stmt::DeclareClassical(decl) => {
+                self.declaration(decl.name());
+                if let Some(initializer) = decl.initializer() {
+                    self.push(" = ");
+                    self.texpr(initializer);
+                }
+                self.push(";");
+            }
+            Stmt::Def(def) => {
+                self.push("def ");
+                self.name(def.name());
+                self.push("(");
+                self.list(def.params(), |printer, param| {
+                    printer.param(param);
+                });
+                self.push(")");
+                if let Some(return_type) = def.return_type() {
+                    self.push(" -> ");
+                    self.typ(return_type);
+                }
+                self.push(" ");
+                self.block(def.block());
+            }
+            Stmt::DefCal(defcal) => self.defcal(defcal),
+            Stmt::Delay(delay) => {
+                self.push("delay");
+                self.designator(delay.duration());
+                self.qubits(delay.qubits());
+                self.push(";");
+            }
+            Stmt::End => self.push("end;"),
+            Stmt::ExprStmt(texpr) => {
+                self.texpr(texpr);
+                self.push(";");
+            }
+            Stmt::Extern(extern_stmt) => {
+                let _ = write!(self.out, "extern {}(", extern_stmt.name());
+                self.list(extern_stmt.params(), |printer, typ| printer.param_type(typ));
+                self.push(")");
+                if let Some(return_type) = extern_stmt.return_type() {
+                    self.push(" -> ");
+                    self.typ(return_type);
+                }
+                self.push(";");
+            }
+            Stmt::For(for_stmt) => {
+                let loop_var_type = self.symbol_type(for_stmt.loop_var());
+                self.push("for ");
+                self.typ(&loop_var_type);
+                self.push(" ");
+                self.name(for_stmt.loop_var());
+                self.push(" in ");
+                match for_stmt.iterable() {
+                    ForIterable::SetExpression(set_expression) => {
+                        self.set_expression(set_expression)
+                    }
+                    ForIterable::RangeExpression(range) => {
+                        self.push("[");
+                        self.range(range);
+                        self.push("]");
+                    }
+                    ForIterable::Expr(texpr) => self.texpr(texpr),
+                }
+                self.push(" ");
+                self.block(for_stmt.loop_body());
+            }
+            Stmt::GateDeclaration(gate) => {
+                self.push(if gate.is_opaque() { "opaque " } else { "gate " });
+                self.name(gate.name());
+                if let Some(params) = gate.params() {
+                    self.push("(");
+                    self.names(params);
+                    self.push(")");
+                }
+                self.push(" ");
+                self.names(gate.qubits());
+                if gate.is_opaque() {
+                    self.push(";");
+                } else {
+                    self.push(" ");
+                    self.block(gate.block());
+                }
+            }
+            Stmt::GateCall(gate_call) => {
+                for modifier in gate_call.modifiers() {
+                    self.gate_modifier(modifier);
+                    self.push(" @ ");
+                }
+                self.name(gate_call.name());
+                if let Some(params) = gate_call.params() {
+                    self.push("(");
+                    self.texprs(params);
+                    self.push(")");
+                }
+                self.push(" ");
+                self.texprs(gate_call.qubits());
+                self.push(";");
+            }
+            Stmt::GPhaseCall(gphase) => {
+                self.push("gphase(");
+                self.texpr(gphase.arg());
+                self.push(");");
+            }
+            Stmt::IODeclaration(io_decl) => {
+                match io_decl.io_type() {
+                    IOType::Input => self.push("input "),
+                    IOType::Output => self.push("output "),
+                    IOType::Neither => (),
+                }
+                self.typ(io_decl.get_type());
+                let _ = write!(self.out, " {};", io_decl.name());
+            }
+            Stmt::If(if_stmt) => {
+                self.push("if (");
+                self.texpr(if_stmt.condition());
+                self.push(") ");
+                match if_stmt.then_branch().statements().as_slice() {
+                    // OpenQASM 2 has no blocks, and no `else`.
+                    [stmt] if self.openqasm2 && if_stmt.else_branch().is_none() => {
+                        self.stmt_inner(stmt)
+                    }
+                    _ => self.block(if_stmt.then_branch()),
+                }
+                if let Some(else_branch) = if_stmt.else_branch() {
+                    self.push(" else ");
+                    self.block(else_branch);
+                }
+            }
             Stmt::Include(include) => {
-                write!(f, "{}", include);
+                let _ = write!(self.out, "include \"{}\";", include.file_path());
+            }
+            Stmt::NullStmt => self.push(";"),
+            Stmt::Pragma(pragma) => {
+                self.push("pragma ");
+                self.push(pragma.pragma());
+            }
+            Stmt::DeclareQuantum(decl) if self.is_openqasm2_register(decl.name()) => {
+                self.push("qreg ");
+                self.register(decl.name());
+            }
+            Stmt::DeclareQuantum(decl) => {
+                self.declaration(decl.name());
+                self.push(";");
+            }
+            Stmt::Reset(reset) => {
+                self.push("reset ");
+                self.texpr(reset.operand());
+                self.push(";");
+            }
+            Stmt::Return(return_stmt) => {
+                self.push("return");
+                if let Some(value) = return_stmt.value() {
+                    self.push(" ");
+                    self.texpr(value);
+                }
+                self.push(";");
+            }
+            Stmt::Switch(switch) => {
+                self.push("switch (");
+                self.texpr(switch.control());
+                self.push(") {\n");
+                self.indent += 1;
+                for case in switch.cases() {
+                    self.line();
+                    self.push("case ");
+                    self.texprs(case.labels());
+                    self.push(" ");
+                    self.block(case.block());
+                    self.push("\n");
+                }
+                if let Some(default_block) = switch.default_block() {
+                    self.line();
+                    self.push("default ");
+                    self.block(default_block);
+                    self.push("\n");
+                }
+                self.indent -= 1;
+                self.line();
+                self.push("}");
+            }
+            Stmt::While(while_stmt) => {
+                self.push("while (");
+                self.texpr(while_stmt.condition());
+                self.push(") ");
+                self.block(while_stmt.loop_body());
+            }
+        }
+    }
+
+    // Whether the variable `symbol_id` is declared in OpenQASM 2 as a `qreg` or a `creg`.
+    fn is_openqasm2_register(&self, symbol_id: &SymbolIdResult) -> bool {
+        self.openqasm2
+            && matches!(
+                self.symbol_type(symbol_id),
+                Type::QubitArray(ArrayDims::D1(_))
+                    | Type::BitArray(ArrayDims::D1(_), IsConst::False)
+            )
+    }
+
+    // The name and length of an OpenQASM 2 register, as in `q[2];`.
+    fn register(&mut self, symbol_id: &SymbolIdResult) {
+        let len = self.symbol_type(symbol_id).register_length().unwrap();
+        self.name(symbol_id);
+        let _ = write!(self.out, "[{len}];");
+    }
+
+    // The type and name of a declared variable. The type is that of its symbol.
+    fn declaration(&mut self, symbol_id: &SymbolIdResult) {
+        let typ = self.symbol_type(symbol_id);
+        if typ.is_const() && !typ.is_quantum() {
+            self.push("const ");
+        }
+        self.typ(&typ);
+        self.push(" ");
+        self.name(symbol_id);
+    }
+
+    // A parameter of a `def`.
+    fn param(&mut self, symbol_id: &SymbolIdResult) {
+        let typ = self.symbol_type(symbol_id);
+        self.param_type(&typ);
+        self.push(" ");
+        self.name(symbol_id);
+    }
+
+    // The type of a parameter of a `def` or `extern`. Arrays are passed by reference, so
+    // their type is qualified. Only a `bit` array, and an array declared with `#dim`,
+    // record whether it is `readonly`.
+    fn param_type(&mut self, typ: &Type) {
+        match typ {
+            Type::UnsizedArray(..) if typ.is_const() => {
+                self.push("readonly ");
+                self.typ(typ);
+            }
+            Type::UnsizedArray(..) => {
+                self.push("mutable ");
+                self.typ(typ);
+            }
+            Type::BitArray(ArrayDims::D1(len), _) if typ.is_const() => {
+                let _ = write!(self.out, "readonly array[bit, {len}]");
+            }
+            Type::BitArray(ArrayDims::D1(_), _) => self.typ(typ),
+            Type::BitArray(..) if !typ.is_const() => {
+                self.push("mutable ");
+                self.typ(typ);
+            }
+            _ if typ.is_classical_array() => {
+                self.push("readonly ");
+                self.typ(typ);
+            }
+            _ => self.typ(typ),
+        }
+    }
+
+    fn defcal(&mut self, defcal: &DefCal) {
+        let _ = write!(self.out, "defcal {}", defcal.name());
+        if !defcal.params().is_empty() {
+            self.push("(");
+            self.list(defcal.params(), |printer, param| match param {
+                DefCalParam::Typed(name, typ) => {
+                    printer.typ(typ);
+                    let _ = write!(printer.out, " {name}");
+                }
+                DefCalParam::Constant(texpr) => printer.texpr(texpr),
+            });
+            self.push(")");
+        }
+        self.push(" ");
+        self.list(defcal.qubits(), |printer, qubit| match qubit {
+            DefCalQubit::Hardware(index) => {
+                let _ = write!(printer.out, "${index}");
+            }
+            DefCalQubit::Identifier(name) => printer.push(name),
+        });
+        if let Some(return_type) = defcal.return_type() {
+            self.push(" -> ");
+            self.typ(return_type);
+        }
+        let _ = write!(self.out, " {{{}}}", defcal.body().unwrap_or_default());
+    }
+
+    fn block(&mut self, block: &Block) {
+        if block.statements().is_empty() {
+            self.push("{}");
+            return;
+        }
+        self.push("{\n");
+        self.indent += 1;
+        for stmt in block.statements() {
+            self.stmt(stmt);
+        }
+        self.indent -= 1;
+        self.line();
+        self.push("}");
+    }
+
+    fn designator(&mut self, texpr: &TExpr) {
+        self.push("[");
+        self.texpr(texpr);
+        self.push("]");
+    }
+
+    fn qubits(&mut self, qubits: &Option<Vec<TExpr>>) {
+        if let Some(qubits) = qubits {
+            if !qubits.is_empty() {
+                self.push(" ");
+                self.texprs(qubits);
+            }
+        }
+    }
+
+    fn gate_modifier(&mut self, modifier: &GateModifier) {
+        let (keyword, arg) = match modifier {
+            GateModifier::Inv => ("inv", None),
+            GateModifier::Pow(exponent) => ("pow", Some(exponent)),
+            GateModifier::Ctrl(num) => ("ctrl", num.as_ref()),
+            GateModifier::NegCtrl(num) => ("negctrl", num.as_ref()),
+        };
+        self.push(keyword);
+        if let Some(arg) = arg {
+            self.push("(");
+            self.texpr(arg);
+            self.push(")");
+        }
+    }
+
+    fn lvalue(&mut self, lvalue: &LValue) {
+        match lvalue {
+            LValue::Identifier(symbol_id) => self.name(symbol_id),
+            LValue::IndexedIdentifier(indexed_identifier) => {
+                self.indexed_identifier(indexed_identifier)
+            }
+            LValue::ArraySlice(array_slice) => self.array_slice(array_slice),
+            LValue::RegisterSlice(register_slice) => {
+                self.name(register_slice.name());
+                self.push("[");
+                match register_slice.index() {
+                    RegisterIndex::Expr(texpr) => self.texpr(texpr),
+                    RegisterIndex::Range(range) => self.range(range),
+                    RegisterIndex::Set(set_expression) => self.set_expression(set_expression),
+                }
+                self.push("]");
+            }
+        }
+    }
+
+    fn array_slice(&mut self, array_slice: &ArraySlice) {
+        self.name(array_slice.name());
+        self.push("[");
+        self.list(array_slice.indices(), |printer, index| match index {
+            ArraySliceIndex::Expr(texpr) | ArraySliceIndex::Range(texpr) => printer.texpr(texpr),
+        });
+        self.push("]");
+    }
+
+    fn indexed_identifier(&mut self, indexed_identifier: &IndexedIdentifier) {
+        self.name(indexed_identifier.identifier());
+        for index in indexed_identifier.indexes() {
+            self.index_operator(index);
+        }
+    }
+
+    fn index_operator(&mut self, index: &IndexOperator) {
+        self.push("[");
+        match index {
+            IndexOperator::SetExpression(set_expression) => self.set_expression(set_expression),
+            IndexOperator::ExpressionList(list) => self.texprs(&list.expressions),
+        }
+        self.push("]");
+    }
+
+    fn set_expression(&mut self, set_expression: &SetExpression) {
+        self.push("{");
+        self.texprs(set_expression.expressions());
+        self.push("}");
+    }
+
+    fn range(&mut self, range: &Range) {
+        self.texpr(range.start());
+        self.push(":");
+        if let Some(step) = range.step() {
+            self.texpr(step);
+            self.push(":");
+        }
+        self.texpr(range.stop());
+    }
+
+    // The expression printed for `texpr`. OpenQASM 2 has no casts, so only the operand
+    // of a cast is printed.
+    // OpenQASM 2 has no casts. An implicit cast is inserted again when the printed source
+    // is analyzed.
+    fn is_omitted(&self, cast: &Cast) -> bool {
+        self.openqasm2 || cast.is_implicit()
+    }
+
+    fn printed_expr<'b>(&self, texpr: &'b TExpr) -> &'b Expr {
+        match texpr.expression() {
+            Expr::Cast(cast) if self.is_omitted(cast) => self.printed_expr(cast.operand()),
+            expr => expr,
+        }
+    }
+
+    // Print `texpr`, parenthesized if it binds less tightly than `min_precedence`.
+    fn operand(&mut self, texpr: &TExpr, min_precedence: u8) {
+        if precedence(self.printed_expr(texpr)) < min_precedence {
+            self.push("(");
+            self.texpr(texpr);
+            self.push(")");
+        } else {
+            self.texpr(texpr);
+        }
+    }
+
+    fn texpr(&mut self, texpr: &TExpr) {
+        match texpr.expression() {
+            Expr::ArraySlice(array_slice) => self.array_slice(array_slice),
+            Expr::BinaryExpr(binary_expr) => {
+                let prec = precedence(texpr.expression());
+                // `**` is right associative. The other operators are left associative.
+                let (left_prec, right_prec) = match binary_expr.op() {
+                    BinaryOp::ArithOp(ArithOp::Pow) => (prec + 1, prec),
+                    _ => (prec, prec + 1),
+                };
+                self.operand(binary_expr.left(), left_prec);
+                let _ = write!(self.out, " {} ", binary_op_str(binary_expr.op()));
+                self.operand(binary_expr.right(), right_prec);
+            }
+            Expr::UnaryExpr(unary_expr) => {
+                self.push(match unary_expr.op() {
+                    UnaryOp::Minus => "-",
+                    UnaryOp::Not => "!",
+                    UnaryOp::BitNot => "~",
+                });
+                self.operand(unary_expr.operand(), precedence(texpr.expression()));
+            }
+            Expr::Literal(literal) => self.literal(literal),
+            Expr::Cast(cast) if self.is_omitted(cast) => self.texpr(cast.operand()),
+            Expr::Cast(cast) => {
+                self.typ(cast.get_type());
+                self.push("(");
+                self.texpr(cast.operand());
+                self.push(")");
+            }
+            Expr::Identifier(identifier) => self.push(identifier.name()),
+            Expr::HardwareQubit(hardware_qubit) => self.push(hardware_qubit.identifier()),
+            Expr::IndexExpression(index_expression) => {
+                self.operand(index_expression.expr(), u8::MAX);
+                self.index_operator(index_expression.index());
+            }
+            Expr::IndexedIdentifier(indexed_identifier) => {
+                self.indexed_identifier(indexed_identifier)
+            }
+            Expr::GateOperand(gate_operand) => match gate_operand {
+                GateOperand::Identifier(identifier) => self.push(identifier.name()),
+                GateOperand::HardwareQubit(hardware_qubit) => {
+                    self.push(hardware_qubit.identifier())
+                }
+                GateOperand::IndexedIdentifier(indexed_identifier) => {
+                    self.indexed_identifier(indexed_identifier)
+                }
+            },
+            Expr::Range(range) => self.range(range),
+            Expr::Call(call) => {
+                self.name(call.name());
+                self.push("(");
+                self.texprs(call.args());
+                self.push(")");
+            }
+            Expr::BuiltinCall(call) => {
+                self.push(call.function().name());
+                self.push("(");
+                self.texprs(call.args());
+                self.push(")");
+            }
+            Expr::SizeOf(sizeof) => {
+                self.push("sizeof(");
+                self.texpr(sizeof.array());
+                if let Some(dim) = sizeof.dim() {
+                    self.push(", ");
+                    self.texpr(dim);
+                }
+                self.push(")");
+            }
+            Expr::Set => self.push("{}"),
+            Expr::Measure(measure) => {
+                self.push("measure ");
+                self.texpr(measure.operand());
+            }
+            Expr::DurationOf(durationof) => {
+                self.push("durationof(");
+                self.block(durationof.scope());
+                self.push(")");
+            }
+            Expr::Concatenation(concatenation) => {
+                for (i, operand) in concatenation.operands().iter().enumerate() {
+                    if i > 0 {
+                        self.push(" ++ ");
+                    }
+                    self.operand(operand, precedence(texpr.expression()) + 1);
+                }
+            }
+        }
+    }
+
+    fn literal(&mut self, literal: &Literal) {
+        match literal {
+            Literal::Bool(bool_literal) => {
+                let _ = write!(self.out, "{}", bool_literal.value());
+            }
+            Literal::Int(int_literal) => {
+                let _ = write!(self.out, "{}", int_literal.value());
+            }
+            Literal::Float(float_literal) => self.push(&float_str(float_literal.value())),
+            Literal::Imaginary(imaginary_literal) => {
+                let _ = write!(self.out, "{}im", imaginary_literal.value());
+            }
+            Literal::BitString(bit_string) => {
+                let _ = write!(self.out, "\"{}\"", bit_string.value());
+            }
+            Literal::Duration(duration) => {
+                let _ = write!(self.out, "{}{}", duration.value(), duration.unit().suffix());
+            }
+            Literal::Array(array_literal) => {
+                self.push("{");
+                self.texprs(array_literal.elements());
+                self.push("}");
             }
-            _ => ()
         }
-        write!(f, ";")
     }
 }
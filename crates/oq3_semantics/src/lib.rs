@@ -11,6 +11,38 @@
 //! information, even though it's not really a tree, but rather a directed acyclic graph. We use the
 //! acronym ASG here not to be pedantic but rather to have an easy and succinct way to distinguish
 //! the output of syntactic analysis from output of semantic analysis.
+//!
+//! # Serialization
+//!
+//! With the `serde` feature enabled, the ASG (`asg::Program` and everything it contains),
+//! `types::Type`, `symbols::SymbolTable`, and the semantic errors implement `Serialize` and
+//! `Deserialize`. The serialized form is serde's default data model, so in JSON:
+//!
+//! * A struct is an object whose keys are the names of its fields, including private fields.
+//!   For example a `Program` has keys `version`, `calibration_grammar`, `stmts`, `stmt_spans`,
+//!   and `file_paths`. A `TExpr` has keys `expression`, `ty`, and `span`.
+//! * An enum is externally tagged with the name of the Rust variant. A unit variant is a string,
+//!   for example `"Qubit"`. Other variants are objects with a single key, for example
+//!   `{"Int": [32, "True"]}` for `const int[32]`. `IsConst` is `"True"` or `"False"`, and a
+//!   missing width is `null`.
+//! * A `SymbolId` is a number, the index of the symbol in the `all_symbols` field of the
+//!   symbol table. A `SymbolIdResult` is `{"Ok": id}` or, for an unresolved name,
+//!   `{"Err": "MissingBinding"}`.
+//! * A `TextRange` is an array `[start, end]` of byte offsets. A `Span` is
+//!   `{"file_id": n, "range": [start, end]}`, where `n` indexes the `file_paths` of the program.
+//! * The names in each scope of the symbol table are written in sorted order, so serializing
+//!   the same table always gives the same text.
+//! * A `SemanticError` is `{"error_kind": kind, "range": [start, end], "text": source_text}`.
+//!
+//! ## Stability
+//!
+//! The schema is versioned by `asg::SCHEMA_VERSION`, currently 1. Wrapping a value in
+//! `asg::Versioned` serializes it as `{"schema_version": 1, "value": value}`. Within a schema
+//! version, the names of fields and variants and the encodings described above do not change.
+//! A change that adds, renames, or removes a field or a variant, or that changes how a value is
+//! encoded, increments `SCHEMA_VERSION`. Only the current schema version can be read: deserializing
+//! a `Versioned` value written with any other version fails. A symbol table and a list of errors
+//! have the schema of the program they were produced with.
 
 // This code is littered with FIXME. This doesn't always mean something needs to be FIXED.
 // All FIXME's should be turned into external Issues (ie on GH) or just removed if obsolete.
@@ -24,8 +56,12 @@
 // that only `use` things from the file-level modules.
 
 pub mod asg;
+pub mod builtins;
+pub mod const_eval;
 pub mod context;
+pub mod display;
 pub mod semantic_error;
+pub mod span;
 pub mod symbols;
 pub mod syntax_to_semantics;
 pub mod types;
@@ -33,6 +69,4 @@ pub mod validate;
 
 pub use rowan::{TextRange, TextSize};
 
-// mod display;
-
 mod utils;
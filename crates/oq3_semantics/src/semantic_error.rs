@@ -12,7 +12,8 @@ use std::path::{Path, PathBuf};
 // re-exported in lib.rs from rowan
 use crate::TextRange;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SemanticErrorKind {
     UndefVarError,
     RedeclarationError,
@@ -20,15 +21,35 @@ pub enum SemanticErrorKind {
     IncompatibleTypesError,
     MutateConstError,
     IncludeNotInGlobalScopeError,
+    IODeclarationNotInGlobalScopeError,
+    ExternNotInGlobalScopeError,
+    DefNotInGlobalScopeError,
+    NotCallableError,
+    NumberOfArgumentsError,
+    ArgumentTypeError,
+    ReturnTypeError,
+    NumberOfQubitOperandsError,
+    OpenQASM2OnlyError,
+    DuplicateCaseLabelError,
+    MisplacedDefaultError,
+    ArrayDimensionError,
+    TooManyIndicesError,
+    NonConstantError,
+    IllegalCastError,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SemanticError {
     error_kind: SemanticErrorKind,
-    node: SyntaxNode, // Includes span and api functions to retrieve text, etc.
+    // The range and text of the syntax node at which the error was found. These are copied
+    // from the node so that the error does not hold a reference into the syntax tree.
+    range: TextRange,
+    text: String,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SemanticErrorList {
     source_file_path: PathBuf,
     list: Vec<SemanticError>,
@@ -121,11 +142,24 @@ impl SemanticErrorList {
 
 impl SemanticError {
     pub fn new(error_kind: SemanticErrorKind, node: SyntaxNode) -> Self {
-        Self { error_kind, node }
+        Self {
+            error_kind,
+            range: node.text_range(),
+            text: node.text().to_string(),
+        }
+    }
+
+    pub fn kind(&self) -> &SemanticErrorKind {
+        &self.error_kind
     }
 
     pub fn range(&self) -> TextRange {
-        self.node.text_range()
+        self.range
+    }
+
+    /// The source text at which the error was found.
+    pub fn text(&self) -> &str {
+        &self.text
     }
 
     pub fn message(&self) -> String {
@@ -135,12 +169,6 @@ impl SemanticError {
 
 impl fmt::Display for SemanticError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:?}: {}, {:?}",
-            self.error_kind,
-            self.node.text(),
-            self.node.text_range()
-        )
+        write!(f, "{:?}: {}, {:?}", self.error_kind, self.text, self.range)
     }
 }
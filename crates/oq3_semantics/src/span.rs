@@ -0,0 +1,50 @@
+// Copyright contributors to the openqasm-parser project
+// SPDX-License-Identifier: Apache-2.0
+
+//! Locations in source text of statements, expressions, and symbols in the ASG.
+//!
+//! A `Span` is the range of text of the syntax node from which an ASG node was lowered,
+//! together with an identifier of the file containing the text. The path of the file
+//! is found with [`Program::file_path`](crate::asg::Program::file_path).
+
+use crate::TextRange;
+
+/// Identifies a source file. The file being analyzed is `FileId(0)`. Each file included
+/// with `include` is numbered in the order in which it is encountered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileId(u32);
+
+impl FileId {
+    pub fn new(id: u32) -> FileId {
+        FileId(id)
+    }
+}
+
+impl From<FileId> for usize {
+    fn from(file_id: FileId) -> usize {
+        file_id.0 as usize
+    }
+}
+
+/// A range of text in a source file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    file_id: FileId,
+    range: TextRange,
+}
+
+impl Span {
+    pub fn new(file_id: FileId, range: TextRange) -> Span {
+        Span { file_id, range }
+    }
+
+    pub fn file_id(&self) -> FileId {
+        self.file_id
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+}
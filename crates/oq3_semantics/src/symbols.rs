@@ -3,6 +3,7 @@
 
 // Defines data structures and api for symbols, scope, and symbol tables.
 
+use crate::span::Span;
 use crate::types::Type;
 use hashbrown::HashMap;
 
@@ -12,6 +13,7 @@ use hashbrown::HashMap;
 
 #[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScopeType {
     /// Top-level
     Global,
@@ -31,6 +33,7 @@ pub enum ScopeType {
 // I am assuming that we can clone `SymbolId` willy-nilly
 // because it is no more expensive than a reference.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SymbolId(usize);
 
 impl SymbolId {
@@ -61,6 +64,7 @@ impl From<SymbolId> for usize {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SymbolError {
     MissingBinding,
     AlreadyBound,
@@ -70,10 +74,12 @@ pub type SymbolIdResult = Result<SymbolId, SymbolError>;
 pub type SymbolRecordResult<'a> = Result<SymbolRecord<'a>, SymbolError>;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Symbol {
     name: String,
     typ: Type,
-    //    ast_node: SyntaxNode,
+    // Location of the declaration. Predefined symbols, such as `U`, have no span.
+    span: Option<Span>,
 }
 
 pub trait SymbolType {
@@ -83,19 +89,22 @@ pub trait SymbolType {
 }
 
 impl Symbol {
-    fn new<T: ToString>(name: T, typ: &Type) -> Symbol {
+    fn new<T: ToString>(name: T, typ: &Type, span: Option<Span>) -> Symbol {
         Symbol {
             name: name.to_string(),
             typ: typ.clone(),
+            span,
         }
-        // fn new<T: ToString>(name: T, typ: &Type, ast_node: &SyntaxNode) -> Symbol {
-        //     Symbol { name: name.to_string(), typ: typ.clone() }
-        //        Symbol { name: name.to_string(), typ: typ.clone(), ast_node: ast_node.clone() }
     }
 
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// The location of the declaration of the symbol, if it was declared in source.
+    pub fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
 }
 
 impl SymbolType for Symbol {
@@ -134,6 +143,10 @@ impl SymbolRecord<'_> {
     pub fn scope_level(&self) -> usize {
         self.scope_level
     }
+
+    pub fn span(&self) -> Option<&Span> {
+        self.symbol.span()
+    }
 }
 
 // This trait is a bit heavy weight for what it does.
@@ -181,12 +194,26 @@ impl SymbolType for SymbolRecord<'_> {
 /// A `SymbolTable` is a stack of `SymbolMap`s together with a `Vec` mapping
 /// `SymbolId as usize` to `Symbol`s.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 struct SymbolMap {
+    // Serialized with the names in sorted order so that the output is reproducible.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_sorted"))]
     table: HashMap<String, SymbolId>,
     scope_type: ScopeType,
 }
 
+#[cfg(feature = "serde")]
+fn serialize_sorted<S: serde::Serializer>(
+    table: &HashMap<String, SymbolId>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(
+        &table.iter().collect::<std::collections::BTreeMap<_, _>>(),
+        serializer,
+    )
+}
+
 impl SymbolMap {
     fn new(scope_type: ScopeType) -> SymbolMap {
         SymbolMap {
@@ -219,6 +246,7 @@ impl SymbolMap {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SymbolTable {
     /// A stack each of whose elements represent a scope mapping `name: String` to `SymbolId`.
     symbol_table_stack: Vec<SymbolMap>,
@@ -241,6 +269,12 @@ impl SymbolTable {
         symbol_table
     }
 
+    /// Return the symbols of every scope, in the order in which they were bound. The index
+    /// of a symbol is its `SymbolId`.
+    pub fn all_symbols(&self) -> &[Symbol] {
+        &self.all_symbols
+    }
+
     pub fn number_of_scopes(&self) -> usize {
         self.symbol_table_stack.len()
     }
@@ -260,16 +294,32 @@ impl SymbolTable {
     /// Otherwise, create a new Symbol from `name` and `typ`, bind `name` to
     /// this Symbol in the current scope, and return the Symbol.
     pub fn new_binding(&mut self, name: &str, typ: &Type) -> Result<SymbolId, SymbolError> {
-        //    pub fn new_binding(&mut self, name: &str, typ: &Type, ast_node: &SyntaxNode) -> Result<SymbolId, SymbolError> {
+        self.bind(name, typ, None)
+    }
+
+    /// Like `new_binding`, but record `span`, the location of the declaration.
+    pub fn new_binding_with_span(
+        &mut self,
+        name: &str,
+        typ: &Type,
+        span: Span,
+    ) -> Result<SymbolId, SymbolError> {
+        self.bind(name, typ, Some(span))
+    }
 
+    fn bind(
+        &mut self,
+        name: &str,
+        typ: &Type,
+        span: Option<Span>,
+    ) -> Result<SymbolId, SymbolError> {
         // Can't create a binding if it already exists in the current scope.
         if self.current_scope_contains_name(name) {
             return Err(SymbolError::AlreadyBound);
         }
 
         // Create new symbol and symbol id.
-        //        let symbol = Symbol::new(name, typ, ast_node);
-        let symbol = Symbol::new(name, typ);
+        let symbol = Symbol::new(name, typ, span);
 
         // Push the symbol onto list of all symbols (in all scopes). Index
         // to this symbol will be `id_count`.
@@ -13,19 +13,19 @@ use std::mem::replace;
 use std::path::PathBuf;
 
 use crate::asg;
+use crate::builtins::BuiltinFunction;
+use crate::const_eval::ConstValue;
 use crate::types;
-use crate::types::{ArrayDims, IsConst, Type};
+use crate::types::{ArrayDims, IOType, IsConst, Type, MAX_ARRAY_DIMS};
 
 use crate::context::Context;
 use crate::semantic_error::{SemanticErrorKind::*, SemanticErrorList};
-use crate::symbols::{ScopeType, SymbolErrorTrait, SymbolIdResult, SymbolTable};
+use crate::symbols::{ScopeType, SymbolError, SymbolErrorTrait, SymbolIdResult, SymbolTable};
 use oq3_source_file::{SourceFile, SourceString, SourceTrait};
 use oq3_syntax::ast as synast; // Syntactic AST
 
 use crate::with_scope;
 
-use crate::utils::type_name_of; // for debugging
-
 // traits
 use synast::{HasArgList, HasModuleItem, HasName, HasTextName};
 
@@ -132,6 +132,7 @@ pub fn syntax_to_semantic<T: SourceTrait>(
     let mut included_iter = parsed_source.included().iter();
     let save_errors = replace(&mut context.semantic_errors, errors);
     for parse_item in parse_tree.statements() {
+        let span = context.span(&parse_item);
         let stmt = match parse_item {
             // Include does not go in the ASG, instead it is evaluated.
             // So we include the parsed code, collect errors, and return `None`.
@@ -145,10 +146,16 @@ pub fn syntax_to_semantic<T: SourceTrait>(
                 if context.symbol_table().current_scope_type() != ScopeType::Global {
                     context.insert_error(IncludeNotInGlobalScopeError, &include);
                 }
+                // Spans of ASG nodes lowered from the included file refer to that file.
+                let included_file_id = context
+                    .program
+                    .add_file_path(included_parsed_source.file_path());
+                let file_id = replace(&mut context.file_id, included_file_id);
                 // Call this function recursively passing the new, empty, storage for errors.
                 // Note that `errors_in_included` will be swapped into `context` upon entering `syntax_to_semantic`.
                 (context, errors_in_included) =
                     syntax_to_semantic(included_parsed_source, context, errors_in_included);
+                context.file_id = file_id;
                 // Just before exiting the previous call, `errors_in_included` and `errors` are swapped again in `context`.
                 // Push the newly-populated list of errors onto the list of included errors in `context`, which now
                 // holds `errors`, the list passed in the current call to this `syntax_to_semantic`. And `errors`
@@ -163,7 +170,7 @@ pub fn syntax_to_semantic<T: SourceTrait>(
             synast::Stmt::ExprStmt(expr_stmt) => from_expr_stmt(expr_stmt, &mut context),
         };
         if let Some(stmt) = stmt {
-            context.program.insert_stmt(stmt)
+            context.program.insert_stmt_with_span(stmt, span)
         }
     }
 
@@ -177,6 +184,10 @@ pub fn syntax_to_semantic<T: SourceTrait>(
 }
 
 fn from_expr_stmt(expr_stmt: synast::ExprStmt, context: &mut Context) -> Option<asg::Stmt> {
+    // `return` is parsed as an expression, but it is a statement.
+    if let Some(synast::Expr::ReturnExpr(return_expr)) = expr_stmt.expr() {
+        return Some(from_return_expr(&return_expr, context));
+    }
     let expr = from_expr(expr_stmt.expr().unwrap(), context);
     expr.map_or_else(
         || panic!("expr::ExprStmt is None"),
@@ -184,9 +195,21 @@ fn from_expr_stmt(expr_stmt: synast::ExprStmt, context: &mut Context) -> Option<
     )
 }
 
+// Lower `expr`, recording the span of its source text.
 fn from_expr(expr: synast::Expr, context: &mut Context) -> Option<asg::TExpr> {
+    let span = context.span(&expr);
+    lower_expr(expr, context).map(|texpr| texpr.with_span(span))
+}
+
+fn lower_expr(expr: synast::Expr, context: &mut Context) -> Option<asg::TExpr> {
     match expr {
         synast::Expr::ParenExpr(paren_expr) => from_expr(paren_expr.expr().unwrap(), context),
+        synast::Expr::BinExpr(bin_expr)
+            if bin_expr.op_kind() == Some(synast::BinaryOp::ConcatenationOp) =>
+        {
+            Some(from_concatenation(&bin_expr, context))
+        }
+
         synast::Expr::BinExpr(bin_expr) => {
             let synast_op = bin_expr.op_kind().unwrap();
             let left_syn = bin_expr.lhs().unwrap();
@@ -196,9 +219,43 @@ fn from_expr(expr: synast::Expr, context: &mut Context) -> Option<asg::TExpr> {
             let left = from_expr(left_syn, context).unwrap();
             let right = from_expr(right_syn, context).unwrap();
 
+            if !is_binary_op_defined(&op, left.get_type(), right.get_type()) {
+                context.insert_error(IncompatibleTypesError, &bin_expr);
+            }
             Some(asg::BinaryExpr::new_texpr_with_cast(op, left, right))
         }
 
+        synast::Expr::PrefixExpr(prefix_expr) => {
+            let op = match prefix_expr.op_kind().unwrap() {
+                synast::UnaryOp::Neg => asg::UnaryOp::Minus,
+                synast::UnaryOp::Not => asg::UnaryOp::Not,
+                synast::UnaryOp::BitNot => asg::UnaryOp::BitNot,
+            };
+            let operand = from_expr(prefix_expr.expr()?, context)?;
+            let is_defined = match (&op, operand.get_type()) {
+                (_, Type::ToDo | Type::Undefined) => true,
+                (asg::UnaryOp::Minus, typ) => {
+                    typ.is_real_numeric()
+                        || typ.is_timing()
+                        || matches!(typ, Type::Angle(..) | Type::Complex(..))
+                }
+                (asg::UnaryOp::Not, typ) => {
+                    typ.is_scalar() || matches!(typ, Type::BitArray(ArrayDims::D1(_), _))
+                }
+                (asg::UnaryOp::BitNot, typ) => {
+                    typ.is_integer()
+                        || matches!(
+                            typ,
+                            Type::Angle(..) | Type::Bit(..) | Type::BitArray(ArrayDims::D1(_), _)
+                        )
+                }
+            };
+            if !is_defined {
+                context.insert_error(IncompatibleTypesError, &prefix_expr);
+            }
+            Some(asg::UnaryExpr::new_texpr(op, operand))
+        }
+
         synast::Expr::Literal(ref literal) => from_literal(literal),
 
         synast::Expr::Identifier(identifier) => {
@@ -223,12 +280,40 @@ fn from_expr(expr: synast::Expr, context: &mut Context) -> Option<asg::TExpr> {
             Some(asg::IndexExpression::new(expr.unwrap(), index).to_texpr())
         }
 
-        synast::Expr::IndexedIdentifier(indexed_identifier) => {
-            let (indexed_identifier, _typ) = ast_indexed_identifier(&indexed_identifier, context);
-            Some(indexed_identifier.to_texpr())
+        synast::Expr::IndexedIdentifier(syn_indexed_identifier) => {
+            let (indexed_identifier, typ) =
+                ast_indexed_identifier(&syn_indexed_identifier, context);
+            let typ = indexed_type(
+                &typ,
+                indexed_identifier.indexes(),
+                &syn_indexed_identifier,
+                context,
+            );
+            Some(indexed_identifier.to_texpr(typ))
+        }
+
+        // The type of an array literal is known only from the context in which it appears.
+        // See `from_array_literal`.
+        synast::Expr::ArrayLiteral(array_literal) => {
+            let elements = array_literal
+                .expression_list()
+                .map_or_else(Vec::new, |exprs| inner_expression_list(exprs, context));
+            Some(asg::ArrayLiteral::new(elements).to_texpr(Type::ToDo))
+        }
+
+        synast::Expr::CallExpr(call_expr) => from_call_expr(&call_expr, context),
+
+        synast::Expr::MeasureExpression(measure_expr) => {
+            Some(from_measure_expression(&measure_expr, context))
+        }
+
+        synast::Expr::DurationOfExpr(durationof_expr) => {
+            let scope = from_block_expr(durationof_expr.block_expr().unwrap(), context);
+            Some(asg::DurationOf::new(scope).to_texpr())
         }
 
-        synast::Expr::MeasureExpression(_measure_expr) => None,
+        synast::Expr::CastExpression(cast_expr) => from_cast_expr(&cast_expr, context),
+
         _ => {
             println!("Expression not supported {:?}", expr);
             None
@@ -236,6 +321,245 @@ fn from_expr(expr: synast::Expr, context: &mut Context) -> Option<asg::TExpr> {
     }
 }
 
+// An explicit cast, such as `int[32](x)`. The cast is const if its operand is const.
+fn from_cast_expr(cast_expr: &synast::CastExpression, context: &mut Context) -> Option<asg::TExpr> {
+    let operand = from_expr(cast_expr.expr()?, context)?;
+    let isconst = operand.get_type().is_const();
+    let typ = match (cast_expr.scalar_type(), cast_expr.array_type()) {
+        (Some(scalar_type), _) => from_scalar_type(&scalar_type, isconst, context),
+        (None, Some(array_type)) => from_array_type(&array_type, context),
+        (None, None) => Type::Undefined,
+    };
+    if !types::can_cast_explicit(operand.get_type(), &typ) {
+        context.insert_error(IllegalCastError, cast_expr);
+    }
+    Some(asg::Cast::new(operand, typ).to_texpr())
+}
+
+fn from_return_expr(return_expr: &synast::ReturnExpr, context: &mut Context) -> asg::Stmt {
+    let value = return_expr.expr().and_then(|expr| from_expr(expr, context));
+    // A return type of `None` means we are not in a subroutine. This is not checked here.
+    if let Some(return_type) = context.return_type.clone() {
+        let is_compatible = match (&return_type, &value) {
+            (Type::Void, None) => true,
+            (Type::Void, Some(_)) | (_, None) => false,
+            (_, Some(value)) => types::can_cast_implicit(value.get_type(), &return_type),
+        };
+        if !is_compatible {
+            context.insert_error(ReturnTypeError, return_expr);
+        }
+    }
+    asg::Return::new(value).to_stmt()
+}
+
+fn from_call_expr(call_expr: &synast::CallExpr, context: &mut Context) -> Option<asg::TExpr> {
+    // FIXME: Only calling a subroutine by name is supported.
+    let identifier = match call_expr.expr() {
+        Some(synast::Expr::Identifier(identifier)) => identifier,
+        _ => {
+            context.insert_error(NotCallableError, call_expr);
+            let args = call_expr
+                .arg_list()
+                .and_then(|arg_list| arg_list.expression_list())
+                .map_or_else(Vec::new, |exprs| inner_expression_list(exprs, context));
+            return Some(invalid_call(args));
+        }
+    };
+    let name_str = identifier.string();
+    if name_str == "sizeof" {
+        return from_sizeof(call_expr, context);
+    }
+    let (symbol_id, typ) = context
+        .lookup_symbol(name_str.as_str(), &identifier)
+        .as_tuple();
+    let args = call_expr
+        .arg_list()
+        .and_then(|arg_list| arg_list.expression_list())
+        .map_or_else(Vec::new, |exprs| inner_expression_list(exprs, context));
+    if let Some(function) = context.builtin_function(&symbol_id) {
+        return Some(from_builtin_call(function, args, call_expr, context));
+    }
+    let return_type = match typ {
+        Type::Subroutine(ref param_types, ref return_type) => {
+            if param_types.len() != args.len() {
+                context.insert_error(NumberOfArgumentsError, call_expr);
+            } else if param_types
+                .iter()
+                .zip(args.iter())
+                .any(|(param_type, arg)| !can_pass_argument(arg.get_type(), param_type))
+            {
+                context.insert_error(ArgumentTypeError, call_expr);
+            }
+            return_type.as_ref().clone()
+        }
+        // Failed lookup has already been logged.
+        Type::Undefined => Type::Undefined,
+        _ => {
+            context.insert_error(NotCallableError, &identifier);
+            Type::Undefined
+        }
+    };
+    Some(asg::Call::new(symbol_id, args).to_texpr(return_type))
+}
+
+// A call to a built-in function. Most built-in functions accept arguments of more than
+// one type, so the type of the call is computed from the types of the arguments.
+fn from_builtin_call(
+    function: BuiltinFunction,
+    args: Vec<asg::TExpr>,
+    call_expr: &synast::CallExpr,
+    context: &mut Context,
+) -> asg::TExpr {
+    let return_type = if args.len() != function.num_args() {
+        context.insert_error(NumberOfArgumentsError, call_expr);
+        Type::Undefined
+    } else {
+        let arg_types: Vec<_> = args.iter().map(|arg| arg.get_type().clone()).collect();
+        function.return_type(&arg_types).unwrap_or_else(|| {
+            context.insert_error(ArgumentTypeError, call_expr);
+            Type::Undefined
+        })
+    };
+    asg::BuiltinCall::new(function, args).to_texpr(return_type)
+}
+
+// A call that could not be lowered, for example because the callee is not a name. The error
+// has been reported. The call is kept, with type `Undefined`, so that the enclosing
+// statement or expression can still be lowered.
+fn invalid_call(args: Vec<asg::TExpr>) -> asg::TExpr {
+    asg::Call::new(Err(SymbolError::MissingBinding), args).to_texpr(Type::Undefined)
+}
+
+// `sizeof(array)` or `sizeof(array, dim)`. `sizeof` is not a symbol, so it is handled
+// before the name of the callee is looked up.
+fn from_sizeof(call_expr: &synast::CallExpr, context: &mut Context) -> Option<asg::TExpr> {
+    let mut args = call_expr
+        .arg_list()
+        .and_then(|arg_list| arg_list.expression_list())
+        .map_or_else(Vec::new, |exprs| inner_expression_list(exprs, context))
+        .into_iter();
+    let Some(array) = args.next() else {
+        context.insert_error(NumberOfArgumentsError, call_expr);
+        return Some(invalid_call(Vec::new()));
+    };
+    let dim = args.next();
+    if args.next().is_some() {
+        context.insert_error(NumberOfArgumentsError, call_expr);
+    }
+    let num_dims = match array.get_type() {
+        Type::UnsizedArray(_, num_dims, _) => Some(*num_dims),
+        typ => typ.dims().map(|dims| dims.len()),
+    };
+    match num_dims {
+        Some(num_dims) => {
+            // A dimension that is not a constant is not checked.
+            let dim_value = dim.as_ref().and_then(|dim| context.eval_const(dim));
+            if dim_value.is_some_and(|dim_value| {
+                dim_value
+                    .as_usize()
+                    .map_or(true, |dim_value| dim_value >= num_dims)
+            }) {
+                context.insert_error(ArrayDimensionError, call_expr);
+            }
+        }
+        None if !matches!(array.get_type(), Type::ToDo | Type::Undefined) => {
+            context.insert_error(ArgumentTypeError, call_expr);
+        }
+        None => (),
+    }
+    Some(asg::SizeOf::new(array, dim).to_texpr())
+}
+
+// Return `true` if an argument of type `arg_type` may be passed to a parameter of type `param_type`.
+// Quantum arguments must be passed to quantum parameters of the same size.
+fn can_pass_argument(arg_type: &Type, param_type: &Type) -> bool {
+    if matches!(arg_type, Type::ToDo | Type::Undefined) || matches!(param_type, Type::ToDo) {
+        return true;
+    }
+    // An array with the same number of dimensions and element type may be passed to
+    // an array parameter declared with `#dim`.
+    if let Type::UnsizedArray(element_type, num_dims, _) = param_type {
+        let (arg_element_type, arg_num_dims) = match arg_type {
+            Type::UnsizedArray(element_type, num_dims, _) => {
+                (Some(element_type.as_ref().clone()), Some(*num_dims))
+            }
+            _ if arg_type.is_classical_array() => (
+                arg_type.array_element_type(),
+                arg_type.dims().map(|dims| dims.len()),
+            ),
+            _ => return false,
+        };
+        return arg_num_dims == Some(*num_dims)
+            && arg_element_type.map_or(false, |arg_element_type| {
+                types::equal_up_to_constness(&arg_element_type, element_type)
+            });
+    }
+    let arg_is_quantum = arg_type.is_quantum() || matches!(arg_type, Type::HardwareQubit);
+    match (arg_is_quantum, param_type.is_quantum()) {
+        (true, true) => {
+            matches!((arg_type, param_type), (Type::HardwareQubit, Type::Qubit))
+                || arg_type == param_type
+        }
+        (false, false) => types::can_cast_implicit(arg_type, param_type),
+        _ => false,
+    }
+}
+
+fn from_for_iterable(
+    for_iterable: &synast::ForIterable,
+    context: &mut Context,
+) -> asg::ForIterable {
+    if let Some(set_expression) = for_iterable.set_expression() {
+        return asg::ForIterable::SetExpression(from_set_expression(set_expression, context));
+    }
+    // A `RangeExpr` is also an `Expr`. So we must look for it first.
+    if let Some(range_expr) = for_iterable.range() {
+        let (start, step, stop) = range_expr.start_step_stop();
+        let start = from_expr(start.unwrap(), context).unwrap();
+        let stop = from_expr(stop.unwrap(), context).unwrap();
+        let step = step.and_then(|step| from_expr(step, context));
+        return asg::ForIterable::RangeExpression(asg::Range::new(start, step, stop));
+    }
+    let expr = from_expr(for_iterable.for_iterable_expr().unwrap(), context);
+    asg::ForIterable::Expr(expr.unwrap())
+}
+
+// Return `true` if each value taken from `iterable` may be assigned to a loop variable of
+// type `loop_var_type`. A loop iterates over a set, a range, or a classical array or bit
+// register. The values taken from a multi-dimensional array are its subarrays.
+fn is_for_iterable_compatible(iterable: &asg::ForIterable, loop_var_type: &Type) -> bool {
+    let can_assign = |texpr: &asg::TExpr| types::can_cast_implicit(texpr.get_type(), loop_var_type);
+    match iterable {
+        asg::ForIterable::SetExpression(set_expression) => {
+            set_expression.expressions().iter().all(can_assign)
+        }
+        asg::ForIterable::RangeExpression(range) => {
+            can_assign(range.start())
+                && can_assign(range.stop())
+                && range.step().map_or(true, can_assign)
+        }
+        asg::ForIterable::Expr(texpr) => {
+            let typ = texpr.get_type();
+            match typ {
+                Type::ToDo | Type::Undefined => return true,
+                Type::UnsizedArray(element_type, 1, _) => {
+                    return types::can_cast_implicit(element_type, loop_var_type)
+                }
+                // The type of a subarray is not represented.
+                Type::UnsizedArray(..) => return true,
+                _ => (),
+            }
+            if !typ.is_classical_array() {
+                return false;
+            }
+            let dims = typ.dims().unwrap();
+            typ.with_array_dims(&dims[1..]).map_or(false, |elem_type| {
+                types::can_cast_implicit(&elem_type, loop_var_type)
+            })
+        }
+    }
+}
+
 fn from_index_operator(
     index_op: synast::IndexOperator,
     context: &mut Context,
@@ -280,33 +604,139 @@ fn inner_expression_list(
         .collect::<Vec<_>>()
 }
 
-fn from_binary_op(synast_op: synast::BinaryOp) -> asg::BinaryOp {
-    match synast_op {
-        synast::BinaryOp::ArithOp(arith_op) => {
-            use asg::BinaryOp::ArithOp;
-            use synast::ArithOp::*;
-            match arith_op {
-                Add => ArithOp(asg::ArithOp::Add),
-                Mul => ArithOp(asg::ArithOp::Mul),
-                Sub => ArithOp(asg::ArithOp::Sub),
-                Div => ArithOp(asg::ArithOp::Div),
-                // variant synast::ArithOp::Mod does not exist
-                // Mod => asg::BinaryOp::Mod,
-                // Shl => Shl,
-                // Shr => Shr,
-                _ => todo!(),
+// `a ++ b ++ c` is parsed as nested binary expressions. We flatten these into
+// a single concatenation.
+fn from_concatenation(bin_expr: &synast::BinExpr, context: &mut Context) -> asg::TExpr {
+    fn collect_operands(expr: synast::Expr, operands: &mut Vec<synast::Expr>) {
+        match expr {
+            synast::Expr::BinExpr(ref bin_expr)
+                if bin_expr.op_kind() == Some(synast::BinaryOp::ConcatenationOp) =>
+            {
+                collect_operands(bin_expr.lhs().unwrap(), operands);
+                collect_operands(bin_expr.rhs().unwrap(), operands);
+            }
+            _ => operands.push(expr),
+        }
+    }
+    let mut syn_operands = Vec::new();
+    collect_operands(synast::Expr::BinExpr(bin_expr.clone()), &mut syn_operands);
+    let operands: Vec<_> = syn_operands
+        .into_iter()
+        .map(|operand| from_expr(operand, context).unwrap())
+        .collect();
+    let typ = operands[1..]
+        .iter()
+        .try_fold(operands[0].get_type().clone(), |typ, operand| {
+            types::promote_concatenation(&typ, operand.get_type())
+        });
+    let typ = typ.unwrap_or_else(|| {
+        context.insert_error(IncompatibleTypesError, bin_expr);
+        Type::Undefined
+    });
+    asg::Concatenation::new(operands).to_texpr(typ)
+}
+
+// Return `false` if the operator is not defined for the operand types.
+fn is_binary_op_defined(op: &asg::BinaryOp, left_type: &Type, right_type: &Type) -> bool {
+    // An error in an operand has already been reported.
+    if matches!(left_type, Type::ToDo | Type::Undefined)
+        || matches!(right_type, Type::ToDo | Type::Undefined)
+    {
+        return true;
+    }
+    let is_complex =
+        matches!(left_type, Type::Complex(..)) || matches!(right_type, Type::Complex(..));
+    let has_common_type = types::promote_types(left_type, right_type) != Type::Void;
+    match op {
+        // The operands of `&&` and `||` are converted to `bool`.
+        asg::BinaryOp::LogicOp(_) => true,
+        // Timing values are compared with each other.
+        asg::BinaryOp::CmpOp(_) if left_type.is_timing() && right_type.is_timing() => true,
+        asg::BinaryOp::ArithOp(op) if left_type.is_timing() || right_type.is_timing() => match op {
+            asg::ArithOp::Add | asg::ArithOp::Sub => {
+                types::promote_timing_sum(left_type, right_type).is_some()
             }
+            asg::ArithOp::Mul => types::promote_timing_product(left_type, right_type).is_some(),
+            asg::ArithOp::Div => types::promote_timing_quotient(left_type, right_type).is_some(),
+            _ => false,
+        },
+        // Complex numbers are not ordered and have no remainder.
+        asg::BinaryOp::ArithOp(asg::ArithOp::Mod | asg::ArithOp::Rem)
+        | asg::BinaryOp::CmpOp(
+            asg::CmpOp::Lt | asg::CmpOp::Gt | asg::CmpOp::Leq | asg::CmpOp::Geq,
+        ) if is_complex => false,
+        asg::BinaryOp::ArithOp(asg::ArithOp::Shl | asg::ArithOp::Shr) => {
+            types::promote_shift(left_type, right_type).is_some()
+        }
+        asg::BinaryOp::ArithOp(
+            asg::ArithOp::BitAnd | asg::ArithOp::BitOr | asg::ArithOp::BitXOr,
+        ) => types::promote_bitwise(left_type, right_type).is_some(),
+        // Arithmetic on `bool`s is not defined. A `bool` and a number have a numeric type.
+        asg::BinaryOp::ArithOp(_) => {
+            has_common_type && !matches!(types::promote_types(left_type, right_type), Type::Bool(_))
+        }
+        // A bit or bit register may be compared with an integer, as in `if (c == 1)`.
+        asg::BinaryOp::CmpOp(_) => {
+            let is_bits = |typ: &Type| matches!(typ, Type::Bit(..) | Type::BitArray(..));
+            has_common_type
+                || (is_bits(left_type) && right_type.is_integer())
+                || (left_type.is_integer() && is_bits(right_type))
         }
+    }
+}
+
+fn from_arith_op(arith_op: synast::ArithOp) -> asg::ArithOp {
+    use synast::ArithOp::*;
+    match arith_op {
+        Add => asg::ArithOp::Add,
+        Mul => asg::ArithOp::Mul,
+        Sub => asg::ArithOp::Sub,
+        Div => asg::ArithOp::Div,
+        Rem => asg::ArithOp::Rem,
+        Pow => asg::ArithOp::Pow,
+        Shl => asg::ArithOp::Shl,
+        Shr => asg::ArithOp::Shr,
+        BitXor => asg::ArithOp::BitXOr,
+        BitOr => asg::ArithOp::BitOr,
+        BitAnd => asg::ArithOp::BitAnd,
+    }
+}
+
+fn from_binary_op(synast_op: synast::BinaryOp) -> asg::BinaryOp {
+    match synast_op {
+        synast::BinaryOp::ArithOp(arith_op) => asg::BinaryOp::ArithOp(from_arith_op(arith_op)),
         synast::BinaryOp::CmpOp(cmp_op) => {
             use asg::BinaryOp::CmpOp;
             use synast::CmpOp::*;
+            use synast::Ordering::*;
             match cmp_op {
                 Eq { negated: false } => CmpOp(asg::CmpOp::Eq),
-                _ => todo!(),
+                Eq { negated: true } => CmpOp(asg::CmpOp::Neq),
+                Ord {
+                    ordering: Less,
+                    strict: true,
+                } => CmpOp(asg::CmpOp::Lt),
+                Ord {
+                    ordering: Less,
+                    strict: false,
+                } => CmpOp(asg::CmpOp::Leq),
+                Ord {
+                    ordering: Greater,
+                    strict: true,
+                } => CmpOp(asg::CmpOp::Gt),
+                Ord {
+                    ordering: Greater,
+                    strict: false,
+                } => CmpOp(asg::CmpOp::Geq),
             }
         }
-        _ => {
-            todo!()
+        synast::BinaryOp::LogicOp(logic_op) => match logic_op {
+            synast::LogicOp::And => asg::BinaryOp::LogicOp(asg::LogicOp::And),
+            synast::LogicOp::Or => asg::BinaryOp::LogicOp(asg::LogicOp::Or),
+        },
+        // Concatenation is handled in `from_concatenation`. Assignment is a statement.
+        synast::BinaryOp::ConcatenationOp | synast::BinaryOp::Assignment { .. } => {
+            unreachable!()
         }
     }
 }
@@ -326,10 +756,26 @@ fn from_literal(literal: &synast::Literal) -> Option<asg::TExpr> {
             asg::FloatLiteral::new(float).to_texpr()
         }
 
+        synast::LiteralKind::ImaginaryNumber(imaginary_num) => {
+            let num = imaginary_num.value()?;
+            asg::ImaginaryLiteral::new(format!("{num}")).to_texpr()
+        }
+
         synast::LiteralKind::BitString(bit_string) => {
             asg::BitStringLiteral::new(bit_string.str()?).to_texpr()
         }
 
+        synast::LiteralKind::TimingIntNumber(timing_num) => {
+            let unit = asg::TimeUnit::from_suffix(timing_num.suffix()?)?;
+            asg::DurationLiteral::new(timing_num.value()?, unit).to_texpr()
+        }
+
+        synast::LiteralKind::TimingFloatNumber(timing_num) => {
+            let unit = asg::TimeUnit::from_suffix(timing_num.suffix()?)?;
+            let num = timing_num.value()?;
+            asg::DurationLiteral::new(format!("{num}"), unit).to_texpr()
+        }
+
         _ => todo!(), // error. can/should be caught at syntax level, obviously
     };
     Some(literal_texpr)
@@ -350,28 +796,46 @@ fn from_item(item: synast::Item, context: &mut Context) -> Option<asg::Stmt> {
             Some(asg::While::new(condition.unwrap(), loop_body).to_stmt())
         }
 
+        synast::Item::SwitchCaseStmt(switch_stmt) => {
+            Some(from_switch_case_stmt(&switch_stmt, context))
+        }
+
+        synast::Item::ForStmt(for_stmt) => {
+            let loop_var = for_stmt.loop_var().unwrap();
+            let loop_var_type = from_scalar_type(&for_stmt.scalar_type().unwrap(), false, context);
+            // The iterable is resolved in the enclosing scope. The loop variable is not visible there.
+            let for_iterable = for_stmt.for_iterable().unwrap();
+            let iterable = from_for_iterable(&for_iterable, context);
+            if !is_for_iterable_compatible(&iterable, &loop_var_type) {
+                context.insert_error(IncompatibleTypesError, &for_iterable);
+            }
+            with_scope!(context, ScopeType::Local,
+                        let loop_var_id = context.new_binding(loop_var.string().as_ref(), &loop_var_type, &loop_var);
+                        let loop_body = from_block_expr(for_stmt.loop_body().unwrap(), context);
+            );
+            Some(asg::For::new(loop_var_id, iterable, loop_body).to_stmt())
+        }
+
+        synast::Item::Def(def) => Some(from_def(&def, context)),
+
         synast::Item::ClassicalDeclarationStatement(type_decl) => {
             Some(from_classical_declaration_statement(&type_decl, context))
         }
 
+        synast::Item::ExternItem(extern_item) => Some(from_extern_item(&extern_item, context)),
+
+        synast::Item::IODeclarationStatement(io_decl) => {
+            Some(from_io_declaration_statement(&io_decl, context))
+        }
+
+        synast::Item::OldStyleDeclarationStatement(old_decl) => {
+            Some(from_old_style_declaration_statement(&old_decl, context))
+        }
+
+        synast::Item::LetStmt(let_stmt) => Some(from_let_stmt(&let_stmt, context)),
+
         synast::Item::QuantumDeclarationStatement(q_decl) => {
-            let qubit_type = q_decl.qubit_type().unwrap();
-            let width = match qubit_type.designator().and_then(|x| x.expr()) {
-                Some(synast::Expr::Literal(ref literal)) => {
-                    match literal.kind() {
-                        synast::LiteralKind::IntNumber(int_num) => {
-                            Some(int_num.value().unwrap() as u32)
-                        }
-                        _ => todo!(), // error. can/should be caught at syntax level, obviously
-                    }
-                }
-                None => None,
-                _ => todo!(), // only literals supported
-            };
-            let typ = match width {
-                Some(width) => Type::QubitArray(ArrayDims::D1(width as usize)),
-                None => Type::Qubit,
-            };
+            let typ = from_qubit_type(&q_decl.qubit_type().unwrap(), context);
             let name_str = q_decl.name().unwrap().string();
             let symbol_id = context.new_binding(name_str.as_ref(), &typ, &q_decl);
             let q_decl_ast = asg::DeclareQuantum::new(symbol_id);
@@ -382,6 +846,10 @@ fn from_item(item: synast::Item, context: &mut Context) -> Option<asg::Stmt> {
             from_assignment_stmt(&assignment_stmt, context)
         }
 
+        synast::Item::MeasureArrowAssignmentStmt(measure_stmt) => {
+            from_measure_arrow_assignment_stmt(&measure_stmt, context)
+        }
+
         synast::Item::BreakStmt(_) => Some(asg::Stmt::Break),
 
         synast::Item::ContinueStmt(_) => Some(asg::Stmt::Continue),
@@ -391,8 +859,16 @@ fn from_item(item: synast::Item, context: &mut Context) -> Option<asg::Stmt> {
         // Gate definition
         synast::Item::Gate(gate) => {
             let name_node = gate.name().unwrap();
-            let gate_name_symbol_id =
-                context.new_binding(name_node.string().as_ref(), &Type::Gate, &name_node);
+            if gate.opaque_token().is_some() && !is_openqasm2(context) {
+                context.insert_error(OpenQASM2OnlyError, &gate);
+            }
+            let num_params = gate.angle_params().map_or(0, |p| p.params().count());
+            let num_qubits = gate.qubit_params().map_or(0, |p| p.params().count());
+            let gate_name_symbol_id = context.new_binding(
+                name_node.string().as_ref(),
+                &Type::Gate(num_params, num_qubits),
+                &name_node,
+            );
 
             // Here are three ways to manage the context.
 
@@ -424,50 +900,60 @@ fn from_item(item: synast::Item, context: &mut Context) -> Option<asg::Stmt> {
             with_scope!(context,  ScopeType::Subroutine,
                           let params = bind_parameter_list(gate.angle_params(), &Type::Angle(None, IsConst::True), context);
                           let qubits = bind_parameter_list(gate.qubit_params(), &Type::Qubit, context).unwrap();
-                          let block = from_block_expr(gate.body().unwrap(), context);
+                          // An `opaque` gate has no body.
+                          let block = gate.body().map_or_else(asg::Block::new, |body| from_block_expr(body, context));
             );
 
-            Some(asg::GateDeclaration::new(gate_name_symbol_id, params, qubits, block).to_stmt())
+            let gate_decl = if gate.opaque_token().is_some() {
+                asg::GateDeclaration::new_opaque(gate_name_symbol_id, params, qubits)
+            } else {
+                asg::GateDeclaration::new(gate_name_symbol_id, params, qubits, block)
+            };
+            Some(gate_decl.to_stmt())
         }
 
         synast::Item::GateCallStmt(gate_call) => {
             // Warning, I think map overlooks None. which can cause a bug, in the present case.
             // Because None means a coding error upstream. Better to blow up here.
-            let gate_operands = gate_call
+            let gate_operands: Vec<_> = gate_call
                 .qubit_list()
                 .unwrap()
                 .gate_operands()
-                .map(|qubit| match qubit {
-                    synast::GateOperand::HardwareQubit(ref hwq) => {
-                        asg::GateOperand::HardwareQubit(ast_hardware_qubit(hwq))
-                            .to_texpr(Type::HardwareQubit)
-                    }
-                    synast::GateOperand::Identifier(identifier) => {
-                        let (astidentifier, typ) = ast_identifier(&identifier, context);
-                        asg::GateOperand::Identifier(astidentifier).to_texpr(typ)
-                    }
-                    synast::GateOperand::IndexedIdentifier(indexed_identifier) => {
-                        let (indexed_identifier, typ) =
-                            ast_indexed_identifier(&indexed_identifier, context);
-                        asg::GateOperand::IndexedIdentifier(indexed_identifier).to_texpr(typ)
-                    }
-                })
+                .map(|qubit| from_gate_operand(qubit, context))
                 .collect();
 
             let param_list = gate_call
                 .arg_list()
                 .map(|ex| inner_expression_list(ex.expression_list().unwrap(), context));
+            let modifiers: Vec<_> = gate_call
+                .modifier_list()
+                .map(|modifier_list| {
+                    modifier_list
+                        .gate_modifiers()
+                        .filter_map(|modifier| from_gate_modifier(&modifier, context))
+                        .collect()
+                })
+                .unwrap_or_default();
             let gate_id = gate_call.identifier();
             // FIXME: make sure we are efficient with strings
             let gate_name = gate_call.identifier().unwrap().text().to_string();
-            let (symbol_result, _typ) = context
+            let (symbol_result, typ) = context
                 .lookup_symbol(gate_name.as_ref(), &gate_id.unwrap())
                 .as_tuple();
+            // Each control modifier consumes qubit operands in addition to those of the gate.
+            // If a control count is not constant, we can't check the number of operands.
+            if let (Type::Gate(_, num_qubits), Some(num_controls)) =
+                (&typ, num_control_qubits(&modifiers, context))
+            {
+                if gate_operands.len() != num_qubits + num_controls {
+                    context.insert_error(NumberOfQubitOperandsError, &gate_call);
+                }
+            }
             Some(asg::Stmt::GateCall(asg::GateCall::new(
                 symbol_result,
                 param_list,
                 gate_operands,
-                None,
+                modifiers,
             )))
         }
 
@@ -481,66 +967,559 @@ fn from_item(item: synast::Item, context: &mut Context) -> Option<asg::Stmt> {
             }
         }
 
+        synast::Item::DefCalGrammar(defcalgrammar) => {
+            if let Some(grammar) = defcalgrammar.file().and_then(|file| file.to_string()) {
+                context.program.set_calibration_grammar(grammar);
+            }
+            None
+        }
+
+        synast::Item::Cal(cal) => {
+            let body = cal.body().map(|body| body.contents()).unwrap_or_default();
+            Some(asg::Cal::new(body).to_stmt())
+        }
+
+        synast::Item::DefCal(defcal) => Some(from_defcal(&defcal, context)),
+
         synast::Item::VersionString(version_string) => {
-            let version = version_string.version().unwrap().version().unwrap();
-            dbg!(version.is_simple());
-            dbg!(version.clone());
-            let (p1, p2) = version.split_into_parts();
-            dbg!((p1, p2));
+            from_version_string(&version_string, context);
             None
         }
 
+        synast::Item::Reset(reset) => {
+            let operand = from_quantum_operand(reset.gate_operand().unwrap(), context);
+            Some(asg::Reset::new(operand).to_stmt())
+        }
+
+        synast::Item::Barrier(barrier) => {
+            let qubits = from_quantum_operands(barrier.qubit_list(), context);
+            Some(asg::Barrier::new(qubits).to_stmt())
+        }
+
+        synast::Item::DelayStmt(delay) => {
+            let duration = from_duration_designator(&delay.designator().unwrap(), context);
+            let qubits = from_quantum_operands(delay.qubit_list(), context);
+            Some(asg::Delay::new(duration, qubits).to_stmt())
+        }
+
+        synast::Item::BoxStmt(box_stmt) => {
+            let duration = box_stmt
+                .designator()
+                .map(|designator| from_duration_designator(&designator, context));
+            let body = from_block_expr(box_stmt.body().unwrap(), context);
+            Some(asg::BoxStmt::new(duration, body).to_stmt())
+        }
+
         synast::Item::GPhaseCallStmt(gphase) => {
             let synarg = gphase.arg().unwrap();
             let arg = from_expr(synarg, context).unwrap();
             Some(asg::Stmt::GPhaseCall(asg::GPhaseCall::new(arg)))
         }
 
+        synast::Item::AnnotatedStmt(annotated_stmt) => {
+            let annotations = annotated_stmt
+                .annotations()
+                .map(|annotation| asg::Annotation::new(annotation.keyword(), annotation.body()))
+                .collect();
+            let stmt = match annotated_stmt.stmt()? {
+                synast::Stmt::Item(item) => from_item(item, context),
+                synast::Stmt::ExprStmt(expr_stmt) => from_expr_stmt(expr_stmt, context),
+            }?;
+            Some(asg::AnnotatedStmt::new(stmt, annotations).to_stmt())
+        }
+
+        synast::Item::Pragma(pragma) => Some(asg::Pragma::new(pragma.content()).to_stmt()),
+
         _ => None,
     }
 }
 
+// Lower the designator of `delay` or `box`, which must be of type `duration` or `stretch`.
+fn from_duration_designator(designator: &synast::Designator, context: &mut Context) -> asg::TExpr {
+    let duration = from_expr(designator.expr().unwrap(), context).unwrap();
+    if !matches!(
+        duration.get_type(),
+        Type::Duration(..) | Type::Stretch(..) | Type::ToDo | Type::Undefined
+    ) {
+        context.insert_error(IncompatibleTypesError, designator);
+    }
+    duration
+}
+
 fn from_block_expr(block_synast: synast::BlockExpr, context: &mut Context) -> asg::Block {
     let mut block = asg::Block::new();
 
     for parse_item in block_synast.statements() {
+        let span = context.span(&parse_item);
         let stmt = match parse_item {
             synast::Stmt::Item(item) => from_item(item, context),
             synast::Stmt::ExprStmt(expr_stmt) => from_expr_stmt(expr_stmt, context),
         };
         if let Some(stmt) = stmt {
-            block.insert_stmt(stmt)
+            block.insert_stmt_with_span(stmt, span)
         }
     }
     block
 }
 
+fn from_def(def: &synast::Def, context: &mut Context) -> asg::Stmt {
+    let name_node = def.name().unwrap();
+    let return_type = def
+        .ret_type()
+        .and_then(|ret_type| ret_type.scalar_type())
+        .map(|scalar_type| from_scalar_type(&scalar_type, false, context));
+    let params = def
+        .typed_param_list()
+        .unwrap()
+        .typed_params()
+        .map(|param| {
+            let typ = from_typed_param(&param, context);
+            (param.name().unwrap(), typ)
+        })
+        .collect::<Vec<_>>();
+
+    // Bind the name of the subroutine before analyzing the body, so that recursive calls resolve.
+    let signature = Type::Subroutine(
+        params.iter().map(|(_, typ)| typ.clone()).collect(),
+        Box::new(return_type.clone().unwrap_or(Type::Void)),
+    );
+    if context.symbol_table().current_scope_type() != ScopeType::Global {
+        context.insert_error(DefNotInGlobalScopeError, def);
+    }
+    let def_name_symbol_id =
+        context.new_binding(name_node.string().as_ref(), &signature, &name_node);
+
+    // Analysis continues after a misplaced nested `def`, so the enclosing return type is restored.
+    let enclosing_return_type = context
+        .return_type
+        .replace(return_type.clone().unwrap_or(Type::Void));
+    with_scope!(context, ScopeType::Subroutine,
+                let param_ids = params
+                    .iter()
+                    .map(|(name, typ)| context.new_binding(name.string().as_ref(), typ, name))
+                    .collect::<Vec<_>>();
+                let block = from_block_expr(def.body().unwrap(), context);
+    );
+    context.return_type = enclosing_return_type;
+    asg::Def::new(def_name_symbol_id, param_ids, block, return_type).to_stmt()
+}
+
+// The control expression must be an integer. Case labels must be constant integers
+// and may not be repeated. `default` may appear only once, as the last item.
+fn from_switch_case_stmt(switch_stmt: &synast::SwitchCaseStmt, context: &mut Context) -> asg::Stmt {
+    let control = from_expr(switch_stmt.control().unwrap(), context).unwrap();
+    if !control.get_type().is_integer()
+        && !matches!(control.get_type(), Type::ToDo | Type::Undefined)
+    {
+        context.insert_error(IncompatibleTypesError, &switch_stmt.control().unwrap());
+    }
+    let mut cases = Vec::<asg::CaseExpr>::new();
+    let mut default_block = None;
+    let mut seen_values = Vec::<ConstValue>::new();
+    let mut seen_labels = Vec::<asg::TExpr>::new();
+    let num_items = switch_stmt.case_items().count();
+    for (i, case_item) in switch_stmt.case_items().enumerate() {
+        let block = match case_item.block_expr() {
+            Some(block_expr) => from_block_expr(block_expr, context),
+            None => asg::Block::new(),
+        };
+        if case_item.default_token().is_some() {
+            if i + 1 != num_items {
+                context.insert_error(MisplacedDefaultError, &case_item);
+            }
+            default_block = Some(block);
+            continue;
+        }
+        let mut labels = Vec::new();
+        for label_expr in case_item
+            .expression_list()
+            .iter()
+            .flat_map(|list| list.exprs())
+        {
+            let label = from_expr(label_expr.clone(), context).unwrap();
+            let label_type = label.get_type();
+            if !(label_type.is_integer() && label_type.is_const()) {
+                context.insert_error(ConstIntegerError, &label_expr);
+            } else {
+                // Labels are compared by value, so that `n` and `2` are the same label if
+                // `const int n = 2`. A label whose value is unknown is compared by its form.
+                let is_duplicate = match context.eval_const(&label) {
+                    Some(value) if seen_values.contains(&value) => true,
+                    Some(value) => {
+                        seen_values.push(value);
+                        false
+                    }
+                    None if seen_labels.contains(&label) => true,
+                    None => {
+                        seen_labels.push(label.clone());
+                        false
+                    }
+                };
+                if is_duplicate {
+                    context.insert_error(DuplicateCaseLabelError, &label_expr);
+                }
+            }
+            labels.push(label);
+        }
+        cases.push(asg::CaseExpr::new(labels, block));
+    }
+    asg::SwitchCaseStmt::new(control, cases, default_block).to_stmt()
+}
+
+// The parameters and qubit operands of a `defcal` are recorded so that gate calls
+// can be matched with their calibrations. The body is not analyzed.
+fn from_defcal(defcal: &synast::DefCal, context: &mut Context) -> asg::Stmt {
+    let name = defcal.name().unwrap().string();
+    let params = defcal
+        .def_cal_param_list()
+        .map(|param_list| {
+            param_list
+                .params()
+                .filter_map(|param| match param {
+                    synast::DefCalParam::Typed(typed_param) => {
+                        let typ = from_typed_param(&typed_param, context);
+                        let name = typed_param.name()?.string();
+                        Some(asg::DefCalParam::Typed(name, typ))
+                    }
+                    synast::DefCalParam::Constant(expr) => {
+                        from_expr(expr, context).map(asg::DefCalParam::Constant)
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let qubits = defcal
+        .qubit_list()
+        .map(|qubit_list| {
+            qubit_list
+                .gate_operands()
+                .filter_map(|operand| match operand {
+                    synast::GateOperand::HardwareQubit(hwq) => ast_hardware_qubit(&hwq)
+                        .index()
+                        .map(asg::DefCalQubit::Hardware),
+                    synast::GateOperand::Identifier(identifier) => {
+                        Some(asg::DefCalQubit::Identifier(identifier.string()))
+                    }
+                    synast::GateOperand::IndexedIdentifier(_) => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let return_type = defcal
+        .ret_type()
+        .and_then(|ret_type| ret_type.scalar_type())
+        .map(|scalar_type| from_scalar_type(&scalar_type, false, context));
+    let body = defcal.body().map(|body| body.contents());
+    asg::DefCal::new(name, params, qubits, return_type, body).to_stmt()
+}
+
+// Bind the name of the extern to a `Subroutine` type, so that calls are checked
+// against its signature in the same way as calls to a `def`.
+fn from_extern_item(extern_item: &synast::ExternItem, context: &mut Context) -> asg::Stmt {
+    let return_type = extern_item
+        .ret_type()
+        .and_then(|ret_type| ret_type.scalar_type())
+        .map(|scalar_type| from_scalar_type(&scalar_type, false, context));
+    let params = extern_item
+        .extern_param_list()
+        .map(|param_list| {
+            param_list
+                .extern_params()
+                .map(|param| from_extern_param(&param, context))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if context.symbol_table().current_scope_type() != ScopeType::Global {
+        context.insert_error(ExternNotInGlobalScopeError, extern_item);
+    }
+    let signature = Type::Subroutine(
+        params.clone(),
+        Box::new(return_type.clone().unwrap_or(Type::Void)),
+    );
+    let name_node = extern_item.name().unwrap();
+    let name_str = name_node.string();
+    let symbol_id = context.new_binding(name_str.as_ref(), &signature, &name_node);
+    asg::Extern::new(name_str, symbol_id, params, return_type).to_stmt()
+}
+
+// `creg[n]` is the old-style spelling of `bit[n]`.
+fn from_extern_param(param: &synast::ExternParam, context: &mut Context) -> Type {
+    if let Some(scalar_type) = param.scalar_type() {
+        from_scalar_type(&scalar_type, false, context)
+    } else if let Some(array_type) = param.array_type() {
+        from_array_type(&array_type, context)
+    } else if param.creg_token().is_some() {
+        match designator_width(param.designator(), context) {
+            Some(width) => Type::BitArray(ArrayDims::D1(width as usize), IsConst::False),
+            None => Type::Bit(IsConst::False),
+        }
+    } else {
+        // Missing type is a syntax error.
+        Type::Undefined
+    }
+}
+
+fn from_typed_param(param: &synast::TypedParam, context: &mut Context) -> Type {
+    if let Some(scalar_type) = param.scalar_type() {
+        from_scalar_type(&scalar_type, false, context)
+    } else if let Some(qubit_type) = param.qubit_type() {
+        from_qubit_type(&qubit_type, context)
+    } else if let Some(array_type) = param.array_type() {
+        from_array_type(&array_type, context)
+    } else {
+        // Missing type is a syntax error.
+        Type::Undefined
+    }
+}
+
+fn from_qubit_type(qubit_type: &synast::QubitType, context: &mut Context) -> Type {
+    match designator_width(qubit_type.designator(), context) {
+        Some(width) => Type::QubitArray(ArrayDims::D1(width as usize)),
+        None => Type::Qubit,
+    }
+}
+
+// Arrays passed as `readonly` have `const` type. But only `BitArray` carries `IsConst` at the moment.
+fn from_array_type(array_type: &synast::ArrayType, context: &mut Context) -> Type {
+    let isconst = array_type.readonly_token().is_some();
+    if let Some(dim_expr) = array_type.dim_expr() {
+        return from_unsized_array_type(array_type, &dim_expr, context);
+    }
+    let dims = array_type.expression_list().and_then(|expression_list| {
+        // Evaluate every dimension, so that an error is logged for each one that is invalid.
+        let dims = expression_list
+            .exprs()
+            .map(|expr| from_const_usize(expr, context))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .collect::<Option<Vec<_>>>()?;
+        let num_dims = dims.len();
+        let array_dims = ArrayDims::new(&dims);
+        if array_dims.is_none() && num_dims > MAX_ARRAY_DIMS {
+            context.insert_error(ArrayDimensionError, &expression_list);
+        }
+        array_dims
+    });
+    let Some(dims) = dims else {
+        return Type::ToDo;
+    };
+    let scalar_type = array_type.scalar_type().unwrap();
+    let width = scalar_type_width(&scalar_type, context);
+    match scalar_type.kind() {
+        synast::ScalarTypeKind::Int => Type::IntArray(dims, width),
+        synast::ScalarTypeKind::UInt => Type::UIntArray(dims, width),
+        synast::ScalarTypeKind::Float => Type::FloatArray(dims, width),
+        synast::ScalarTypeKind::Angle => Type::AngleArray(dims, width),
+        synast::ScalarTypeKind::Complex => Type::ComplexArray(dims, width),
+        synast::ScalarTypeKind::Bool => Type::BoolArray(dims),
+        synast::ScalarTypeKind::Duration => Type::DurationArray(dims),
+        synast::ScalarTypeKind::Bit => Type::BitArray(dims, isconst.into()),
+        _ => Type::ToDo,
+    }
+}
+
+// `array[type, #dim = n]`, which declares only the number of dimensions of an array parameter.
+fn from_unsized_array_type(
+    array_type: &synast::ArrayType,
+    dim_expr: &synast::DimExpr,
+    context: &mut Context,
+) -> Type {
+    let isconst = array_type.readonly_token().is_some();
+    let num_dims = dim_expr
+        .expr()
+        .and_then(|expr| from_const_usize(expr, context));
+    let num_dims = match num_dims {
+        Some(num_dims) if (1..=MAX_ARRAY_DIMS).contains(&num_dims) => num_dims,
+        Some(_) => {
+            context.insert_error(ArrayDimensionError, dim_expr);
+            return Type::Undefined;
+        }
+        None => return Type::Undefined,
+    };
+    // As for sized arrays, the elements of a `bit` array are single bits.
+    let element_type = match from_scalar_type(&array_type.scalar_type().unwrap(), false, context) {
+        Type::BitArray(..) => Type::Bit(IsConst::False),
+        typ => typ,
+    };
+    Type::UnsizedArray(Box::new(element_type), num_dims, isconst.into())
+}
+
+// Lower the value assigned to, or used to initialize, a variable of type `typ`.
+// An array literal takes its type from `typ`.
+fn from_initializer(expr: synast::Expr, typ: &Type, context: &mut Context) -> Option<asg::TExpr> {
+    match expr {
+        synast::Expr::ArrayLiteral(ref array_literal) if typ.is_classical_array() => {
+            Some(from_array_literal(array_literal, typ, context))
+        }
+        _ => from_expr(expr, context),
+    }
+}
+
+// Lower `array_literal` as a literal of the array type `typ`. Log `ArrayDimensionError`
+// if the nesting of the literal does not match the dimensions of `typ`, and
+// `IncompatibleTypesError` if an element cannot be cast to the element type.
+fn from_array_literal(
+    array_literal: &synast::ArrayLiteral,
+    typ: &Type,
+    context: &mut Context,
+) -> asg::TExpr {
+    let dims = typ.dims().unwrap();
+    let element_type = typ.array_element_type().unwrap();
+    let exprs: Vec<_> = array_literal
+        .expression_list()
+        .map_or_else(Vec::new, |exprs| exprs.exprs().collect());
+    if exprs.len() != dims[0] {
+        context.insert_error(ArrayDimensionError, array_literal);
+    }
+    let elements = exprs
+        .into_iter()
+        .filter_map(|expr| match (&expr, dims.len()) {
+            (synast::Expr::ArrayLiteral(inner), 2..) => {
+                let inner_type = typ.with_array_dims(&dims[1..]).unwrap();
+                Some(from_array_literal(inner, &inner_type, context))
+            }
+            (synast::Expr::ArrayLiteral(_), _) | (_, 2..) => {
+                context.insert_error(ArrayDimensionError, &expr);
+                from_expr(expr, context)
+            }
+            _ => {
+                let element = from_expr(expr.clone(), context)?;
+                if is_assignment_type_mismatch(&element_type, &element) {
+                    context.insert_error(IncompatibleTypesError, &expr);
+                }
+                Some(element)
+            }
+        })
+        .collect();
+    asg::ArrayLiteral::new(elements).to_texpr(typ.clone())
+}
+
 fn from_classical_declaration_statement(
     type_decl: &synast::ClassicalDeclarationStatement,
     context: &mut Context,
 ) -> asg::Stmt {
-    let scalar_type = type_decl.scalar_type().unwrap();
     let isconst = type_decl.const_token().is_some();
+    let typ = match (type_decl.scalar_type(), type_decl.array_type()) {
+        (Some(scalar_type), _) => from_scalar_type(&scalar_type, isconst, context),
+        (None, Some(array_type)) => from_array_type(&array_type, context),
+        (None, None) => Type::Undefined,
+    };
 
-    // We only support literal integer designators at the moment.
-    let width = match scalar_type.designator().and_then(|desg| desg.expr()) {
-        Some(synast::Expr::Literal(ref literal)) => {
-            match literal.kind() {
-                synast::LiteralKind::IntNumber(int_num) => Some(int_num.value().unwrap() as u32),
-                _ => {
-                    // FIXME: This error should be done when validating syntax. Before the semantic analysis
-                    context.insert_error(ConstIntegerError, literal);
-                    None // FIXME. This should be something signifying an invalid type
-                         // `None` signifies a valid type.
-                }
+    let name_str = type_decl.name().unwrap().string();
+    let initializer = type_decl
+        .expr()
+        .and_then(|initializer| from_initializer(initializer, &typ, context));
+
+    // FIXME: This error and several others can and should be moved to a subsequent pass,
+    // which would locate them with the spans recorded in the ASG.
+    let symbol_id = context.new_binding(name_str.as_ref(), &typ, type_decl);
+    if let Some(ref initializer) = initializer {
+        if is_assignment_type_mismatch(&typ, initializer) {
+            context.insert_error(IncompatibleTypesError, type_decl);
+        }
+        // Record the value of a constant, so that it may be used in designators, for example.
+        if let (true, Ok(id)) = (isconst, &symbol_id) {
+            if let Some(value) = context.eval_const(initializer).and_then(|v| v.cast(&typ)) {
+                context.const_values.insert(id.clone(), value);
             }
         }
-        Some(expr) => panic!("Unsupported designator type: {:?}", type_name_of(expr)),
-        None => None,
+    }
+    asg::DeclareClassical::new(symbol_id, initializer).to_stmt()
+}
+
+fn from_io_declaration_statement(
+    io_decl: &synast::IODeclarationStatement,
+    context: &mut Context,
+) -> asg::Stmt {
+    let io_type = if io_decl.input_token().is_some() {
+        IOType::Input
+    } else {
+        IOType::Output
+    };
+    let typ = match (io_decl.scalar_type(), io_decl.array_type()) {
+        (Some(scalar_type), _) => from_scalar_type(&scalar_type, false, context),
+        (None, Some(array_type)) => from_array_type(&array_type, context),
+        (None, None) => Type::Undefined,
     };
+    if context.symbol_table().current_scope_type() != ScopeType::Global {
+        context.insert_error(IODeclarationNotInGlobalScopeError, io_decl);
+    }
+    let name_str = io_decl.name().unwrap().string();
+    let symbol_id = context.new_binding(name_str.as_ref(), &typ, io_decl);
+    asg::IODeclaration::new(name_str, typ, symbol_id, io_type).to_stmt()
+}
+
+// Only registers, slices of registers, and their concatenations may be aliased.
+fn from_let_stmt(let_stmt: &synast::LetStmt, context: &mut Context) -> asg::Stmt {
+    let rvalue = from_expr(let_stmt.expr().unwrap(), context).unwrap();
+    let typ = rvalue.get_type().clone();
+    if typ.register_length().is_none() && !matches!(typ, Type::ToDo | Type::Undefined) {
+        context.insert_error(IncompatibleTypesError, let_stmt);
+    }
+    let name_str = let_stmt.name().unwrap().string();
+    let symbol_id = context.new_binding(name_str.as_ref(), &typ, let_stmt);
+    asg::Alias::new(symbol_id, rvalue).to_stmt()
+}
+
+// `qreg q[n];` declares the same thing as `qubit[n] q;` and `creg c[n];` the same
+// thing as `bit[n] c;`. So we lower them to the same statements.
+fn from_old_style_declaration_statement(
+    old_decl: &synast::OldStyleDeclarationStatement,
+    context: &mut Context,
+) -> asg::Stmt {
+    let width = designator_width(old_decl.designator(), context);
+    let name_str = old_decl.name().unwrap().string();
+    if old_decl.qreg_token().is_some() {
+        let typ = match width {
+            Some(width) => Type::QubitArray(ArrayDims::D1(width as usize)),
+            None => Type::Qubit,
+        };
+        let symbol_id = context.new_binding(name_str.as_ref(), &typ, old_decl);
+        asg::Stmt::DeclareQuantum(asg::DeclareQuantum::new(symbol_id))
+    } else {
+        let typ = match width {
+            Some(width) => Type::BitArray(ArrayDims::D1(width as usize), IsConst::False),
+            None => Type::Bit(IsConst::False),
+        };
+        let symbol_id = context.new_binding(name_str.as_ref(), &typ, old_decl);
+        asg::DeclareClassical::new(symbol_id, None).to_stmt()
+    }
+}
+
+fn is_openqasm2(context: &Context) -> bool {
+    matches!(context.program().version(), Some(version) if version.major() == 2)
+}
+
+// Record the version and, for OpenQASM 2, define the builtin `CX` gate.
+fn from_version_string(version_string: &synast::VersionString, context: &mut Context) {
+    let version = version_string.version().unwrap().token();
+    let (major, minor) = version
+        .text()
+        .split_once('.')
+        .unwrap_or((version.text(), "0"));
+    let (Ok(major), Ok(minor)) = (major.parse::<usize>(), minor.parse::<usize>()) else {
+        return;
+    };
+    if context.program().version().is_some() {
+        return;
+    }
+    context
+        .program
+        .set_version(asg::OpenQASMVersion::new(major, minor));
+    if major == 2 {
+        context.define_openqasm2_builtins();
+    }
+}
 
-    let typ = match scalar_type.kind() {
+fn from_scalar_type(
+    scalar_type: &synast::ScalarType,
+    isconst: bool,
+    context: &mut Context,
+) -> Type {
+    let width = scalar_type_width(scalar_type, context);
+    match scalar_type.kind() {
         synast::ScalarTypeKind::Int => Type::Int(width, isconst.into()),
+        synast::ScalarTypeKind::UInt => Type::UInt(width, isconst.into()),
         synast::ScalarTypeKind::Float => Type::Float(width, isconst.into()),
         synast::ScalarTypeKind::Angle => Type::Angle(width, isconst.into()),
         synast::ScalarTypeKind::Bit => match width {
@@ -548,23 +1527,57 @@ fn from_classical_declaration_statement(
             None => Type::Bit(isconst.into()),
         },
         synast::ScalarTypeKind::Bool => Type::Bool(isconst.into()),
+        synast::ScalarTypeKind::Duration => Type::Duration(isconst.into()),
+        synast::ScalarTypeKind::Stretch => Type::Stretch(isconst.into()),
+        synast::ScalarTypeKind::Complex => Type::Complex(width, isconst.into()),
         _ => todo!(),
-    };
+    }
+}
 
-    let name_str = type_decl.name().unwrap().string();
-    let initializer = type_decl
-        .expr()
-        .and_then(|initializer| from_expr(initializer, context));
+// The width of a scalar type. The width of `complex[float[w]]` is the width `w` of its
+// components, which must be `float`s.
+fn scalar_type_width(scalar_type: &synast::ScalarType, context: &mut Context) -> Option<u32> {
+    if scalar_type.kind() != synast::ScalarTypeKind::Complex {
+        return designator_width(scalar_type.designator(), context);
+    }
+    let component_type = scalar_type.scalar_type()?;
+    if component_type.kind() != synast::ScalarTypeKind::Float {
+        context.insert_error(IncompatibleTypesError, &component_type);
+        return None;
+    }
+    designator_width(component_type.designator(), context)
+}
 
-    // FIXME: This error and several others can and should be moved to a subsequent pass.
-    // However, we would lose the information in `text_range` unless we do something to preserve it.
-    let symbol_id = context.new_binding(name_str.as_ref(), &typ, type_decl);
-    if let Some(ref initializer) = initializer {
-        if !types::can_cast_loose(&typ, initializer.get_type()) {
-            context.insert_error(IncompatibleTypesError, type_decl);
+fn designator_width(designator: Option<synast::Designator>, context: &mut Context) -> Option<u32> {
+    let expr = designator.and_then(|desg| desg.expr())?;
+    // FIXME: An invalid width is treated as if there were no designator.
+    from_const_usize(expr, context).and_then(|width| u32::try_from(width).ok())
+}
+
+// Evaluate `expr`, which must be a constant non-negative integer, as is required of a
+// designator, an array dimension, or the argument of `ctrl`. Log `NonConstantError` if
+// `expr` is not constant, and `ConstIntegerError` if its value is not a non-negative integer.
+fn from_const_usize(expr: synast::Expr, context: &mut Context) -> Option<usize> {
+    let Some(texpr) = from_expr(expr.clone(), context) else {
+        context.insert_error(NonConstantError, &expr);
+        return None;
+    };
+    match context.eval_const(&texpr) {
+        Some(value) => {
+            let value = value.as_usize();
+            if value.is_none() {
+                context.insert_error(ConstIntegerError, &expr);
+            }
+            value
+        }
+        None => {
+            // An undefined symbol has already been logged.
+            if !matches!(texpr.get_type(), Type::Undefined) {
+                context.insert_error(NonConstantError, &expr);
+            }
+            None
         }
     }
-    asg::DeclareClassical::new(symbol_id, initializer).to_stmt()
 }
 
 // FIXME: In oq3_syntax we have both Name and Identifier. We only need one, I think.
@@ -574,19 +1587,210 @@ fn from_assignment_stmt(
     assignment_stmt: &synast::AssignmentStmt,
     context: &mut Context,
 ) -> Option<asg::Stmt> {
-    let nameb = assignment_stmt.name();
-    let name = nameb.as_ref().unwrap();
-    let name_str = name.string();
-    let expr = from_expr(assignment_stmt.expr().unwrap(), context); // rhs of `=` operator
-
-    let (symbol_id, typ) = context.lookup_symbol(name_str.as_str(), name).as_tuple();
-    let is_mutating_const = symbol_id.is_ok() && typ.is_const();
-    let lvalue = asg::LValue::Identifier(symbol_id);
-    let ret_stmt = Some(asg::Assignment::new(lvalue, expr.unwrap()).to_stmt());
+    let lvalue = match assignment_stmt.indexed_identifier() {
+        Some(indexed_identifier) => from_indexed_lvalue(&indexed_identifier, context),
+        None => {
+            let name = assignment_stmt.name().unwrap();
+            from_identifier_lvalue(name.string().as_str(), &name, context)
+        }
+    };
+    // rhs of `=` operator
+    let expr = from_initializer(assignment_stmt.rhs().unwrap(), &lvalue.1, context);
+    let op = match assignment_stmt.op_kind() {
+        Some(synast::BinaryOp::Assignment { op: Some(arith_op) }) => {
+            asg::AssignOp::Compound(from_arith_op(arith_op))
+        }
+        _ => asg::AssignOp::Assign,
+    };
+    Some(checked_assignment(
+        lvalue,
+        op,
+        expr.unwrap(),
+        assignment_stmt,
+        context,
+    ))
+}
+
+// `measure q;` is an expression statement. `measure q -> c;` is the assignment `c = measure q;`
+fn from_measure_arrow_assignment_stmt(
+    measure_stmt: &synast::MeasureArrowAssignmentStmt,
+    context: &mut Context,
+) -> Option<asg::Stmt> {
+    let measure = from_measure_expression(&measure_stmt.measure_expression().unwrap(), context);
+    let lvalue = if let Some(identifier) = measure_stmt.identifier() {
+        from_identifier_lvalue(identifier.string().as_str(), &identifier, context)
+    } else if let Some(indexed_identifier) = measure_stmt.indexed_identifier() {
+        from_indexed_lvalue(&indexed_identifier, context)
+    } else {
+        return Some(asg::Stmt::ExprStmt(measure));
+    };
+    Some(checked_assignment(
+        lvalue,
+        asg::AssignOp::Assign,
+        measure,
+        measure_stmt,
+        context,
+    ))
+}
+
+// An lvalue together with its type, and `true` if the assignment would mutate a `const`.
+type LValueInfo = (asg::LValue, Type, bool);
+
+fn from_identifier_lvalue<T>(name: &str, node: &T, context: &mut Context) -> LValueInfo
+where
+    T: synast::AstNode,
+{
+    let (symbol_id, typ) = context.lookup_symbol(name, node).as_tuple();
+    // Assigning to a qubit is a type error, rather than mutating a `const`.
+    let is_mutating_const = symbol_id.is_ok() && typ.is_const() && !typ.is_quantum();
+    (asg::LValue::Identifier(symbol_id), typ, is_mutating_const)
+}
+
+fn from_indexed_lvalue(
+    syn_indexed_identifier: &synast::IndexedIdentifier,
+    context: &mut Context,
+) -> LValueInfo {
+    let (indexed_identifier, typ) = ast_indexed_identifier(syn_indexed_identifier, context);
+    let is_mutating_const =
+        indexed_identifier.identifier().is_ok() && typ.is_const() && !typ.is_quantum();
+    let element_type = indexed_type(
+        &typ,
+        indexed_identifier.indexes(),
+        syn_indexed_identifier,
+        context,
+    );
+    (
+        indexed_lvalue(indexed_identifier, &typ),
+        element_type,
+        is_mutating_const,
+    )
+}
+
+// An indexed bit or qubit register is a `RegisterSlice`, and an indexed classical array
+// is an `ArraySlice`. Other indexed identifiers, for example when the identifier is
+// not bound, are kept as they are.
+fn indexed_lvalue(indexed_identifier: asg::IndexedIdentifier, typ: &Type) -> asg::LValue {
+    let name = indexed_identifier.identifier().clone();
+    if typ.register_element_type().is_some() {
+        let index = match indexed_identifier.indexes() {
+            [asg::IndexOperator::ExpressionList(list)] if list.expressions.len() == 1 => {
+                let texpr = &list.expressions[0];
+                match texpr.expression() {
+                    asg::Expr::Range(range) => Some(asg::RegisterIndex::Range(range.clone())),
+                    _ => Some(asg::RegisterIndex::Expr(texpr.clone())),
+                }
+            }
+            [asg::IndexOperator::SetExpression(set)] => Some(asg::RegisterIndex::Set(set.clone())),
+            _ => None,
+        };
+        if let Some(index) = index {
+            return asg::LValue::RegisterSlice(asg::RegisterSlice::new(name, index));
+        }
+    } else if typ.is_classical_array() {
+        // `a[i][j]` and `a[i, j]` are the same element.
+        let indices: Option<Vec<_>> = indexed_identifier
+            .indexes()
+            .iter()
+            .map(|index| match index {
+                asg::IndexOperator::ExpressionList(list) => Some(&list.expressions),
+                asg::IndexOperator::SetExpression(_) => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(|lists| {
+                lists
+                    .into_iter()
+                    .flatten()
+                    .map(|texpr| match texpr.expression() {
+                        asg::Expr::Range(_) => asg::ArraySliceIndex::Range(texpr.clone()),
+                        _ => asg::ArraySliceIndex::Expr(texpr.clone()),
+                    })
+                    .collect()
+            });
+        if let Some(indices) = indices {
+            return asg::LValue::ArraySlice(asg::ArraySlice::new(name, indices));
+        }
+    }
+    asg::LValue::IndexedIdentifier(indexed_identifier)
+}
+
+fn checked_assignment<T>(
+    lvalue: LValueInfo,
+    op: asg::AssignOp,
+    rvalue: asg::TExpr,
+    node: &T,
+    context: &mut Context,
+) -> asg::Stmt
+where
+    T: synast::AstNode,
+{
+    let (lvalue, typ, is_mutating_const) = lvalue;
     if is_mutating_const {
-        context.insert_error(MutateConstError, assignment_stmt);
+        context.insert_error(MutateConstError, node);
+    }
+    let is_compatible = match &op {
+        asg::AssignOp::Assign => !is_assignment_type_mismatch(&typ, &rvalue),
+        // `x op= y` is checked as `x op y`.
+        asg::AssignOp::Compound(arith_op) => {
+            let bin_op = asg::BinaryOp::ArithOp(arith_op.clone());
+            !typ.is_quantum() && is_binary_op_defined(&bin_op, &typ, rvalue.get_type())
+        }
+    };
+    if !is_compatible {
+        context.insert_error(IncompatibleTypesError, node);
+    }
+    asg::Assignment::new(lvalue, op, rvalue).to_stmt()
+}
+
+// The result of measuring a qubit is a `bit`. The result of measuring a register is
+// a bit register of the same length.
+fn from_measure_expression(
+    measure_expr: &synast::MeasureExpression,
+    context: &mut Context,
+) -> asg::TExpr {
+    let operand = from_gate_operand(measure_expr.gate_operand().unwrap(), context);
+    let typ = match operand.get_type() {
+        Type::Qubit | Type::HardwareQubit => Type::Bit(IsConst::False),
+        Type::QubitArray(dims) => Type::BitArray(dims.clone(), IsConst::False),
+        Type::ToDo | Type::Undefined => Type::ToDo,
+        _ => {
+            context.insert_error(IncompatibleTypesError, measure_expr);
+            Type::ToDo
+        }
+    };
+    asg::MeasureExpression::new(operand)
+        .to_texpr(typ)
+        .with_span(context.span(measure_expr))
+}
+
+// Return `true` if `rvalue` may not be assigned to a target of type `typ`. That is, if
+// `rvalue` is a measurement whose result does not have the same width as the target, or
+// if the type of `rvalue` cannot be converted implicitly to `typ`.
+fn is_assignment_type_mismatch(typ: &Type, rvalue: &asg::TExpr) -> bool {
+    let is_known = |typ: &Type| !matches!(typ, Type::ToDo | Type::Undefined | Type::Void);
+    let rvalue_type = rvalue.get_type();
+    if !is_known(typ) || !is_known(rvalue_type) {
+        return false;
+    }
+    if matches!(rvalue.expression(), asg::Expr::Measure(_)) {
+        return !types::equal_up_to_constness(typ, rvalue_type);
+    }
+    // A bit register is also a classical array, but may be cast to an integer.
+    let is_array = |typ: &Type| typ.is_classical_array() && !matches!(typ, Type::BitArray(..));
+    match (typ, rvalue_type) {
+        // Qubits are not assigned to.
+        (Type::Qubit | Type::QubitArray(_) | Type::HardwareQubit, _) => true,
+        // A bit register is assigned only from a register of the same length.
+        (Type::BitArray(dims1, _), Type::BitArray(dims2, _)) => dims1 != dims2,
+        // Arrays are assigned only from arrays of the same shape.
+        (typ, rvalue_type) if is_array(typ) || is_array(rvalue_type) => {
+            typ.dims() != rvalue_type.dims()
+                || !types::can_cast_implicit(
+                    &rvalue_type.array_element_type().unwrap(),
+                    &typ.array_element_type().unwrap(),
+                )
+        }
+        _ => !types::can_cast_implicit(rvalue_type, typ),
     }
-    ret_stmt
 }
 
 //
@@ -611,6 +1815,31 @@ fn ast_identifier(
     (asg::Identifier::new(name_str, symbol_id), typ)
 }
 
+fn from_gate_operand(gate_operand: synast::GateOperand, context: &mut Context) -> asg::TExpr {
+    let span = context.span(&gate_operand);
+    let operand = match gate_operand {
+        synast::GateOperand::HardwareQubit(ref hwq) => {
+            asg::GateOperand::HardwareQubit(ast_hardware_qubit(hwq)).to_texpr(Type::HardwareQubit)
+        }
+        synast::GateOperand::Identifier(identifier) => {
+            let (astidentifier, typ) = ast_identifier(&identifier, context);
+            asg::GateOperand::Identifier(astidentifier).to_texpr(typ)
+        }
+        synast::GateOperand::IndexedIdentifier(syn_indexed_identifier) => {
+            let (indexed_identifier, typ) =
+                ast_indexed_identifier(&syn_indexed_identifier, context);
+            let typ = indexed_type(
+                &typ,
+                indexed_identifier.indexes(),
+                &syn_indexed_identifier,
+                context,
+            );
+            asg::GateOperand::IndexedIdentifier(indexed_identifier).to_texpr(typ)
+        }
+    };
+    operand.with_span(span)
+}
+
 fn ast_indexed_identifier(
     indexed_identifier: &synast::IndexedIdentifier,
     context: &mut Context,
@@ -626,6 +1855,193 @@ fn ast_indexed_identifier(
     (asg::IndexedIdentifier::new(symbol_id, indexes), typ)
 }
 
+// Return `None` for a `pow` modifier whose exponent is missing or is not a number. The error
+// is reported, and the modifier is dropped.
+fn from_gate_modifier(
+    modifier: &synast::GateModifier,
+    context: &mut Context,
+) -> Option<asg::GateModifier> {
+    let arg = modifier.expr().and_then(|expr| from_expr(expr, context));
+    // The number of control qubits must be a constant positive integer.
+    if let (Some(arg), Some(expr)) = (&arg, modifier.expr()) {
+        if modifier.pow_token().is_none() {
+            match context.eval_const(arg).map(|value| value.as_usize()) {
+                Some(Some(num)) if num > 0 => (),
+                Some(_) => context.insert_error(ConstIntegerError, &expr),
+                None if matches!(arg.get_type(), Type::Undefined) => (),
+                None => context.insert_error(NonConstantError, &expr),
+            }
+        }
+    }
+    let modifier = if modifier.inv_token().is_some() {
+        asg::GateModifier::Inv
+    } else if modifier.pow_token().is_some() {
+        let Some(arg) = arg else {
+            context.insert_error(NumberOfArgumentsError, modifier);
+            return None;
+        };
+        if !matches!(
+            arg.get_type(),
+            Type::Int(..) | Type::UInt(..) | Type::Float(..) | Type::ToDo | Type::Undefined
+        ) {
+            context.insert_error(ArgumentTypeError, modifier);
+            return None;
+        }
+        asg::GateModifier::Pow(arg)
+    } else if modifier.ctrl_token().is_some() {
+        asg::GateModifier::Ctrl(arg)
+    } else if modifier.negctrl_token().is_some() {
+        asg::GateModifier::NegCtrl(arg)
+    } else {
+        unreachable!()
+    };
+    Some(modifier)
+}
+
+// Total number of control qubits added by `modifiers`. A control modifier without
+// an argument adds one control qubit. Returns `None` if some count is not a constant integer.
+fn num_control_qubits(modifiers: &[asg::GateModifier], context: &Context) -> Option<usize> {
+    modifiers
+        .iter()
+        .try_fold(0, |total, modifier| match modifier {
+            asg::GateModifier::Ctrl(None) | asg::GateModifier::NegCtrl(None) => Some(total + 1),
+            asg::GateModifier::Ctrl(Some(num)) | asg::GateModifier::NegCtrl(Some(num)) => {
+                Some(total + context.eval_const(num)?.as_usize()?)
+            }
+            _ => Some(total),
+        })
+}
+
+// Lower an operand that must be a qubit or qubit register, as in `reset` or `barrier`.
+fn from_quantum_operand(gate_operand: synast::GateOperand, context: &mut Context) -> asg::TExpr {
+    let operand = from_gate_operand(gate_operand.clone(), context);
+    if !matches!(
+        operand.get_type(),
+        Type::Qubit | Type::QubitArray(..) | Type::HardwareQubit | Type::ToDo | Type::Undefined
+    ) {
+        context.insert_error(IncompatibleTypesError, &gate_operand);
+    }
+    operand
+}
+
+fn from_quantum_operands(
+    qubit_list: Option<synast::QubitList>,
+    context: &mut Context,
+) -> Option<Vec<asg::TExpr>> {
+    qubit_list.map(|qubit_list| {
+        qubit_list
+            .gate_operands()
+            .map(|gate_operand| from_quantum_operand(gate_operand, context))
+            .collect()
+    })
+}
+
+// The type of the identifier of type `typ` indexed by `indexes`. Each index operator applies
+// to the array resulting from the previous one. Within an operator, a scalar index removes
+// a dimension and a range or set keeps it with the length of the slice. So the type of
+// `a[1, 2]` is the element type of a two-dimensional array `a`.
+// Log `TooManyIndicesError` if there are more indices than dimensions.
+fn indexed_type<T>(
+    typ: &Type,
+    indexes: &[asg::IndexOperator],
+    node: &T,
+    context: &mut Context,
+) -> Type
+where
+    T: synast::AstNode,
+{
+    if matches!(typ, Type::Undefined | Type::ToDo) {
+        return typ.clone();
+    }
+    if let Type::UnsizedArray(element_type, num_dims, _) = typ {
+        return unsized_indexed_type(element_type, *num_dims, indexes, node, context);
+    }
+    // FIXME: Bit-level indexing of scalars, for example `int[8] x; x[0]`, is not supported.
+    let Some(mut dims) = typ.dims() else {
+        return Type::ToDo;
+    };
+    for index in indexes {
+        let lengths: Vec<Option<Option<usize>>> = match index {
+            // `None` for a scalar index. `Some(len)` for a slice, where `len` may be unknown.
+            asg::IndexOperator::ExpressionList(list) => list
+                .expressions
+                .iter()
+                .map(|texpr| match texpr.expression() {
+                    asg::Expr::Range(range) => Some(range_length(range)),
+                    _ => None,
+                })
+                .collect(),
+            asg::IndexOperator::SetExpression(set) => vec![Some(Some(set.expressions().len()))],
+        };
+        if lengths.len() > dims.len() {
+            context.insert_error(TooManyIndicesError, node);
+            return Type::Undefined;
+        }
+        let rest = dims.split_off(lengths.len());
+        let Some(sliced) = lengths.into_iter().flatten().collect::<Option<Vec<_>>>() else {
+            return Type::ToDo;
+        };
+        dims = sliced.into_iter().chain(rest).collect();
+    }
+    typ.with_array_dims(&dims).unwrap_or(Type::ToDo)
+}
+
+// The type of an array declared with `#dim = num_dims` indexed by `indexes`. An element is
+// selected by one index for each dimension. The type of a subarray, whose sizes are not
+// known, is not represented.
+fn unsized_indexed_type<T>(
+    element_type: &Type,
+    num_dims: usize,
+    indexes: &[asg::IndexOperator],
+    node: &T,
+    context: &mut Context,
+) -> Type
+where
+    T: synast::AstNode,
+{
+    let mut num_indices = 0;
+    for index in indexes {
+        match index {
+            asg::IndexOperator::ExpressionList(list)
+                if list
+                    .expressions
+                    .iter()
+                    .all(|texpr| !matches!(texpr.expression(), asg::Expr::Range(_))) =>
+            {
+                num_indices += list.expressions.len();
+            }
+            _ => return Type::ToDo,
+        }
+    }
+    match num_indices.cmp(&num_dims) {
+        std::cmp::Ordering::Less => Type::ToDo,
+        std::cmp::Ordering::Equal => element_type.clone(),
+        std::cmp::Ordering::Greater => {
+            context.insert_error(TooManyIndicesError, node);
+            Type::Undefined
+        }
+    }
+}
+
+// The number of elements in the range `start:step:stop`, which includes `stop`,
+// if the bounds and step are integer literals.
+fn range_length(range: &asg::Range) -> Option<usize> {
+    let int_value = |texpr: &asg::TExpr| match texpr.expression() {
+        asg::Expr::Literal(asg::Literal::Int(int)) => Some(*int.value()),
+        _ => None,
+    };
+    let start = int_value(range.start())?;
+    let stop = int_value(range.stop())?;
+    let step = match range.step() {
+        Some(step) => int_value(step)?,
+        None => 1,
+    };
+    if step == 0 || stop < start {
+        return Some(0);
+    }
+    usize::try_from((stop - start) / step + 1).ok()
+}
+
 // Bind all parameter names to new symbols. Assume they all have common type `typ`.
 // Log RedeclarationError when it occurs.
 fn bind_parameter_list(
@@ -22,13 +22,15 @@
 use boolenum::BoolEnum;
 
 #[derive(BoolEnum, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IsConst {
     True,
     False,
 }
 
-// I think semantics of these should be cleared up before implementing
+// Whether a variable is declared with `input` or `output`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IOType {
     Input,
     Output,
@@ -45,6 +47,7 @@ type Width = Option<u32>;
 
 #[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
     // Scalar types
     Bit(IsConst),
@@ -59,19 +62,26 @@ pub enum Type {
     Bool(IsConst),           // bool field is is_const
     Duration(IsConst),
     Stretch(IsConst),
-    // Arrays
+    // Arrays. Width is the bit width of the elements.
     BitArray(ArrayDims, IsConst),
     QubitArray(ArrayDims),
-    IntArray(ArrayDims),
-    UIntArray(ArrayDims),
-    FloatArray(ArrayDims),
-    AngleArray(ArrayDims),
-    ComplexArray(ArrayDims),
+    IntArray(ArrayDims, Width),
+    UIntArray(ArrayDims, Width),
+    FloatArray(ArrayDims, Width),
+    AngleArray(ArrayDims, Width),
+    ComplexArray(ArrayDims, Width),
     BoolArray(ArrayDims),
     DurationArray(ArrayDims),
+    // An array parameter declared with `#dim = n`: the element type and the number of
+    // dimensions. The size of each dimension is not known. `IsConst` is `True` if `readonly`.
+    UnsizedArray(Box<Type>, usize, IsConst),
 
     // Other
-    Gate,  // <-- this type seems anomalous
+    // Gate signature: number of angle parameters and number of qubit parameters.
+    Gate(usize, usize), // <-- this type seems anomalous
+    // Subroutine signature: types of the parameters and the return type.
+    // The return type is `Void` if the subroutine does not return a value.
+    Subroutine(Vec<Type>, Box<Type>),
     Range, // temporary placeholder, perhaps
     Void,
     ToDo, // not yet implemented
@@ -85,26 +95,60 @@ pub enum Type {
 // Probably exists a much better way to represent dims... [usize, N]
 // Could use Box for higher dimensional arrays, or...
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArrayDims {
     D1(usize),
     D2(usize, usize),
     D3(usize, usize, usize),
+    D4(usize, usize, usize, usize),
+    D5(usize, usize, usize, usize, usize),
+    D6(usize, usize, usize, usize, usize, usize),
+    D7(usize, usize, usize, usize, usize, usize, usize),
 }
 
+/// The maximum number of dimensions of an array.
+pub const MAX_ARRAY_DIMS: usize = 7;
+
 impl ArrayDims {
+    /// Return the `ArrayDims` with sizes `dims`, or `None` if the number of
+    /// dimensions is zero or greater than `MAX_ARRAY_DIMS`.
+    pub fn new(dims: &[usize]) -> Option<ArrayDims> {
+        use ArrayDims::*;
+        let dims = match *dims {
+            [a] => D1(a),
+            [a, b] => D2(a, b),
+            [a, b, c] => D3(a, b, c),
+            [a, b, c, d] => D4(a, b, c, d),
+            [a, b, c, d, e] => D5(a, b, c, d, e),
+            [a, b, c, d, e, f] => D6(a, b, c, d, e, f),
+            [a, b, c, d, e, f, g] => D7(a, b, c, d, e, f, g),
+            _ => return None,
+        };
+        Some(dims)
+    }
+
     pub fn num_dims(&self) -> i32 {
         match self {
             ArrayDims::D1(..) => 1,
             ArrayDims::D2(..) => 2,
             ArrayDims::D3(..) => 3,
+            ArrayDims::D4(..) => 4,
+            ArrayDims::D5(..) => 5,
+            ArrayDims::D6(..) => 6,
+            ArrayDims::D7(..) => 7,
         }
     }
 
     pub fn dims(&self) -> Vec<usize> {
-        match self {
-            ArrayDims::D1(a) => vec![*a],
-            ArrayDims::D2(a, b) => vec![*a, *b],
-            ArrayDims::D3(a, b, c) => vec![*a, *b, *c],
+        use ArrayDims::*;
+        match *self {
+            D1(a) => vec![a],
+            D2(a, b) => vec![a, b],
+            D3(a, b, c) => vec![a, b, c],
+            D4(a, b, c, d) => vec![a, b, c, d],
+            D5(a, b, c, d, e) => vec![a, b, c, d, e],
+            D6(a, b, c, d, e, f) => vec![a, b, c, d, e, f],
+            D7(a, b, c, d, e, f, g) => vec![a, b, c, d, e, f, g],
         }
     }
 }
@@ -155,23 +199,142 @@ impl Type {
     pub fn is_const(&self) -> bool {
         use Type::*;
         match self {
-            Bit(c) | Int(_, c) => matches!(*c, IsConst::True),
+            Bit(c)
+            | Bool(c)
+            | Duration(c)
+            | Stretch(c)
+            | BitArray(_, c)
+            | UnsizedArray(_, _, c) => {
+                matches!(*c, IsConst::True)
+            }
+            Int(_, c) | UInt(_, c) | Float(_, c) | Angle(_, c) | Complex(_, c) => {
+                matches!(*c, IsConst::True)
+            }
+            // FIXME: Only `BitArray` records whether an array is `readonly`.
+            IntArray(..) | UIntArray(..) | FloatArray(..) | AngleArray(..) | ComplexArray(..)
+            | BoolArray(..) | DurationArray(..) => false,
             _ => true,
         }
     }
 
+    /// Return `true` if the type is `duration` or `stretch`.
+    pub fn is_timing(&self) -> bool {
+        matches!(self, Type::Duration(..) | Type::Stretch(..))
+    }
+
+    /// Return `true` if the type is `int` or `uint`.
+    pub fn is_integer(&self) -> bool {
+        matches!(self, Type::Int(..) | Type::UInt(..))
+    }
+
+    /// Return `true` if the type is a real scalar numeric type.
+    pub fn is_real_numeric(&self) -> bool {
+        matches!(self, Type::Int(..) | Type::UInt(..) | Type::Float(..))
+    }
+
     /// Return `true` if the type is a qubit or qubit register.
     pub fn is_quantum(&self) -> bool {
         matches!(self, Type::Qubit | Type::QubitArray(..))
     }
 
+    /// Return the type of an element of a one-dimensional bit or qubit register.
+    /// Otherwise return `None`.
+    pub fn register_element_type(&self) -> Option<Type> {
+        match self {
+            Type::BitArray(ArrayDims::D1(_), isconst) => Some(Type::Bit(isconst.clone())),
+            Type::QubitArray(ArrayDims::D1(_)) => Some(Type::Qubit),
+            _ => None,
+        }
+    }
+
+    /// Return the type of a slice of length `len` of a one-dimensional bit or qubit register.
+    /// Otherwise return `None`.
+    pub fn register_slice_type(&self, len: usize) -> Option<Type> {
+        match self {
+            Type::BitArray(ArrayDims::D1(_), isconst) => {
+                Some(Type::BitArray(ArrayDims::D1(len), isconst.clone()))
+            }
+            Type::QubitArray(ArrayDims::D1(_)) => Some(Type::QubitArray(ArrayDims::D1(len))),
+            _ => None,
+        }
+    }
+
+    /// Return the number of elements of a one-dimensional bit or qubit register,
+    /// counting a single `bit` or `qubit` as a register of length one.
+    pub fn register_length(&self) -> Option<usize> {
+        match self {
+            Type::Bit(_) | Type::Qubit => Some(1),
+            Type::BitArray(ArrayDims::D1(n), _) | Type::QubitArray(ArrayDims::D1(n)) => Some(*n),
+            _ => None,
+        }
+    }
+
     pub fn dims(&self) -> Option<Vec<usize>> {
+        self.array_dims().map(|dims| dims.dims())
+    }
+
+    fn array_dims(&self) -> Option<&ArrayDims> {
         use Type::*;
         match self {
-            QubitArray(dims) | IntArray(dims) => Some(dims.dims()),
+            BitArray(dims, _) | QubitArray(dims) | BoolArray(dims) | DurationArray(dims) => {
+                Some(dims)
+            }
+            IntArray(dims, _)
+            | UIntArray(dims, _)
+            | FloatArray(dims, _)
+            | AngleArray(dims, _)
+            | ComplexArray(dims, _) => Some(dims),
             _ => None,
         }
     }
+
+    /// Return `true` if the type is an array of classical values. A bit register
+    /// is also a one-dimensional array.
+    pub fn is_classical_array(&self) -> bool {
+        !self.is_quantum() && self.array_dims().is_some()
+    }
+
+    /// Return the type of the elements of an array. Otherwise return `None`.
+    pub fn array_element_type(&self) -> Option<Type> {
+        use Type::*;
+        let typ = match self {
+            BitArray(_, isconst) => Bit(isconst.clone()),
+            QubitArray(_) => Qubit,
+            IntArray(_, w) => Int(*w, IsConst::False),
+            UIntArray(_, w) => UInt(*w, IsConst::False),
+            FloatArray(_, w) => Float(*w, IsConst::False),
+            AngleArray(_, w) => Angle(*w, IsConst::False),
+            ComplexArray(_, w) => Complex(*w, IsConst::False),
+            BoolArray(_) => Bool(IsConst::False),
+            DurationArray(_) => Duration(IsConst::False),
+            _ => return None,
+        };
+        Some(typ)
+    }
+
+    /// Return the type of an array with the same elements as this array type,
+    /// but with dimensions `dims`. If `dims` is empty, this is the element type.
+    /// Return `None` if the type is not an array, or `dims` has too many dimensions.
+    pub fn with_array_dims(&self, dims: &[usize]) -> Option<Type> {
+        use Type::*;
+        if dims.is_empty() {
+            return self.array_element_type();
+        }
+        let dims = ArrayDims::new(dims)?;
+        let typ = match self {
+            BitArray(_, isconst) => BitArray(dims, isconst.clone()),
+            QubitArray(_) => QubitArray(dims),
+            IntArray(_, w) => IntArray(dims, *w),
+            UIntArray(_, w) => UIntArray(dims, *w),
+            FloatArray(_, w) => FloatArray(dims, *w),
+            AngleArray(_, w) => AngleArray(dims, *w),
+            ComplexArray(_, w) => ComplexArray(dims, *w),
+            BoolArray(_) => BoolArray(dims),
+            DurationArray(_) => DurationArray(dims),
+            _ => return None,
+        };
+        Some(typ)
+    }
 }
 
 #[test]
@@ -207,7 +370,32 @@ fn promote_width(ty1: &Type, ty2: &Type) -> Width {
     }
 }
 
-// promotion suitable for some binary operations, eg +, -, *, /
+// Return `typ` with `const` attribute `isconst`. Types without the attribute are unchanged.
+pub(crate) fn with_constness(typ: &Type, isconst: IsConst) -> Type {
+    use Type::*;
+    match typ {
+        Bit(_) => Bit(isconst),
+        Bool(_) => Bool(isconst),
+        Duration(_) => Duration(isconst),
+        Stretch(_) => Stretch(isconst),
+        Int(w, _) => Int(*w, isconst),
+        UInt(w, _) => UInt(*w, isconst),
+        Float(w, _) => Float(*w, isconst),
+        Angle(w, _) => Angle(*w, isconst),
+        Complex(w, _) => Complex(*w, isconst),
+        BitArray(dims, _) => BitArray(dims.clone(), isconst),
+        _ => typ.clone(),
+    }
+}
+
+/// Return the type to which both operands of an arithmetic or comparison operator,
+/// such as `+`, `*`, or `<`, are implicitly promoted. Return `Void` if there is no such type.
+///
+/// Operands of the same kind are promoted to the larger width. A `bool` is promoted to
+/// the type of a numeric operand. Two `bool`s have the common type `bool`, which may be
+/// compared but not used in arithmetic. An `int` and a `uint` are promoted to an `int` with
+/// the width of the `int`. Integers are promoted to `float` or `complex`, and a `float` is
+/// promoted to `complex`. Bits, `angle`s, and timing types are not promoted to other types.
 pub fn promote_types(ty1: &Type, ty2: &Type) -> Type {
     use Type::*;
     if ty1 == ty2 {
@@ -217,37 +405,197 @@ pub fn promote_types(ty1: &Type, ty2: &Type) -> Type {
     match (ty1, ty2) {
         (Int(..), Int(..)) => Int(promote_width(ty1, ty2), isconst),
         (UInt(..), UInt(..)) => UInt(promote_width(ty1, ty2), isconst),
-        (Int(..), Float(..)) => ty2.clone(),
-        (Float(..), Int(..)) => ty1.clone(),
+        (Float(..), Float(..)) => Float(promote_width(ty1, ty2), isconst),
+        (Angle(..), Angle(..)) => Angle(promote_width(ty1, ty2), isconst),
+        (Complex(..), Complex(..)) => Complex(promote_width(ty1, ty2), isconst),
+        (Bool(_), Bool(_)) => Bool(isconst),
+        (Bit(_), Bit(_)) => Bit(isconst),
+        (BitArray(dims1, _), BitArray(dims2, _)) if dims1 == dims2 => {
+            BitArray(dims1.clone(), isconst)
+        }
+        (Int(..), UInt(..)) => with_constness(ty1, isconst),
+        (UInt(..), Int(..)) => with_constness(ty2, isconst),
+        (Bool(_), Int(..) | UInt(..) | Float(..) | Complex(..)) => with_constness(ty2, isconst),
+        (Int(..) | UInt(..) | Float(..) | Complex(..), Bool(_)) => with_constness(ty1, isconst),
+        (Int(..) | UInt(..), Float(..) | Complex(..)) => with_constness(ty2, isconst),
+        (Float(..) | Complex(..), Int(..) | UInt(..)) => with_constness(ty1, isconst),
+        (Float(..), Complex(..)) | (Complex(..), Float(..)) => {
+            Complex(promote_width(ty1, ty2), isconst)
+        }
         _ => Void,
     }
 }
 
-// FIXME: always returns false
+// The following three functions return the type of a binary arithmetic
+// expression with at least one operand of timing type. `None` means that the
+// operation is not defined for the operand types.
+
+// Type of `ty1 + ty2` or `ty1 - ty2`. The sum involving a `stretch` is a `stretch`.
+pub fn promote_timing_sum(ty1: &Type, ty2: &Type) -> Option<Type> {
+    use Type::*;
+    let isconst = promote_constness(ty1, ty2);
+    match (ty1, ty2) {
+        (Duration(_), Duration(_)) => Some(Duration(isconst)),
+        (Duration(_) | Stretch(_), Stretch(_)) | (Stretch(_), Duration(_)) => {
+            Some(Stretch(isconst))
+        }
+        _ => None,
+    }
+}
+
+// Type of `ty1 * ty2`. A timing value may be scaled by a real number.
+pub fn promote_timing_product(ty1: &Type, ty2: &Type) -> Option<Type> {
+    let isconst = promote_constness(ty1, ty2);
+    let timing = if ty1.is_timing() && ty2.is_real_numeric() {
+        ty1
+    } else if ty2.is_timing() && ty1.is_real_numeric() {
+        ty2
+    } else {
+        return None;
+    };
+    match timing {
+        Type::Duration(_) => Some(Type::Duration(isconst)),
+        _ => Some(Type::Stretch(isconst)),
+    }
+}
+
+// Type of `ty1 / ty2`. The ratio of two durations is a `float`.
+pub fn promote_timing_quotient(ty1: &Type, ty2: &Type) -> Option<Type> {
+    use Type::*;
+    let isconst = promote_constness(ty1, ty2);
+    match (ty1, ty2) {
+        (Duration(_), Duration(_)) => Some(Float(None, isconst)),
+        (Duration(_), _) if ty2.is_real_numeric() => Some(Duration(isconst)),
+        (Stretch(_), _) if ty2.is_real_numeric() => Some(Stretch(isconst)),
+        _ => None,
+    }
+}
+
+// Type of `ty1 << ty2` or `ty1 >> ty2`. The result has the type of the value shifted,
+// which may be an integer, an `angle`, or a bit register. The shift amount is an integer.
+pub fn promote_shift(ty1: &Type, ty2: &Type) -> Option<Type> {
+    use Type::*;
+    if !matches!(ty2, Int(..) | UInt(..) | ToDo | Undefined) {
+        return None;
+    }
+    match ty1 {
+        Int(..) | UInt(..) | Angle(..) | Bit(..) | BitArray(ArrayDims::D1(_), _) => {
+            Some(ty1.clone())
+        }
+        ToDo | Undefined => Some(ty1.clone()),
+        _ => None,
+    }
+}
+
+// Type of `ty1 & ty2`, `ty1 | ty2`, or `ty1 ^ ty2`. Bitwise operations are defined on
+// integers, `angle`s, and on bits and bit registers of the same length.
+pub fn promote_bitwise(ty1: &Type, ty2: &Type) -> Option<Type> {
+    use Type::*;
+    let isconst = promote_constness(ty1, ty2);
+    match (ty1, ty2) {
+        (ToDo | Undefined, _) => Some(ty1.clone()),
+        (_, ToDo | Undefined) => Some(ty2.clone()),
+        (Bit(_), Bit(_)) => Some(Bit(isconst)),
+        (BitArray(ArrayDims::D1(n), _), BitArray(ArrayDims::D1(m), _)) if n == m => {
+            Some(BitArray(ArrayDims::D1(*n), isconst))
+        }
+        (Angle(..), Angle(..)) => Some(Angle(promote_width(ty1, ty2), isconst)),
+        _ if ty1.is_integer() && ty2.is_integer() => Some(promote_types(ty1, ty2)),
+        _ => None,
+    }
+}
+
+// Type of `ty1 ++ ty2`, where each operand is a bit or qubit register, or a single
+// bit or qubit. Both operands must be classical or both quantum.
+pub fn promote_concatenation(ty1: &Type, ty2: &Type) -> Option<Type> {
+    use Type::*;
+    match (ty1, ty2) {
+        (ToDo | Undefined, _) => return Some(ty1.clone()),
+        (_, ToDo | Undefined) => return Some(ty2.clone()),
+        _ => (),
+    }
+    let len = ty1.register_length()? + ty2.register_length()?;
+    match (ty1.is_quantum(), ty2.is_quantum()) {
+        (true, true) => Some(QubitArray(ArrayDims::D1(len))),
+        (false, false) => Some(BitArray(ArrayDims::D1(len), promote_constness(ty1, ty2))),
+        _ => None,
+    }
+}
+
 // Return `true` if `ty1 == ty2` except that the `is_const`
 // property is allowed to differ.
-fn equal_up_to_constness(_ty1: &Type, _ty2: &Type) -> bool {
-    false
-    //     match (ty1, ty2) {
-    // //        (Bit(w1, _), Bit(w2, _)) => w1 == w2,
-    //         _ => false,
-    //     }
-}
-
-// Return `true` of  `from_type` can be cast to `to_type`.
-// Warning: It is assumed that `equal_up_to_constness(from_type, to_type)`
-// returns `false`. If not, `can_cast_strict` may give incorrect results.
-// Unused at the moment
-// fn can_cast_strict(from_type: &Type, to_type: &Type) -> bool {
-//     use Type::*;
-//     !matches!((from_type, to_type), (Bit(..), Bit(..)))
-// }
-
-// FIXME: This in unfinished and permissive.
-/// Return `true` if `from_type` is equal to `to_type` up to constness,
-/// or if `from_type` can be cast to `to_type`. What "can be cast" means
-/// is perhaps a bit vague at the moment.
-pub(crate) fn can_cast_loose(from_type: &Type, to_type: &Type) -> bool {
+pub(crate) fn equal_up_to_constness(ty1: &Type, ty2: &Type) -> bool {
+    use Type::*;
+    match (ty1, ty2) {
+        (Bit(_), Bit(_))
+        | (Bool(_), Bool(_))
+        | (Duration(_), Duration(_))
+        | (Stretch(_), Stretch(_)) => true,
+        (Int(w1, _), Int(w2, _))
+        | (UInt(w1, _), UInt(w2, _))
+        | (Float(w1, _), Float(w2, _))
+        | (Angle(w1, _), Angle(w2, _))
+        | (Complex(w1, _), Complex(w2, _)) => w1 == w2,
+        (BitArray(dims1, _), BitArray(dims2, _)) => dims1 == dims2,
+        _ => ty1 == ty2,
+    }
+}
+
+// The width of a bit or bit register, or `None` for other types.
+fn bit_width(typ: &Type) -> Option<usize> {
+    match typ {
+        Type::Bit(_) => Some(1),
+        Type::BitArray(ArrayDims::D1(n), _) => Some(*n),
+        _ => None,
+    }
+}
+
+/// Return `true` if a value of type `from_type` may be cast explicitly to `to_type`,
+/// as in `int[32](x)`. This is the table of allowed casts in the OpenQASM 3 specification.
+/// A cast between a bit register and an integer or `angle` requires equal widths, if the
+/// width of the integer or `angle` is specified and the value is not constant.
+pub fn can_cast_explicit(from_type: &Type, to_type: &Type) -> bool {
+    use Type::*;
+    if matches!(from_type, ToDo | Undefined) || matches!(to_type, ToDo | Undefined) {
+        return true;
+    }
+    if equal_up_to_constness(from_type, to_type) {
+        return true;
+    }
+    let widths_match =
+        |width: &Width, bits: usize| width.map_or(true, |width| width as usize == bits);
+    match (from_type, to_type) {
+        (Bool(_), Int(..) | UInt(..) | Float(..) | Bit(_) | BitArray(ArrayDims::D1(_), _)) => true,
+        (Int(..) | UInt(..), Bool(_) | Int(..) | UInt(..) | Float(..) | Complex(..)) => true,
+        (Float(..), Bool(_) | Int(..) | UInt(..) | Float(..) | Angle(..) | Complex(..)) => true,
+        (Angle(..), Bool(_) | Angle(..)) => true,
+        (Complex(..), Complex(..)) => true,
+        (Bit(_) | BitArray(ArrayDims::D1(_), _), Bool(_)) => true,
+        // A constant integer, such as a literal, may be assigned to a bit register of any width.
+        (Int(..) | UInt(..), _) if from_type.is_const() && bit_width(to_type).is_some() => true,
+        (Int(width, _) | UInt(width, _) | Angle(width, _), _) if bit_width(to_type).is_some() => {
+            widths_match(width, bit_width(to_type).unwrap())
+        }
+        (_, Int(width, _) | UInt(width, _) | Angle(width, _)) if bit_width(from_type).is_some() => {
+            widths_match(width, bit_width(from_type).unwrap())
+        }
+        (_, _) if bit_width(from_type).is_some() && bit_width(to_type).is_some() => {
+            bit_width(from_type) == bit_width(to_type)
+        }
+        // A `stretch` is a `duration` whose value is resolved by the compiler.
+        (Duration(_), Stretch(_)) | (Stretch(_), Duration(_)) => true,
+        _ => false,
+    }
+}
+
+/// Return `true` if a value of type `from_type` may be converted implicitly to `to_type`,
+/// as when it is assigned to a variable or passed as an argument. These are the explicit
+/// casts, except those that may lose information, which must be written explicitly.
+pub fn can_cast_implicit(from_type: &Type, to_type: &Type) -> bool {
     use Type::*;
-    equal_up_to_constness(from_type, to_type) || !matches!((from_type, to_type), (Bit(..), Bit(..)))
+    let is_explicit_only = matches!(
+        (from_type, to_type),
+        (Float(..), Bool(_) | Int(..) | UInt(..)) | (Angle(..), Bool(_) | Bit(_) | BitArray(..))
+    );
+    !is_explicit_only && can_cast_explicit(from_type, to_type)
 }
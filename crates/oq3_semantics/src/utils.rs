@@ -1,7 +1,8 @@
 // Copyright contributors to the openqasm-parser project
 // SPDX-License-Identifier: Apache-2.0
 
-/// Return the name of the type of a value.
+/// Return the name of the type of a value. Useful when debugging.
+#[allow(dead_code)]
 pub fn type_name_of<T>(_: T) -> &'static str {
     std::any::type_name::<T>()
 }
@@ -0,0 +1,280 @@
+// Copyright contributors to the openqasm-parser project
+// SPDX-License-Identifier: Apache-2.0
+
+use oq3_semantics::asg;
+use oq3_semantics::display::{program_to_source, texpr_to_source, type_to_source};
+use oq3_semantics::semantic_error::SemanticErrorList;
+use oq3_semantics::symbols::{SymbolTable, SymbolType};
+use oq3_semantics::syntax_to_semantics::parse_source_string;
+use oq3_semantics::types::{ArrayDims, IsConst, Type};
+
+fn parse_string(code: &str) -> (asg::Program, SemanticErrorList, SymbolTable) {
+    parse_source_string(code, None).take_context().as_tuple()
+}
+
+// Print `code` and analyze the printed source. Check that this gives the same ASG and
+// symbols as analyzing `code`, and that printing again gives the same text. Return the
+// printed source.
+fn round_trip(code: &str) -> String {
+    let (program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty(), "{code}\n{errors:?}");
+    let printed = program_to_source(&program, &symbol_table);
+    let result = parse_source_string(printed.clone(), None);
+    assert!(!result.any_errors(), "errors in printed source:\n{printed}");
+    let (program_out, _errors, symbol_table_out) = result.take_context().as_tuple();
+    assert_eq!(program_out.version(), program.version(), "{printed}");
+    assert_eq!(
+        program_out.calibration_grammar(),
+        program.calibration_grammar(),
+        "{printed}"
+    );
+    assert_eq!(program_out.stmts(), program.stmts(), "{printed}");
+    let symbols = |symbol_table: &SymbolTable| {
+        symbol_table
+            .all_symbols()
+            .iter()
+            .map(|symbol| (symbol.name().to_string(), symbol.symbol_type().clone()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        symbols(&symbol_table_out),
+        symbols(&symbol_table),
+        "{printed}"
+    );
+    assert_eq!(printed, program_to_source(&program_out, &symbol_table_out));
+    printed
+}
+
+// The source of the programs in the test files of this crate.
+fn test_programs() -> Vec<&'static str> {
+    let sources = [
+        include_str!("from_string_tests.rs"),
+        include_str!("serde_tests.rs"),
+        include_str!("visit_tests.rs"),
+    ];
+    sources
+        .iter()
+        .flat_map(|source| source.split("r##\"").skip(1))
+        .map(|rest| rest.split("\"##").next().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_display_round_trip_test_programs() {
+    let mut num_printed = 0;
+    for code in test_programs() {
+        // Programs with errors are not printed.
+        if parse_source_string(code, None).any_errors() {
+            continue;
+        }
+        round_trip(code);
+        num_printed += 1;
+    }
+    assert!(num_printed > 30);
+}
+
+#[test]
+fn test_display_declarations() {
+    let code = r##"
+OPENQASM 3.0;
+const int[32] n = 2;
+qubit[n] q;
+qreg r[2];
+bit[2] c;
+creg d[2];
+float[64] x = 1.0;
+complex[float[64]] z = 1.0 + 2.0im;
+array[int[8], 2, 3] a = {{1, 2, 3}, {4, 5, 6}};
+input angle[16] theta;
+output bool b;
+let s = q[0:1] ++ r;
+"##;
+    let expected = r##"OPENQASM 3.0;
+const int[32] n = 2;
+qubit[2] q;
+qubit[2] r;
+bit[2] c;
+bit[2] d;
+float[64] x = 1.0;
+complex[float[64]] z = 1.0 + 2im;
+array[int[8], 2, 3] a = {{1, 2, 3}, {4, 5, 6}};
+input angle[16] theta;
+output bool b;
+let s = q[0:1] ++ r;
+"##;
+    assert_eq!(round_trip(code), expected);
+}
+
+#[test]
+fn test_display_statements() {
+    let code = r##"
+gate h a { U(π / 2, 0, π) a; }
+def f(int y, qubit p, readonly array[float, 2] v) -> int { return y + 1; }
+extern g(int, float[32]) -> bit;
+qubit[2] q;
+int x;
+array[float, 2] w = {1.0, 2.0};
+for int i in [0:2:4] {
+    if (i == 0) { continue; } else { x += i; }
+}
+while (x < 10) { x = x * 2; }
+switch (x) {
+    case 1, 2 { h q[0]; }
+    default { reset q; }
+}
+ctrl @ inv @ h q[0], q[1];
+barrier q;
+@bind q
+x = f(x, q[0], w);
+"##;
+    let expected = r##"gate h a {
+    U(π / 2, 0, π) a;
+}
+def f(int y, qubit p, readonly array[float, 2] v) -> int {
+    return y + 1;
+}
+extern g(int, float[32]) -> bit;
+qubit[2] q;
+int x;
+array[float, 2] w = {1.0, 2.0};
+for int i in [0:2:4] {
+    if (i == 0) {
+        continue;
+    } else {
+        x += i;
+    }
+}
+while (x < 10) {
+    x = x * 2;
+}
+switch (x) {
+    case 1, 2 {
+        h q[0];
+    }
+    default {
+        reset q;
+    }
+}
+ctrl @ inv @ h q[0], q[1];
+barrier q;
+@bind q
+x = f(x, q[0], w);
+"##;
+    assert_eq!(round_trip(code), expected);
+}
+
+// Explicit casts are printed. Casts inserted by type promotion are not.
+// Parentheses are printed only where they are needed.
+#[test]
+fn test_display_expressions() {
+    let code = r##"
+int[32] x;
+float[64] y;
+bool b = x && true;
+y = x + y * 2;
+x = (x + 1) * (x - 1);
+x = -(x ** 2);
+x = (-x) ** 2;
+x = 2 ** 3 ** 2;
+x = (2 ** 3) ** 2;
+x = x - (x - 1);
+y = float[32](x) / 2.0;
+b = !(x < 1) || b;
+"##;
+    let expected = r##"int[32] x;
+float[64] y;
+bool b = x && true;
+y = x + y * 2;
+x = (x + 1) * (x - 1);
+x = -x ** 2;
+x = (-x) ** 2;
+x = 2 ** 3 ** 2;
+x = (2 ** 3) ** 2;
+x = x - (x - 1);
+y = float[32](x) / 2.0;
+b = !(x < 1) || b;
+"##;
+    assert_eq!(round_trip(code), expected);
+}
+
+#[test]
+fn test_display_texpr() {
+    let (program, _errors, symbol_table) = parse_string("int x; x + 1;");
+    let asg::Stmt::ExprStmt(texpr) = &program.stmts()[2] else {
+        unreachable!()
+    };
+    assert_eq!(texpr_to_source(texpr, &symbol_table), "x + 1");
+}
+
+#[test]
+fn test_display_type() {
+    assert_eq!(
+        type_to_source(&Type::Int(Some(32), IsConst::True)),
+        "int[32]"
+    );
+    assert_eq!(
+        type_to_source(&Type::BitArray(ArrayDims::D1(4), IsConst::False)),
+        "bit[4]"
+    );
+    assert_eq!(
+        type_to_source(&Type::BitArray(ArrayDims::D2(2, 3), IsConst::False)),
+        "array[bit, 2, 3]"
+    );
+    assert_eq!(
+        type_to_source(&Type::ComplexArray(ArrayDims::D1(2), Some(32))),
+        "array[complex[float[32]], 2]"
+    );
+}
+
+// A program with an OpenQASM 2 header is printed with the syntax of OpenQASM 2.
+#[test]
+fn test_display_openqasm2() {
+    let code = r##"
+OPENQASM 2.0;
+qreg q[2];
+creg c[2];
+opaque g(a) r;
+gate h a { U(π / 2, 0, π) a; }
+g(0.5) q[0];
+h q[1];
+measure q -> c;
+if (c == 1) h q[0];
+"##;
+    let expected = r##"OPENQASM 2.0;
+qreg q[2];
+creg c[2];
+opaque g(a) r;
+gate h a {
+    U(π / 2, 0, π) a;
+}
+g(0.5) q[0];
+h q[1];
+measure q -> c;
+if (c == 1) h q[0];
+"##;
+    assert_eq!(round_trip(code), expected);
+}
+
+#[test]
+fn test_display_dim_params() {
+    let code = r##"
+def f(readonly array[int, #dim = 2] a) -> int { return sizeof(a, 1); }
+def g(mutable array[float[64], #dim = 1] b) { b[0] = 1.0; }
+array[int, 2, 3] x;
+array[float[64], 4] y;
+f(x);
+g(y);
+"##;
+    let expected = r##"def f(readonly array[int, #dim = 2] a) -> int {
+    return sizeof(a, 1);
+}
+def g(mutable array[float[64], #dim = 1] b) {
+    b[0] = 1.0;
+}
+array[int, 2, 3] x;
+array[float[64], 4] y;
+f(x);
+g(y);
+"##;
+    assert_eq!(round_trip(code), expected);
+}
@@ -2,10 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use oq3_semantics::asg;
-use oq3_semantics::semantic_error::SemanticErrorList;
+use oq3_semantics::semantic_error::{SemanticErrorKind, SemanticErrorList};
+use oq3_semantics::span::{FileId, Span};
 use oq3_semantics::symbols::{SymbolTable, SymbolType};
 use oq3_semantics::syntax_to_semantics::parse_source_string;
-use oq3_semantics::types::{IsConst, Type};
+use oq3_semantics::types::{ArrayDims, IsConst, Type};
 
 fn parse_string(code: &str) -> (asg::Program, SemanticErrorList, SymbolTable) {
     parse_source_string(code, None).take_context().as_tuple()
@@ -209,3 +210,1606 @@ U(1.0, 2.0, 3.0) q;
 //     assert_eq!(varname_id, symbol_table.lookup("q").unwrap().symbol_id());
 //     assert_eq!(&Type::Qubit(Some(3)), (symbol_table[varname_id]).symbol_type());
 // }
+
+#[test]
+fn test_from_string_for_range() {
+    let code = r##"
+for int i in [0:2:10] {
+   i;
+}
+"##;
+    let (program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 2);
+    let for_stmt = match &program.stmts()[1] {
+        asg::Stmt::For(for_stmt) => for_stmt,
+        _ => unreachable!(),
+    };
+    let loop_var = for_stmt.loop_var().clone().unwrap();
+    assert_eq!(
+        &Type::Int(None, IsConst::False),
+        symbol_table[&loop_var].symbol_type()
+    );
+    let range = match for_stmt.iterable() {
+        asg::ForIterable::RangeExpression(range) => range,
+        _ => unreachable!(),
+    };
+    assert!(range.step().is_some());
+    assert_eq!(for_stmt.loop_body().statements().len(), 1);
+    // The loop variable is not visible outside the loop.
+    assert!(symbol_table.lookup("i").is_err());
+}
+
+#[test]
+fn test_from_string_for_set() {
+    let code = r##"
+for uint i in {1, 3, 5} {
+   i;
+}
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 2);
+    let for_stmt = match &program.stmts()[1] {
+        asg::Stmt::For(for_stmt) => for_stmt,
+        _ => unreachable!(),
+    };
+    let set_expression = match for_stmt.iterable() {
+        asg::ForIterable::SetExpression(set_expression) => set_expression,
+        _ => unreachable!(),
+    };
+    assert_eq!(set_expression.expressions().len(), 3);
+}
+
+#[test]
+fn test_from_string_for_undefined_iterable() {
+    let code = r##"
+for int i in myarr {
+   i;
+}
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(program.len(), 2);
+}
+
+#[test]
+fn test_from_string_for_iterable_types() {
+    let code = r##"
+int x;
+bit[4] c;
+array[int[8], 3] a;
+array[float[64], 2, 3] b;
+qubit[2] q;
+for int i in x {}
+for bit e in c {}
+for int i in a {}
+for angle i in a {}
+for float[64] f in b {}
+for int i in q {}
+for int i in [0:0.5:2] {}
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    let error_texts: Vec<_> = errors.iter().map(|err| err.text()).collect();
+    assert_eq!(error_texts, ["x", "a", "b", "q", "[0:0.5:2]"]);
+}
+
+#[test]
+fn test_from_string_for_loop_var_scope() {
+    let code = r##"
+for int i in [0:3] {
+}
+i;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(program.len(), 3);
+}
+
+#[test]
+fn test_from_string_def() {
+    let code = r##"
+def f(int[32] a, qubit q) -> int[32] {
+   return a;
+}
+"##;
+    let (program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 2);
+    let def = match &program.stmts()[1] {
+        asg::Stmt::Def(def) => def,
+        _ => unreachable!(),
+    };
+    assert_eq!(def.params().len(), 2);
+    assert_eq!(
+        def.return_type(),
+        Some(&Type::Int(Some(32), IsConst::False))
+    );
+    assert!(matches!(def.block().statements()[0], asg::Stmt::Return(_)));
+    let name_id = def.name().clone().unwrap();
+    assert_eq!(
+        &Type::Subroutine(
+            vec![Type::Int(Some(32), IsConst::False), Type::Qubit],
+            Box::new(Type::Int(Some(32), IsConst::False))
+        ),
+        symbol_table[&name_id].symbol_type()
+    );
+}
+
+#[test]
+fn test_from_string_def_call() {
+    let code = r##"
+def f(int[32] a, qubit q) -> bit {
+   bit b;
+   return b;
+}
+qubit r;
+f(1, r);
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 4);
+    let call = match &program.stmts()[3] {
+        asg::Stmt::ExprStmt(texpr) => texpr,
+        _ => unreachable!(),
+    };
+    assert_eq!(call.get_type(), &Type::Bit(IsConst::False));
+    assert!(matches!(call.expression(), asg::Expr::Call(_)));
+}
+
+#[test]
+fn test_from_string_def_call_num_args() {
+    let code = r##"
+def f(int[32] a, int[32] b) {
+}
+f(1);
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_from_string_def_call_arg_type() {
+    let code = r##"
+def f(int[32] a) {
+}
+qubit q;
+f(q);
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_from_string_def_return_type() {
+    let code = r##"
+def f(int[32] a) {
+   return a;
+}
+def g(int[32] a) -> int[32] {
+   return;
+}
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 2);
+}
+
+// A `def` must be global. Analyzing a nested `def` does not lose the return type of
+// the enclosing subroutine.
+#[test]
+fn test_from_string_nested_def() {
+    let code = r##"
+def f(int a) -> int {
+   def g() {}
+   return;
+}
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(
+        errors[0].kind(),
+        SemanticErrorKind::DefNotInGlobalScopeError
+    ));
+    assert!(matches!(
+        errors[1].kind(),
+        SemanticErrorKind::ReturnTypeError
+    ));
+}
+
+#[test]
+fn test_from_string_call_not_callable() {
+    let code = r##"
+int x;
+x(1);
+def f(int a) {}
+(f)(1);
+int y = 1 + (f)(2);
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 3);
+    assert!(errors
+        .iter()
+        .all(|err| matches!(err.kind(), SemanticErrorKind::NotCallableError)));
+    assert_eq!(errors[1].text(), "(f)(1)");
+    match &program.stmts()[4] {
+        asg::Stmt::ExprStmt(texpr) => assert_eq!(texpr.get_type(), &Type::Undefined),
+        stmt => panic!("expected expression, found {stmt:?}"),
+    }
+}
+
+#[test]
+fn test_from_string_gate_modifiers() {
+    let code = r##"
+gate h q {}
+qubit a;
+qubit b;
+qubit c;
+inv @ pow(2) @ ctrl @ negctrl(1) @ h a, b, c;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 6);
+    let gate_call = match &program.stmts()[5] {
+        asg::Stmt::GateCall(gate_call) => gate_call,
+        _ => unreachable!(),
+    };
+    let modifiers = gate_call.modifiers();
+    assert_eq!(modifiers.len(), 4);
+    assert!(matches!(modifiers[0], asg::GateModifier::Inv));
+    assert!(matches!(modifiers[1], asg::GateModifier::Pow(_)));
+    assert!(matches!(modifiers[2], asg::GateModifier::Ctrl(None)));
+    assert!(matches!(modifiers[3], asg::GateModifier::NegCtrl(Some(_))));
+}
+
+#[test]
+fn test_from_string_pow_modifier_errors() {
+    let code = r##"
+qubit q;
+pow(sizeof()) @ U(0, 0, 0) q;
+pow(true) @ U(0, 0, 0) q;
+pow(1.5) @ U(0, 0, 0) q;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    let kinds = errors.iter().map(|err| err.kind()).collect::<Vec<_>>();
+    assert!(matches!(
+        kinds[..],
+        [
+            SemanticErrorKind::NumberOfArgumentsError,
+            SemanticErrorKind::ArgumentTypeError
+        ]
+    ));
+    let num_modifiers = |n: usize| match &program.stmts()[n] {
+        asg::Stmt::GateCall(gate_call) => gate_call.modifiers().len(),
+        stmt => panic!("expected gate call, found {stmt:?}"),
+    };
+    // The exponent of `sizeof()` is an error, but is kept with type `Undefined`.
+    assert_eq!(num_modifiers(2), 1);
+    assert_eq!(num_modifiers(3), 0);
+    assert_eq!(num_modifiers(4), 1);
+}
+
+#[test]
+fn test_from_string_gate_modifiers_num_qubits() {
+    let code = r##"
+gate h q {}
+qubit a;
+qubit b;
+ctrl(2) @ h a, b;
+ctrl @ h a, b;
+h a, b;
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn test_from_string_measure() {
+    let code = r##"
+qubit[2] q;
+bit[2] c;
+measure q;
+c = measure q;
+c[0] = measure q[0];
+bit[2] d = measure q;
+measure q -> c;
+measure q[1] -> c[1];
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 9);
+    let measure = match &program.stmts()[3] {
+        asg::Stmt::ExprStmt(texpr) => texpr,
+        _ => unreachable!(),
+    };
+    assert!(matches!(measure.expression(), asg::Expr::Measure(_)));
+    assert_eq!(
+        measure.get_type(),
+        &Type::BitArray(ArrayDims::D1(2), IsConst::False)
+    );
+    let assignment = match &program.stmts()[5] {
+        asg::Stmt::Assignment(assignment) => assignment,
+        _ => unreachable!(),
+    };
+    assert!(matches!(assignment.lvalue(), asg::LValue::RegisterSlice(_)));
+    assert_eq!(assignment.rvalue().get_type(), &Type::Bit(IsConst::False));
+    assert!(matches!(&program.stmts()[7], asg::Stmt::Assignment(_)));
+}
+
+#[test]
+fn test_from_string_indexed_and_compound_assignment() {
+    let code = r##"
+bit[4] c;
+int[32] x;
+c[2] = 1;
+c[0:3] = "0101";
+x += 2;
+x <<= 1;
+c |= "1100";
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let assignment = |n: usize| match &program.stmts()[n] {
+        asg::Stmt::Assignment(assignment) => assignment,
+        _ => unreachable!(),
+    };
+    assert!(matches!(
+        assignment(3).lvalue(),
+        asg::LValue::RegisterSlice(slice)
+            if matches!(slice.index(), asg::RegisterIndex::Expr(_))
+    ));
+    assert!(matches!(
+        assignment(4).lvalue(),
+        asg::LValue::RegisterSlice(slice)
+            if matches!(slice.index(), asg::RegisterIndex::Range(_))
+    ));
+    assert_eq!(assignment(4).op(), &asg::AssignOp::Assign);
+    assert_eq!(
+        assignment(5).op(),
+        &asg::AssignOp::Compound(asg::ArithOp::Add)
+    );
+    assert_eq!(
+        assignment(6).op(),
+        &asg::AssignOp::Compound(asg::ArithOp::Shl)
+    );
+    assert_eq!(
+        assignment(7).op(),
+        &asg::AssignOp::Compound(asg::ArithOp::BitOr)
+    );
+}
+
+#[test]
+fn test_from_string_assignment_errors() {
+    let code = r##"
+const int n = 1;
+bit[4] c;
+float f;
+qubit q;
+n += 1;
+c[0:2] = "0101";
+f <<= 1;
+q += 1;
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 4);
+}
+
+#[test]
+fn test_from_string_array_declaration() {
+    let code = r##"
+array[int[32], 2, 3] a = {{1, 2, 3}, {4, 5, 6}};
+array[float[64], 2, 2, 2, 2, 2] b;
+array[bool, 3] c = {true, false, true};
+"##;
+    let (_program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let symbol_type = |name: &str| symbol_table.lookup(name).unwrap().symbol_type().clone();
+    assert_eq!(
+        symbol_type("a"),
+        Type::IntArray(ArrayDims::D2(2, 3), Some(32))
+    );
+    assert_eq!(
+        symbol_type("b"),
+        Type::FloatArray(ArrayDims::D5(2, 2, 2, 2, 2), Some(64))
+    );
+    assert_eq!(symbol_type("c"), Type::BoolArray(ArrayDims::D1(3)));
+}
+
+#[test]
+fn test_from_string_array_literal_errors() {
+    let code = r##"
+array[int[32], 2, 3] a = {{1, 2, 3}, {4, 5}};
+array[int[32], 2] b = {1, 2, 3};
+array[int[32], 2] c = {{1}, 2};
+array[bool, 1, 1, 1, 1, 1, 1, 1, 1] d;
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 4);
+}
+
+#[test]
+fn test_from_string_array_index() {
+    let code = r##"
+array[int[32], 2, 3] a;
+a[1, 2];
+a[0][1:2];
+a[0:1][1];
+a[{0, 1}];
+sizeof(a, 1);
+sizeof(a);
+a[0] = {1, 2, 3};
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let expr_type = |n: usize| match &program.stmts()[n] {
+        asg::Stmt::ExprStmt(texpr) => texpr.get_type().clone(),
+        _ => unreachable!(),
+    };
+    assert_eq!(expr_type(2), Type::Int(Some(32), IsConst::False));
+    assert_eq!(expr_type(3), Type::IntArray(ArrayDims::D1(2), Some(32)));
+    assert_eq!(expr_type(4), Type::IntArray(ArrayDims::D1(3), Some(32)));
+    assert_eq!(expr_type(5), Type::IntArray(ArrayDims::D2(2, 3), Some(32)));
+    assert_eq!(expr_type(6), Type::UInt(None, IsConst::True));
+    assert!(matches!(
+        &program.stmts()[7],
+        asg::Stmt::ExprStmt(texpr) if matches!(texpr.expression(), asg::Expr::SizeOf(sizeof) if sizeof.dim().is_none())
+    ));
+}
+
+#[test]
+fn test_from_string_array_index_errors() {
+    let code = r##"
+array[int[32], 2, 3] a;
+int[32] x;
+a[0, 1, 2];
+a[0][1][2];
+sizeof(a, 2);
+sizeof(x);
+sizeof(a, 0, 1);
+a[0] = {1, 2};
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 6);
+}
+
+#[test]
+fn test_from_string_sizeof_errors() {
+    let code = r##"
+array[int[32], 2, 3] a;
+const int n = 2;
+const int m = 1;
+sizeof();
+int x = 1 + sizeof();
+sizeof(a, n);
+sizeof(a, m);
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    let kinds = errors.iter().map(|err| err.kind()).collect::<Vec<_>>();
+    assert!(matches!(
+        kinds[..],
+        [
+            SemanticErrorKind::NumberOfArgumentsError,
+            SemanticErrorKind::NumberOfArgumentsError,
+            SemanticErrorKind::ArrayDimensionError
+        ]
+    ));
+    assert_eq!(errors[2].text(), "sizeof(a, n)");
+    match &program.stmts()[4] {
+        asg::Stmt::ExprStmt(texpr) => assert_eq!(texpr.get_type(), &Type::Undefined),
+        stmt => panic!("expected expression, found {stmt:?}"),
+    }
+}
+
+#[test]
+fn test_from_string_dim_param() {
+    let code = r##"
+def f(readonly array[int, #dim = 2] a) -> int {
+    int s = sizeof(a, 1);
+    sizeof(a, 2);
+    return a[0, 1];
+}
+array[int, 2, 3] x;
+array[int, 4] y;
+array[float, 2, 3] z;
+f(x);
+f(y);
+f(z);
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    let kinds = errors.iter().map(|err| err.kind()).collect::<Vec<_>>();
+    assert!(matches!(
+        kinds[..],
+        [
+            SemanticErrorKind::ArrayDimensionError,
+            SemanticErrorKind::ArgumentTypeError,
+            SemanticErrorKind::ArgumentTypeError
+        ]
+    ));
+    assert_eq!(errors[0].text(), "sizeof(a, 2)");
+    assert_eq!(errors[1].text(), "f(y)");
+    assert_eq!(errors[2].text(), "f(z)");
+    assert_eq!(program.len(), 8);
+}
+
+#[test]
+fn test_from_string_const_designator() {
+    let code = r##"
+const int n = 4;
+const uint m = n - 1;
+qubit[2 * n] q;
+int[n * 8] x;
+bit[m] c;
+array[float[64], n, sizeof(c) + 1] a;
+ctrl(m - 1) @ U(0, 0, 0) q[0], q[1], q[2];
+"##;
+    let (_program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let symbol_type = |name: &str| symbol_table.lookup(name).unwrap().symbol_type().clone();
+    assert_eq!(symbol_type("q"), Type::QubitArray(ArrayDims::D1(8)));
+    assert_eq!(symbol_type("x"), Type::Int(Some(32), IsConst::False));
+    assert_eq!(
+        symbol_type("c"),
+        Type::BitArray(ArrayDims::D1(3), IsConst::False)
+    );
+    assert_eq!(
+        symbol_type("a"),
+        Type::FloatArray(ArrayDims::D2(4, 4), Some(64))
+    );
+}
+
+#[test]
+fn test_from_string_non_const_designator() {
+    let code = r##"
+int n = 4;
+const float f = 1.5;
+qubit[n] q;
+int[f] x;
+array[int[32], n] a;
+ctrl(n) @ U(0, 0, 0) q[0], q[1];
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 4);
+}
+
+#[test]
+fn test_from_string_complex() {
+    let code = r##"
+complex[float[32]] z = 1.5im;
+complex[float[64]] w;
+int[32] n;
+float[32] f;
+z + n;
+z * f;
+z / w;
+1 + 2im;
+"##;
+    let (program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(
+        symbol_table.lookup("z").unwrap().symbol_type(),
+        &Type::Complex(Some(32), IsConst::False)
+    );
+    let expr_type = |n: usize| match &program.stmts()[n] {
+        asg::Stmt::ExprStmt(texpr) => texpr.get_type().clone(),
+        _ => unreachable!(),
+    };
+    assert_eq!(expr_type(5), Type::Complex(Some(32), IsConst::False));
+    assert_eq!(expr_type(6), Type::Complex(Some(32), IsConst::False));
+    assert_eq!(expr_type(7), Type::Complex(Some(64), IsConst::False));
+    assert_eq!(expr_type(8), Type::Complex(Some(64), IsConst::True));
+}
+
+#[test]
+fn test_from_string_complex_errors() {
+    let code = r##"
+complex[int[32]] z;
+complex w;
+w % 2;
+w < w;
+w << 1;
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 4);
+}
+
+#[test]
+fn test_from_string_casts() {
+    let code = r##"
+float[64] x;
+bit[32] b;
+int[32](1.5);
+float(x);
+bool(x);
+angle[32](x);
+int[32](b);
+bit[32](int[32](x));
+float[32] y = 1;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let expr_type = |n: usize| match &program.stmts()[n] {
+        asg::Stmt::ExprStmt(texpr) => {
+            assert!(matches!(texpr.expression(), asg::Expr::Cast(_)));
+            texpr.get_type().clone()
+        }
+        _ => unreachable!(),
+    };
+    assert_eq!(expr_type(3), Type::Int(Some(32), IsConst::True));
+    assert_eq!(expr_type(4), Type::Float(None, IsConst::False));
+    assert_eq!(expr_type(5), Type::Bool(IsConst::False));
+    assert_eq!(expr_type(6), Type::Angle(Some(32), IsConst::False));
+    assert_eq!(expr_type(7), Type::Int(Some(32), IsConst::False));
+    assert_eq!(
+        expr_type(8),
+        Type::BitArray(ArrayDims::D1(32), IsConst::False)
+    );
+}
+
+#[test]
+fn test_from_string_cast_errors() {
+    let code = r##"
+angle[32] a;
+duration d;
+bit[8] b;
+int(a);
+float(a);
+int(d);
+int[16](b);
+bit[4](b);
+int x = 1.5;
+bool c = a;
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 7);
+}
+
+#[test]
+fn test_from_string_builtins() {
+    let code = r##"
+float[32] f;
+angle[32] a;
+bit[4] b;
+sqrt(f);
+cos(a);
+mod(7, 3);
+real(1.5im);
+rotr(b, 1);
+U(π / 2, 0, euler) $0;
+const uint n = popcount("1011");
+const uint r = rotl(uint[4](3), 1);
+const int m = int(floor(tau));
+bit[n] c;
+bit[r] d;
+bit[m] e;
+"##;
+    let (program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let expr_type = |n: usize| match &program.stmts()[n] {
+        asg::Stmt::ExprStmt(texpr) => {
+            assert!(matches!(texpr.expression(), asg::Expr::BuiltinCall(_)));
+            texpr.get_type().clone()
+        }
+        _ => unreachable!(),
+    };
+    assert_eq!(expr_type(4), Type::Float(Some(32), IsConst::False));
+    assert_eq!(expr_type(5), Type::Float(None, IsConst::False));
+    assert_eq!(expr_type(6), Type::UInt(Some(128), IsConst::True));
+    assert_eq!(expr_type(7), Type::Float(Some(64), IsConst::True));
+    assert_eq!(
+        expr_type(8),
+        Type::BitArray(ArrayDims::D1(4), IsConst::False)
+    );
+    let symbol_type = |name: &str| symbol_table.lookup(name).unwrap().symbol_type().clone();
+    assert_eq!(symbol_type("pi"), Type::Float(None, IsConst::True));
+    assert_eq!(
+        symbol_type("c"),
+        Type::BitArray(ArrayDims::D1(3), IsConst::False)
+    );
+    assert_eq!(
+        symbol_type("d"),
+        Type::BitArray(ArrayDims::D1(6), IsConst::False)
+    );
+    assert_eq!(
+        symbol_type("e"),
+        Type::BitArray(ArrayDims::D1(6), IsConst::False)
+    );
+}
+
+#[test]
+fn test_from_string_euler() {
+    let code = r##"
+float f = ℇ;
+float g = euler;
+const int n = int(10 * ℇ);
+const int m = int(10 * euler);
+bit[n] c;
+bit[m] d;
+"##;
+    let (_program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let symbol_type = |name: &str| symbol_table.lookup(name).unwrap().symbol_type().clone();
+    assert_eq!(symbol_type("ℇ"), Type::Float(None, IsConst::True));
+    assert_eq!(
+        symbol_type("c"),
+        Type::BitArray(ArrayDims::D1(27), IsConst::False)
+    );
+    assert_eq!(
+        symbol_type("d"),
+        Type::BitArray(ArrayDims::D1(27), IsConst::False)
+    );
+}
+
+#[test]
+fn test_from_string_builtin_errors() {
+    let code = r##"
+angle[32] a;
+bit[4] b;
+float x = floor(a);
+sin(b);
+real(1.5);
+popcount(3);
+rotl(b);
+mod(1, 2, 3);
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 6);
+}
+
+#[test]
+fn test_from_string_builtin_const_bit_register() {
+    let code = r##"
+const bit[4] b = "0111";
+const uint n = popcount(b);
+const uint l = rotl(b, 1);
+const uint r = rotr(b, 1);
+const uint s = rotl(1, 2);
+const uint t = rotr(4, 2);
+qubit[n] q;
+bit[l] c;
+bit[r] d;
+bit[s] e;
+bit[t] f;
+"##;
+    let (_program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let symbol_type = |name: &str| symbol_table.lookup(name).unwrap().symbol_type().clone();
+    assert_eq!(symbol_type("q"), Type::QubitArray(ArrayDims::D1(3)));
+    let bits = |n: usize| Type::BitArray(ArrayDims::D1(n), IsConst::False);
+    assert_eq!(symbol_type("c"), bits(0b1110));
+    assert_eq!(symbol_type("d"), bits(0b1011));
+    assert_eq!(symbol_type("e"), bits(4));
+    assert_eq!(symbol_type("f"), bits(1));
+}
+
+// Calls with missing arguments are not evaluated as constants.
+#[test]
+fn test_from_string_builtin_no_args() {
+    let code = r##"
+const int n = popcount();
+qubit[sin()] q;
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert!(errors
+        .iter()
+        .any(|err| matches!(err.kind(), SemanticErrorKind::NumberOfArgumentsError)));
+}
+
+#[test]
+fn test_from_string_spans() {
+    let code = r##"int x = 1;
+if (x == 1) {
+    x + 2;
+}
+"##;
+    let (program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let text = |span: &Span| &code[span.range()];
+    // The `U` gate is predefined, and has no span.
+    assert!(program.stmt_span(0).is_none());
+    let decl_span = program.stmt_span(1).unwrap();
+    assert_eq!(text(decl_span), "int x = 1;");
+    assert_eq!(decl_span.file_id(), FileId::new(0));
+    assert!(program.file_path(decl_span.file_id()).is_some());
+    let x = symbol_table.lookup("x").unwrap();
+    assert_eq!(x.span(), Some(decl_span));
+    assert!(symbol_table.lookup("pi").unwrap().span().is_none());
+    let if_stmt = match &program.stmts()[2] {
+        asg::Stmt::If(if_stmt) => if_stmt,
+        _ => unreachable!(),
+    };
+    assert_eq!(text(if_stmt.condition().span().unwrap()), "(x == 1)");
+    let block = if_stmt.then_branch();
+    assert_eq!(text(block.stmt_span(0).unwrap()), "x + 2;");
+    let sum = match &block.statements()[0] {
+        asg::Stmt::ExprStmt(texpr) => texpr,
+        _ => unreachable!(),
+    };
+    assert_eq!(text(sum.span().unwrap()), "x + 2");
+
+    // Quantum operands and measurements also have spans.
+    let code = r##"qubit[2] q;
+bit[2] c;
+gate h a { U(pi / 2, 0, pi) a; }
+h q[0];
+reset q;
+barrier q[1], $0;
+delay[10ns] q;
+measure q;
+measure q -> c;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let text = |texpr: &asg::TExpr| &code[texpr.span().unwrap().range()];
+    let stmts = program.stmts();
+    match &stmts[4] {
+        asg::Stmt::GateCall(gate_call) => assert_eq!(text(&gate_call.qubits()[0]), "q[0]"),
+        stmt => panic!("expected gate call, found {stmt:?}"),
+    }
+    match &stmts[5] {
+        asg::Stmt::Reset(reset) => assert_eq!(text(reset.operand()), "q"),
+        stmt => panic!("expected reset, found {stmt:?}"),
+    }
+    match &stmts[6] {
+        asg::Stmt::Barrier(barrier) => {
+            let qubits = barrier.qubits().as_ref().unwrap();
+            assert_eq!(text(&qubits[0]), "q[1]");
+            assert_eq!(text(&qubits[1]), "$0");
+        }
+        stmt => panic!("expected barrier, found {stmt:?}"),
+    }
+    match &stmts[7] {
+        asg::Stmt::Delay(delay) => assert_eq!(text(&delay.qubits().as_ref().unwrap()[0]), "q"),
+        stmt => panic!("expected delay, found {stmt:?}"),
+    }
+    for (i, stmt) in stmts[8..].iter().enumerate() {
+        let measure = match stmt {
+            asg::Stmt::ExprStmt(texpr) if i == 0 => texpr,
+            asg::Stmt::Assignment(assignment) => assignment.rvalue(),
+            stmt => panic!("expected measurement, found {stmt:?}"),
+        };
+        assert_eq!(text(measure), "measure q");
+        match measure.expression() {
+            asg::Expr::Measure(measure) => assert_eq!(text(measure.operand()), "q"),
+            expr => panic!("expected measure, found {expr:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_from_string_measure_width_mismatch() {
+    let code = r##"
+qubit[2] q;
+bit[3] c;
+bit b;
+c = measure q;
+bit[3] d = measure q;
+measure q -> b;
+b = measure q[0];
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 3);
+}
+
+#[test]
+fn test_from_string_reset_barrier_delay() {
+    let code = r##"
+qubit[2] q;
+duration d;
+reset q[0];
+barrier q, $1;
+barrier;
+delay[d] q[1];
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 7);
+    let reset = match &program.stmts()[3] {
+        asg::Stmt::Reset(reset) => reset,
+        _ => unreachable!(),
+    };
+    assert_eq!(reset.operand().get_type(), &Type::Qubit);
+    let barrier = match &program.stmts()[4] {
+        asg::Stmt::Barrier(barrier) => barrier,
+        _ => unreachable!(),
+    };
+    assert_eq!(barrier.qubits().as_ref().unwrap().len(), 2);
+    assert!(matches!(
+        &program.stmts()[5],
+        asg::Stmt::Barrier(barrier) if barrier.qubits().is_none()
+    ));
+    let delay = match &program.stmts()[6] {
+        asg::Stmt::Delay(delay) => delay,
+        _ => unreachable!(),
+    };
+    assert_eq!(delay.duration().get_type(), &Type::Duration(IsConst::False));
+    assert_eq!(delay.qubits().as_ref().unwrap().len(), 1);
+}
+
+#[test]
+fn test_from_string_reset_barrier_delay_not_quantum() {
+    let code = r##"
+qubit q;
+bit c;
+int x;
+reset c;
+barrier q, x;
+delay[x] q;
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 3);
+}
+
+#[test]
+fn test_from_string_operators() {
+    let code = r##"
+int[32] x;
+bit[4] c;
+bool b;
+x < 1;
+x << 2;
+c & c;
+c >> 1;
+b || x;
+x ** 2;
+-x;
+!x;
+~c;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let expr_type = |n: usize| match &program.stmts()[n] {
+        asg::Stmt::ExprStmt(texpr) => texpr.get_type().clone(),
+        _ => unreachable!(),
+    };
+    assert_eq!(expr_type(4), Type::Bool(IsConst::False));
+    assert_eq!(expr_type(5), Type::Int(Some(32), IsConst::False));
+    assert_eq!(
+        expr_type(6),
+        Type::BitArray(ArrayDims::D1(4), IsConst::False)
+    );
+    assert_eq!(
+        expr_type(7),
+        Type::BitArray(ArrayDims::D1(4), IsConst::False)
+    );
+    assert_eq!(expr_type(8), Type::Bool(IsConst::False));
+    assert_eq!(expr_type(10), Type::Int(Some(32), IsConst::False));
+    assert_eq!(expr_type(11), Type::Bool(IsConst::False));
+    assert_eq!(
+        expr_type(12),
+        Type::BitArray(ArrayDims::D1(4), IsConst::False)
+    );
+    let or_expr = match &program.stmts()[8] {
+        asg::Stmt::ExprStmt(texpr) => texpr.expression(),
+        _ => unreachable!(),
+    };
+    assert!(matches!(
+        or_expr,
+        asg::Expr::BinaryExpr(bin_expr)
+            if bin_expr.op() == &asg::BinaryOp::LogicOp(asg::LogicOp::Or)
+            && matches!(bin_expr.right().expression(), asg::Expr::Cast(_))
+    ));
+}
+
+#[test]
+fn test_from_string_operator_errors() {
+    let code = r##"
+float x;
+bit[4] c;
+bit[2] d;
+x << 1;
+c & d;
+c | 1.5;
+~x;
+-c;
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 5);
+}
+
+// Arithmetic on operands with no common type is an error, and its type is `Undefined`.
+#[test]
+fn test_from_string_arithmetic_type_errors() {
+    let code = r##"
+bool b;
+angle a;
+complex z;
+b + a;
+z + a;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .all(|err| matches!(err.kind(), SemanticErrorKind::IncompatibleTypesError)));
+    for stmt in &program.stmts()[4..] {
+        match stmt {
+            asg::Stmt::ExprStmt(texpr) => assert_eq!(texpr.get_type(), &Type::Undefined),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn test_from_string_duration_literal_and_arithmetic() {
+    let code = r##"
+duration d = 100ns;
+duration e = 1.5us * 2;
+stretch s;
+float r = d / e;
+s + d;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 6);
+    let initializer_type = |n: usize| match &program.stmts()[n] {
+        asg::Stmt::DeclareClassical(decl) => {
+            decl.initializer().as_ref().unwrap().get_type().clone()
+        }
+        _ => unreachable!(),
+    };
+    assert_eq!(initializer_type(1), Type::Duration(IsConst::True));
+    assert_eq!(initializer_type(2), Type::Duration(IsConst::True));
+    assert_eq!(initializer_type(4), Type::Float(None, IsConst::False));
+    let sum = match &program.stmts()[5] {
+        asg::Stmt::ExprStmt(texpr) => texpr,
+        _ => unreachable!(),
+    };
+    assert_eq!(sum.get_type(), &Type::Stretch(IsConst::False));
+    let literal = match &program.stmts()[1] {
+        asg::Stmt::DeclareClassical(decl) => decl.initializer().as_ref().unwrap().expression(),
+        _ => unreachable!(),
+    };
+    assert!(matches!(
+        literal,
+        asg::Expr::Literal(asg::Literal::Duration(duration))
+            if duration.value() == "100" && duration.unit() == asg::TimeUnit::NanoSecond
+    ));
+}
+
+// A `stretch` converts to a `duration`, and a `duration` to a `stretch`.
+#[test]
+fn test_from_string_stretch_duration_casts() {
+    let code = r##"
+stretch s;
+duration d = s;
+stretch t = d;
+duration e = duration(s);
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(program.len(), 5);
+}
+
+// Arithmetic on two `bool`s is an error. A `bool` in arithmetic with a number is promoted.
+#[test]
+fn test_from_string_bool_arithmetic() {
+    let code = r##"
+bool b = true;
+bool c = b + b;
+int i = b + 1;
+b == b;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert_eq!(errors[0].kind(), &SemanticErrorKind::IncompatibleTypesError);
+    assert_eq!(errors[0].text(), "b + b");
+    let initializer_type = |n: usize| match &program.stmts()[n] {
+        asg::Stmt::DeclareClassical(decl) => {
+            decl.initializer().as_ref().unwrap().get_type().clone()
+        }
+        _ => unreachable!(),
+    };
+    assert_eq!(initializer_type(2), Type::Undefined);
+    assert_eq!(initializer_type(3), Type::UInt(Some(128), IsConst::False));
+}
+
+#[test]
+fn test_from_string_duration_arithmetic_errors() {
+    let code = r##"
+duration d;
+stretch s;
+d + 1;
+d * d;
+s - 2.0;
+1.0 / d;
+d % d;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 5);
+    assert!(errors
+        .iter()
+        .all(|err| matches!(err.kind(), SemanticErrorKind::IncompatibleTypesError)));
+    for stmt in &program.stmts()[3..] {
+        match stmt {
+            asg::Stmt::ExprStmt(texpr) => assert_eq!(texpr.get_type(), &Type::Undefined),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn test_from_string_box_and_durationof() {
+    let code = r##"
+qubit q;
+box[100dt] {
+    U(0, 0, 0) q;
+}
+box {
+    reset q;
+}
+duration d = durationof({
+    U(0, 0, 0) q;
+});
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 5);
+    let box_stmt = match &program.stmts()[2] {
+        asg::Stmt::Box(box_stmt) => box_stmt,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        box_stmt.duration().as_ref().unwrap().get_type(),
+        &Type::Duration(IsConst::True)
+    );
+    assert_eq!(box_stmt.body().statements().len(), 1);
+    assert!(matches!(
+        &program.stmts()[3],
+        asg::Stmt::Box(box_stmt) if box_stmt.duration().is_none()
+    ));
+    let durationof = match &program.stmts()[4] {
+        asg::Stmt::DeclareClassical(decl) => decl.initializer().as_ref().unwrap(),
+        _ => unreachable!(),
+    };
+    assert_eq!(durationof.get_type(), &Type::Duration(IsConst::False));
+}
+
+#[test]
+fn test_from_string_box_not_duration() {
+    let code = r##"
+qubit q;
+box[3] {
+    reset q;
+}
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_from_string_input_output() {
+    let code = r##"
+input angle theta;
+input float[64] phi;
+output bit[4] result;
+int x;
+"##;
+    let (program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let inputs = program.inputs();
+    assert_eq!(inputs.len(), 2);
+    assert_eq!(inputs[0].name(), "theta");
+    assert_eq!(inputs[0].get_type(), &Type::Angle(None, IsConst::False));
+    assert_eq!(inputs[1].name(), "phi");
+    let outputs = program.outputs();
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].name(), "result");
+    assert_eq!(
+        outputs[0].get_type(),
+        &Type::BitArray(ArrayDims::D1(4), IsConst::False)
+    );
+    let symbol_id = outputs[0].symbol().as_ref().unwrap();
+    assert_eq!(symbol_table[symbol_id].name(), "result");
+}
+
+#[test]
+fn test_from_string_input_not_global() {
+    let code = r##"
+def f() {
+    input int n;
+}
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_from_string_qreg_creg() {
+    let code = r##"
+qreg q[5];
+creg c[5];
+creg b;
+"##;
+    let (program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 4);
+    let q = match &program.stmts()[1] {
+        asg::Stmt::DeclareQuantum(decl) => decl.name().as_ref().unwrap(),
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        symbol_table[q].symbol_type(),
+        &Type::QubitArray(ArrayDims::D1(5))
+    );
+    let c = match &program.stmts()[2] {
+        asg::Stmt::DeclareClassical(decl) => decl.name().as_ref().unwrap(),
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        symbol_table[c].symbol_type(),
+        &Type::BitArray(ArrayDims::D1(5), IsConst::False)
+    );
+}
+
+#[test]
+fn test_from_string_openqasm2() {
+    let code = r##"
+OPENQASM 2.0;
+qreg q[2];
+creg c[2];
+opaque g(theta) a, b;
+U(0, 0, 0) q[0];
+CX q[0], q[1];
+g(0.5) q[0], q[1];
+if (c==1) U(0, 0, 0) q[0];
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let version = program.version().as_ref().unwrap();
+    assert_eq!((version.major(), version.minor()), (2, 0));
+    let condition = match program.stmts().last().unwrap() {
+        asg::Stmt::If(if_stmt) => if_stmt.condition(),
+        _ => unreachable!(),
+    };
+    assert_eq!(condition.get_type(), &Type::Bool(IsConst::False));
+}
+
+#[test]
+fn test_from_string_openqasm2_only() {
+    let code = r##"
+OPENQASM 3.0;
+qubit[2] q;
+opaque g a, b;
+CX q[0], q[1];
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn test_from_string_alias_concatenation() {
+    let code = r##"
+qubit[4] q;
+qubit[2] r;
+let q2 = q[0:2] ++ r;
+let q3 = q[{0, 3}];
+x q2[4];
+"##;
+    let (program, errors, symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 1); // `x` is not defined.
+    let alias = match &program.stmts()[3] {
+        asg::Stmt::Alias(alias) => alias,
+        _ => unreachable!(),
+    };
+    let alias_symbol = alias.name().as_ref().unwrap();
+    assert_eq!(symbol_table[alias_symbol].name(), "q2");
+    assert_eq!(
+        symbol_table[alias_symbol].symbol_type(),
+        &Type::QubitArray(ArrayDims::D1(5))
+    );
+    let operands = alias.operands();
+    assert_eq!(operands.len(), 2);
+    let underlying = match operands[0].expression() {
+        asg::Expr::IndexedIdentifier(indexed) => indexed.identifier().as_ref().unwrap(),
+        _ => unreachable!(),
+    };
+    assert_eq!(symbol_table[underlying].name(), "q");
+    assert_eq!(operands[1].get_type(), &Type::QubitArray(ArrayDims::D1(2)));
+    let alias = match &program.stmts()[4] {
+        asg::Stmt::Alias(alias) => alias,
+        _ => unreachable!(),
+    };
+    assert_eq!(alias.operands().len(), 1);
+    assert_eq!(
+        alias.rvalue().get_type(),
+        &Type::QubitArray(ArrayDims::D1(2))
+    );
+}
+
+#[test]
+fn test_from_string_alias_incompatible() {
+    let code = r##"
+qubit[2] q;
+bit[2] c;
+int n;
+let a = q ++ c;
+let b = n;
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn test_from_string_extern() {
+    let code = r##"
+extern f(int[32], float[64]) -> bit;
+extern g(creg[4]);
+bit b = f(1, 2.0);
+"##;
+    let (program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let externs = program.externs();
+    assert_eq!(externs.len(), 2);
+    assert_eq!(externs[0].name(), "f");
+    assert_eq!(
+        externs[0].params(),
+        &[
+            Type::Int(Some(32), IsConst::False),
+            Type::Float(Some(64), IsConst::False)
+        ]
+    );
+    assert_eq!(externs[0].return_type(), Some(&Type::Bit(IsConst::False)));
+    assert_eq!(
+        externs[1].params(),
+        &[Type::BitArray(ArrayDims::D1(4), IsConst::False)]
+    );
+    assert_eq!(externs[1].return_type(), None);
+    let symbol_id = externs[0].symbol().as_ref().unwrap();
+    assert!(matches!(
+        symbol_table[symbol_id].symbol_type(),
+        Type::Subroutine(..)
+    ));
+}
+
+#[test]
+fn test_from_string_extern_call_errors() {
+    let code = r##"
+extern f(int[32]) -> bit;
+qubit q;
+f(1, 2);
+f(q);
+def g() {
+    extern h();
+}
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 3);
+}
+
+#[test]
+fn test_from_string_defcal() {
+    let code = r##"
+defcalgrammar "openpulse";
+cal {
+    extern drive(int) -> int;
+}
+gate x q { U(3.14, 0, 3.14) q; }
+defcal x $0 {
+    play(drive($0), 1);
+}
+defcal x q { }
+defcal rx(angle[20] theta) $0 { }
+defcal measure $1 -> bit { }
+x $0;
+x $1;
+"##;
+    let context = parse_source_string(code, None).take_context();
+    let const_values = context.const_values().clone();
+    let (program, errors, symbol_table) = context.as_tuple();
+    assert!(errors.is_empty());
+    assert_eq!(program.calibration_grammar(), Some("openpulse"));
+    let cal = match &program.stmts()[1] {
+        asg::Stmt::Cal(cal) => cal,
+        _ => unreachable!(),
+    };
+    assert_eq!(cal.body().trim(), "extern drive(int) -> int;");
+    let defcals = program.defcals();
+    assert_eq!(defcals.len(), 4);
+    assert_eq!(defcals[0].qubits(), &[asg::DefCalQubit::Hardware(0)]);
+    assert_eq!(defcals[0].body().unwrap().trim(), "play(drive($0), 1);");
+    assert_eq!(
+        defcals[1].qubits(),
+        &[asg::DefCalQubit::Identifier("q".to_string())]
+    );
+    assert_eq!(
+        defcals[2].params(),
+        &[asg::DefCalParam::Typed(
+            "theta".to_string(),
+            Type::Angle(Some(20), IsConst::False)
+        )]
+    );
+    assert_eq!(defcals[3].name(), "measure");
+    assert_eq!(defcals[3].return_type(), Some(&Type::Bit(IsConst::False)));
+
+    let gate_calls = program
+        .stmts()
+        .iter()
+        .filter_map(|stmt| match stmt {
+            asg::Stmt::GateCall(gate_call) => Some(gate_call),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    // The calibration on `$0` is preferred over the one on any qubit.
+    assert_eq!(
+        program.find_defcal_for_gate_call(gate_calls[0], &symbol_table, &const_values),
+        Some(defcals[0])
+    );
+    assert_eq!(
+        program.find_defcal_for_gate_call(gate_calls[1], &symbol_table, &const_values),
+        Some(defcals[1])
+    );
+    assert_eq!(
+        program.find_defcal("rx", &[None], &[0], &const_values),
+        Some(defcals[2])
+    );
+    assert_eq!(
+        program.find_defcal("rx", &[None], &[1], &const_values),
+        None
+    );
+}
+
+#[test]
+fn test_from_string_defcal_constant_params() {
+    let code = r##"
+defcalgrammar "openpulse";
+gate rx(theta) q { U(theta, -pi / 2, pi / 2) q; }
+defcal rx(angle[20] theta) $0 { }
+defcal rx(pi / 2) $0 { }
+rx(0.3) $0;
+rx(pi / 2) $0;
+rx(2 * pi / 4) $0;
+"##;
+    let context = parse_source_string(code, None).take_context();
+    let const_values = context.const_values().clone();
+    let (program, errors, symbol_table) = context.as_tuple();
+    assert!(errors.is_empty());
+    let defcals = program.defcals();
+    let gate_calls = program
+        .stmts()
+        .iter()
+        .filter_map(|stmt| match stmt {
+            asg::Stmt::GateCall(gate_call) => Some(gate_call),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    // An argument that differs from the constant parameter falls back to the typed parameter.
+    assert_eq!(
+        program.find_defcal_for_gate_call(gate_calls[0], &symbol_table, &const_values),
+        Some(defcals[0])
+    );
+    assert_eq!(
+        program.find_defcal_for_gate_call(gate_calls[1], &symbol_table, &const_values),
+        Some(defcals[1])
+    );
+    assert_eq!(
+        program.find_defcal_for_gate_call(gate_calls[2], &symbol_table, &const_values),
+        Some(defcals[1])
+    );
+}
+
+#[test]
+fn test_from_string_switch() {
+    let code = r##"
+int i = 1;
+const int n = 3;
+switch (i) {
+  case 0, 1 {
+    i = 2;
+  }
+  case n { }
+  default {
+    i = 3;
+  }
+}
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let switch_stmt = match &program.stmts()[3] {
+        asg::Stmt::Switch(switch_stmt) => switch_stmt,
+        _ => unreachable!(),
+    };
+    assert_eq!(switch_stmt.cases().len(), 2);
+    assert_eq!(switch_stmt.cases()[0].labels().len(), 2);
+    assert_eq!(switch_stmt.cases()[1].labels().len(), 1);
+    assert!(switch_stmt.default_block().is_some());
+}
+
+#[test]
+fn test_from_string_switch_errors() {
+    let code = r##"
+float f;
+int i;
+int m;
+switch (f) {
+  case 1 { }
+}
+switch (i) {
+  case 0, 1 { }
+  case 1 { }
+  case m { }
+  default { }
+  case 2 { }
+}
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    // Non-integer control, duplicate label, non-const label, misplaced default.
+    assert_eq!(errors.len(), 4);
+}
+
+// Labels with the same constant value are duplicates.
+#[test]
+fn test_from_string_switch_duplicate_const_label() {
+    let code = r##"
+const int n = 2;
+int x;
+switch (x) {
+  case n { }
+  case 1 + 1 { }
+  case 3 { }
+}
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].kind(),
+        SemanticErrorKind::DuplicateCaseLabelError
+    ));
+    assert_eq!(errors[0].text(), "1 + 1");
+}
+
+#[test]
+fn test_from_string_annotation_and_pragma() {
+    let code = r##"
+pragma my_compiler opt_level 2
+#pragma other
+qubit q;
+@bind $0
+@vendor.hint
+reset q;
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    assert_eq!(program.len(), 5);
+    let pragma = match &program.stmts()[1] {
+        asg::Stmt::Pragma(pragma) => pragma,
+        _ => unreachable!(),
+    };
+    assert_eq!(pragma.pragma(), "my_compiler opt_level 2");
+    let annotated = match &program.stmts()[4] {
+        asg::Stmt::AnnotatedStmt(annotated) => annotated,
+        _ => unreachable!(),
+    };
+    let annotations = annotated.annotations();
+    assert_eq!(annotations.len(), 2);
+    assert_eq!(annotations[0].kind(), "bind");
+    assert_eq!(annotations[0].body(), Some("$0"));
+    assert_eq!(annotations[1].kind(), "vendor.hint");
+    assert_eq!(annotations[1].body(), None);
+    assert!(matches!(annotated.statement(), asg::Stmt::Reset(_)));
+}
+
+// Annotated declarations are found by the accessors of the program.
+#[test]
+fn test_from_string_annotated_declarations() {
+    let code = r##"
+defcalgrammar "openpulse";
+@bind p
+input angle theta;
+output bit b;
+@vendor.link
+extern f(int) -> int;
+@vendor.hint
+defcal x $0 { }
+"##;
+    let (program, errors, _symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let inputs = program.inputs();
+    assert_eq!(inputs.len(), 1);
+    assert_eq!(inputs[0].name(), "theta");
+    assert_eq!(program.outputs().len(), 1);
+    let externs = program.externs();
+    assert_eq!(externs.len(), 1);
+    assert_eq!(externs[0].name(), "f");
+    let defcals = program.defcals();
+    assert_eq!(defcals.len(), 1);
+    assert_eq!(defcals[0].name(), "x");
+}
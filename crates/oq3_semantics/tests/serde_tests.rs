@@ -0,0 +1,179 @@
+// Copyright contributors to the openqasm-parser project
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "serde")]
+
+use oq3_semantics::asg;
+use oq3_semantics::semantic_error::{SemanticErrorKind, SemanticErrorList};
+use oq3_semantics::symbols::{SymbolTable, SymbolType};
+use oq3_semantics::syntax_to_semantics::parse_source_string;
+use oq3_semantics::types::{ArrayDims, IsConst, Type};
+use serde_json::json;
+
+fn parse_string(code: &str) -> (asg::Program, SemanticErrorList, SymbolTable) {
+    parse_source_string(code, None).take_context().as_tuple()
+}
+
+// Serialize `value` to JSON and back, and check that nothing is lost.
+fn round_trip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let json = serde_json::to_string(value).unwrap();
+    let value_out: T = serde_json::from_str(&json).unwrap();
+    assert_eq!(value, &value_out);
+    // Serializing again gives the same text.
+    assert_eq!(json, serde_json::to_string(&value_out).unwrap());
+    value_out
+}
+
+#[test]
+fn test_serde_program() {
+    let code = r##"
+const int[32] n = 2;
+qubit[n] q;
+bit[2] c;
+float[64] x = π / 2;
+gate h a { U(π / 2, 0, π) a; }
+def f(int y) -> int { return y + 1; }
+for int i in [0:1] {
+    h q[i];
+    x += 1.5;
+}
+if (f(n) == 3) {
+    ctrl @ h q[0], q[1];
+}
+c = measure q;
+"##;
+    let (program, errors, symbol_table) = parse_string(code);
+    assert!(errors.is_empty());
+    let program_out = round_trip(&program);
+    // The spans, which are ignored when comparing expressions, are also preserved.
+    assert_eq!(program_out.stmt_span(3), program.stmt_span(3));
+    match (&program.stmts()[4], &program_out.stmts()[4]) {
+        (asg::Stmt::DeclareClassical(decl), asg::Stmt::DeclareClassical(decl_out)) => {
+            let init = decl.initializer().as_ref().unwrap();
+            let init_out = decl_out.initializer().as_ref().unwrap();
+            assert!(init.span().is_some());
+            assert_eq!(init.span(), init_out.span());
+        }
+        _ => unreachable!(),
+    };
+    round_trip(&symbol_table);
+}
+
+#[test]
+fn test_serde_symbol_table() {
+    let code = r##"
+qubit[2] q;
+int x = 1;
+"##;
+    let (_program, _errors, symbol_table) = parse_string(code);
+    let symbol_table_out = round_trip(&symbol_table);
+    let x = symbol_table_out.lookup("x").unwrap();
+    assert_eq!(x.symbol_type(), &Type::Int(None, IsConst::False));
+    assert_eq!(x.symbol_id(), symbol_table.lookup("x").unwrap().symbol_id());
+    assert_eq!(
+        symbol_table_out.lookup("q").unwrap().symbol_type(),
+        &Type::QubitArray(ArrayDims::D1(2))
+    );
+}
+
+#[test]
+fn test_serde_errors() {
+    let code = r##"
+int x = y;
+const int n = 1;
+n = 2;
+"##;
+    let (_program, errors, _symbol_table) = parse_string(code);
+    assert_eq!(errors.len(), 2);
+    let errors_out = round_trip(&errors);
+    assert!(matches!(
+        errors_out[0].kind(),
+        SemanticErrorKind::UndefVarError
+    ));
+    assert_eq!(errors_out[0].text(), "y");
+    assert_eq!(errors_out[0].range(), errors[0].range());
+    assert_eq!(errors_out[1].to_string(), errors[1].to_string());
+}
+
+// The JSON schema is documented in the crate documentation. These check some of the details.
+#[test]
+fn test_serde_schema() {
+    let to_json = |value| serde_json::to_value(value).unwrap();
+    assert_eq!(
+        to_json(Type::Int(Some(32), IsConst::True)),
+        json!({"Int": [32, "True"]})
+    );
+    assert_eq!(
+        to_json(Type::Float(None, IsConst::False)),
+        json!({"Float": [null, "False"]})
+    );
+    assert_eq!(to_json(Type::Qubit), json!("Qubit"));
+    assert_eq!(
+        to_json(Type::BitArray(ArrayDims::D1(3), IsConst::False)),
+        json!({"BitArray": [{"D1": 3}, "False"]})
+    );
+
+    let (program, errors, symbol_table) = parse_string("int x = y;\n");
+    let x = usize::from(symbol_table.lookup("x").unwrap().symbol_id());
+    let stmt = serde_json::to_value(&program.stmts()[1]).unwrap();
+    assert_eq!(
+        stmt,
+        json!({"DeclareClassical": {
+            "name": {"Ok": x},
+            "initializer": {
+                "expression": {"Identifier": {"name": "y", "symbol": {"Err": "MissingBinding"}}},
+                "ty": "Undefined",
+                "span": {"file_id": 0, "range": [8, 9]},
+            },
+        }})
+    );
+    let error = serde_json::to_value(&errors[0]).unwrap();
+    assert_eq!(
+        error,
+        json!({"error_kind": "UndefVarError", "range": [8, 9], "text": "y"})
+    );
+    let table = serde_json::to_value(&symbol_table).unwrap();
+    assert_eq!(
+        table["all_symbols"][x],
+        json!({"name": "x", "typ": {"Int": [null, "False"]}, "span": {"file_id": 0, "range": [0, 10]}})
+    );
+    assert_eq!(table["symbol_table_stack"][0]["table"]["x"], json!(x));
+}
+
+// A serialized program has the documented keys. The schema version is written
+// outside of the program, and a value with another schema version is rejected.
+#[test]
+fn test_serde_schema_version() {
+    let (program, _errors, _symbol_table) = parse_string("qubit q;\n");
+    let json = serde_json::to_value(asg::Versioned::new(&program)).unwrap();
+    assert_eq!(json["schema_version"], json!(asg::SCHEMA_VERSION));
+    let mut keys = json["value"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(
+        keys,
+        [
+            "calibration_grammar",
+            "file_paths",
+            "stmt_spans",
+            "stmts",
+            "version"
+        ]
+    );
+    let versioned: asg::Versioned<asg::Program> = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(versioned.into_value(), program);
+
+    let mut json_other = json;
+    json_other["schema_version"] = json!(asg::SCHEMA_VERSION + 1);
+    let err = serde_json::from_value::<asg::Versioned<asg::Program>>(json_other).unwrap_err();
+    assert!(
+        err.to_string().contains("unsupported schema version"),
+        "{err}"
+    );
+}
@@ -0,0 +1,99 @@
+// Copyright contributors to the openqasm-parser project
+// SPDX-License-Identifier: Apache-2.0
+
+use oq3_semantics::asg;
+use oq3_semantics::asg::visit::{Visitor, VisitorMut};
+use oq3_semantics::semantic_error::SemanticErrorList;
+use oq3_semantics::symbols::SymbolTable;
+use oq3_semantics::syntax_to_semantics::parse_source_string;
+use oq3_semantics::validate::count_symbol_errors;
+
+fn parse_string(code: &str) -> (asg::Program, SemanticErrorList, SymbolTable) {
+    parse_source_string(code, None).take_context().as_tuple()
+}
+
+const CODE: &str = r##"
+gate h q {}
+qubit[2] q;
+int x = 1;
+for int i in [0:1] {
+    h q[i];
+    x += i;
+}
+if (x == 1) {
+    ctrl(1) @ h q[0], q[1];
+}
+"##;
+
+// Counts gate calls and the identifiers referring to each symbol.
+#[derive(Default)]
+struct Counter {
+    gate_calls: usize,
+    symbols: usize,
+    identifiers: usize,
+}
+
+impl Visitor for Counter {
+    fn visit_gate_call(&mut self, gate_call: &asg::GateCall) {
+        self.gate_calls += 1;
+        self.super_gate_call(gate_call);
+    }
+
+    fn visit_identifier(&mut self, identifier: &asg::Identifier) {
+        self.identifiers += 1;
+        self.super_identifier(identifier);
+    }
+
+    fn visit_symbol(&mut self, _symbol: &oq3_semantics::symbols::SymbolIdResult) {
+        self.symbols += 1;
+    }
+}
+
+#[test]
+fn test_visitor() {
+    let (program, errors, symbol_table) = parse_string(CODE);
+    assert!(errors.is_empty());
+    let mut counter = Counter::default();
+    counter.visit_program(&program);
+    assert_eq!(counter.gate_calls, 2);
+    // `i` and `x` in the loop body, and `x` in the condition.
+    assert_eq!(counter.identifiers, 3);
+    // The gates `U` and `h` and their parameters, `q`, `x`, `i`, the assigned `x`,
+    // the gate calls and their operands, and the identifiers.
+    assert!(counter.symbols > counter.identifiers + counter.gate_calls);
+    assert_eq!(count_symbol_errors(&program, &symbol_table), 0);
+}
+
+// Replaces each literal `1` by `2`.
+struct Rewriter;
+
+impl VisitorMut for Rewriter {
+    fn visit_expr(&mut self, expr: &mut asg::Expr) {
+        if matches!(expr, asg::Expr::Literal(asg::Literal::Int(int)) if *int.value() == 1) {
+            *expr = asg::IntLiteral::new(2_u32).to_expr();
+        }
+        self.super_expr(expr);
+    }
+}
+
+// Collects the values of the integer literals.
+struct IntLiterals(Vec<u128>);
+
+impl Visitor for IntLiterals {
+    fn visit_literal(&mut self, literal: &asg::Literal) {
+        if let asg::Literal::Int(int) = literal {
+            self.0.push(*int.value());
+        }
+    }
+}
+
+#[test]
+fn test_visitor_mut() {
+    let (mut program, errors, _symbol_table) = parse_string(CODE);
+    assert!(errors.is_empty());
+    Rewriter.visit_program(&mut program);
+    let mut literals = IntLiterals(Vec::new());
+    literals.visit_program(&program);
+    // `int x = 1`, the range `[0:1]`, `x == 1`, `ctrl(1)`, and the indices in `q[0], q[1]`.
+    assert_eq!(literals.0, vec![2, 0, 2, 2, 2, 0, 2]);
+}
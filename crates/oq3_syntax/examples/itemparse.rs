@@ -170,8 +170,8 @@ fn print_gate(gate: ast::Gate) {
 
 fn print_defcal(defcal: ast::DefCal) {
     println!("DefCal\ndefcal name: '{}'", defcal.name().unwrap());
-    if !defcal.param_list().is_none() {
-        println!("parameters: '{}'", defcal.param_list().unwrap());
+    if !defcal.def_cal_param_list().is_none() {
+        println!("parameters: '{}'", defcal.def_cal_param_list().unwrap());
     }
     println!("qubits: '{}'", defcal.qubit_list().unwrap());
     if !defcal.ret_type().is_none() {
@@ -182,8 +182,8 @@ fn print_defcal(defcal: ast::DefCal) {
 
 fn print_def(def: ast::Def) {
     println!("Def\ndef name: '{}'", def.name().unwrap());
-    if !def.param_list().is_none() {
-        println!("parameters: '{}'", def.param_list().unwrap());
+    if !def.typed_param_list().is_none() {
+        println!("parameters: '{}'", def.typed_param_list().unwrap());
     }
     if !def.ret_type().is_none() {
         println!("return type: '{}'", def.ret_type().unwrap());
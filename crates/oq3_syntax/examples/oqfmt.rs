@@ -0,0 +1,118 @@
+// Copyright contributors to the openqasm-parser project
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use oq3_syntax::format::{format_range, format_source_file};
+use oq3_syntax::{AstNode, SourceFile, TextRange, TextSize};
+
+#[derive(Parser)]
+#[command(name = "oqfmt")]
+#[command(about = "Format OpenQASM 3 source files.")]
+#[command(long_about = "
+Format OpenQASM 3 source files.
+
+By default the formatted source is printed to stdout. Only whitespace is changed.
+Files with syntax errors are not formatted.
+")]
+struct Cli {
+    /// files to format
+    #[arg(value_name = "FILENAME", required = true)]
+    filenames: Vec<PathBuf>,
+
+    /// Write the formatted source back to the files
+    #[arg(long)]
+    write: bool,
+
+    /// Write nothing, but exit with status 1 if a file is not formatted
+    #[arg(long, conflicts_with = "write")]
+    check: bool,
+
+    /// Format only lines FIRST to LAST, counting from 1
+    #[arg(long, value_name = "FIRST:LAST")]
+    lines: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let lines = match cli.lines.as_deref().map(parse_lines).transpose() {
+        Ok(lines) => lines,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut success = true;
+    for filename in &cli.filenames {
+        let text = match fs::read_to_string(filename) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("{}: {err}", filename.display());
+                success = false;
+                continue;
+            }
+        };
+        let parse = SourceFile::parse(&text);
+        if !parse.errors().is_empty() {
+            eprintln!("{}: not formatted, found syntax errors", filename.display());
+            success = false;
+            continue;
+        }
+        let formatted = match lines {
+            Some((first, last)) => {
+                let mut formatted = text.clone();
+                let range = line_range(&text, first, last);
+                for indel in format_range(&parse.tree(), range).iter().rev() {
+                    indel.apply(&mut formatted);
+                }
+                formatted
+            }
+            None => format_source_file(&parse.tree()).syntax().to_string(),
+        };
+        if cli.check {
+            if formatted != text {
+                println!("{}: not formatted", filename.display());
+                success = false;
+            }
+        } else if cli.write {
+            if formatted != text {
+                if let Err(err) = fs::write(filename, formatted) {
+                    eprintln!("{}: {err}", filename.display());
+                    success = false;
+                }
+            }
+        } else {
+            print!("{formatted}");
+        }
+    }
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn parse_lines(lines: &str) -> Result<(usize, usize), String> {
+    let err = || format!("expected lines as FIRST:LAST, found `{lines}`");
+    let (first, last) = lines.split_once(':').ok_or_else(err)?;
+    let first: usize = first.parse().map_err(|_| err())?;
+    let last: usize = last.parse().map_err(|_| err())?;
+    if first == 0 || last < first {
+        return Err(err());
+    }
+    Ok((first, last))
+}
+
+/// The range of text from the start of line `first` to the end of line `last`.
+fn line_range(text: &str, first: usize, last: usize) -> TextRange {
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    let offset = |line: usize| line_starts.get(line).copied().unwrap_or(text.len());
+    let start = offset(first - 1);
+    let end = offset(last).max(start);
+    TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32))
+}
@@ -26,7 +26,7 @@ use crate::{
 pub use self::{
     expr_ext::{ArrayExprKind, ElseBranch, LiteralKind},
     generated::{nodes::*, tokens::*},
-    node_ext::HasTextName,
+    node_ext::{DefCalParam, HasTextName},
     operators::{ArithOp, BinaryOp, CmpOp, LogicOp, Ordering, RangeOp, UnaryOp},
     token_ext::{CommentKind, CommentShape, IsString, QuoteOffsets, Radix},
     traits::{HasArgList, HasLoopBody, HasModuleItem, HasName},
@@ -8,7 +8,7 @@
 use crate::{
     ast::{
         self,
-        operators::{ArithOp, BinaryOp, CmpOp, LogicOp, Ordering},
+        operators::{ArithOp, BinaryOp, CmpOp, LogicOp, Ordering, UnaryOp},
         support, AstChildren, AstNode,
     },
     AstToken,
@@ -177,18 +177,22 @@ impl ast::BinExpr {
                 T![-]  => BinaryOp::ArithOp(ArithOp::Sub),
                 T![/]  => BinaryOp::ArithOp(ArithOp::Div),
                 T![%]  => BinaryOp::ArithOp(ArithOp::Rem),
+                T![**] => BinaryOp::ArithOp(ArithOp::Pow),
                 T![<<] => BinaryOp::ArithOp(ArithOp::Shl),
                 T![>>] => BinaryOp::ArithOp(ArithOp::Shr),
                 T![^]  => BinaryOp::ArithOp(ArithOp::BitXor),
                 T![|]  => BinaryOp::ArithOp(ArithOp::BitOr),
                 T![&]  => BinaryOp::ArithOp(ArithOp::BitAnd),
 
+                T![++] => BinaryOp::ConcatenationOp,
+
                 T![=]   => BinaryOp::Assignment { op: None },
                 T![+=]  => BinaryOp::Assignment { op: Some(ArithOp::Add) },
                 T![*=]  => BinaryOp::Assignment { op: Some(ArithOp::Mul) },
                 T![-=]  => BinaryOp::Assignment { op: Some(ArithOp::Sub) },
                 T![/=]  => BinaryOp::Assignment { op: Some(ArithOp::Div) },
                 T![%=]  => BinaryOp::Assignment { op: Some(ArithOp::Rem) },
+                T![**=] => BinaryOp::Assignment { op: Some(ArithOp::Pow) },
                 T![<<=] => BinaryOp::Assignment { op: Some(ArithOp::Shl) },
                 T![>>=] => BinaryOp::Assignment { op: Some(ArithOp::Shr) },
                 T![^=]  => BinaryOp::Assignment { op: Some(ArithOp::BitXor) },
@@ -225,6 +229,56 @@ impl ast::BinExpr {
     }
 }
 
+impl ast::PrefixExpr {
+    pub fn op_kind(&self) -> Option<UnaryOp> {
+        let res = match self.op_token()?.kind() {
+            T![~] => UnaryOp::BitNot,
+            T![!] => UnaryOp::Not,
+            T![-] => UnaryOp::Neg,
+            _ => return None,
+        };
+        Some(res)
+    }
+
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        self.syntax().first_child_or_token()?.into_token()
+    }
+}
+
+impl ast::AssignmentStmt {
+    // The left hand side may be an `IndexedIdentifier`, which is also an `Expr`.
+    // So the right hand side is the last `Expr`.
+    pub fn rhs(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).last()
+    }
+
+    /// Return `BinaryOp::Assignment { op }`, where `op` is `None` for `=` and, for example,
+    /// `Some(ArithOp::Add)` for `+=`.
+    pub fn op_kind(&self) -> Option<BinaryOp> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find_map(|token| {
+                let op = match token.kind() {
+                    T![=] => None,
+                    T![+=] => Some(ArithOp::Add),
+                    T![-=] => Some(ArithOp::Sub),
+                    T![*=] => Some(ArithOp::Mul),
+                    T![/=] => Some(ArithOp::Div),
+                    T![%=] => Some(ArithOp::Rem),
+                    T![**=] => Some(ArithOp::Pow),
+                    T![&=] => Some(ArithOp::BitAnd),
+                    T![|=] => Some(ArithOp::BitOr),
+                    T![^=] => Some(ArithOp::BitXor),
+                    T![<<=] => Some(ArithOp::Shl),
+                    T![>>=] => Some(ArithOp::Shr),
+                    _ => return None,
+                };
+                Some(BinaryOp::Assignment { op })
+            })
+    }
+}
+
 // FIXME: The r-a implementations always return nodes for the all tokens, eg COLONs.
 // Maybe include this later. For now, we only need expressions.
 impl ast::RangeExpr {
@@ -285,7 +339,9 @@ pub enum LiteralKind {
     IntNumber(ast::IntNumber),
     FloatNumber(ast::FloatNumber),
     SimpleFloatNumber(ast::SimpleFloatNumber),
+    TimingIntNumber(ast::TimingIntNumber),
     TimingFloatNumber(ast::TimingFloatNumber),
+    ImaginaryNumber(ast::ImaginaryNumber),
     Char(ast::Char),
     Byte(ast::Byte),
     Bool(bool),
@@ -352,9 +408,15 @@ impl ast::Literal {
         if let Some(t) = ast::SimpleFloatNumber::cast(token.clone()) {
             return LiteralKind::SimpleFloatNumber(t);
         }
+        if let Some(t) = ast::TimingIntNumber::cast(token.clone()) {
+            return LiteralKind::TimingIntNumber(t);
+        }
         if let Some(t) = ast::TimingFloatNumber::cast(token.clone()) {
             return LiteralKind::TimingFloatNumber(t);
         }
+        if let Some(t) = ast::ImaginaryNumber::cast(token.clone()) {
+            return LiteralKind::ImaginaryNumber(t);
+        }
         if let Some(t) = ast::String::cast(token.clone()) {
             return LiteralKind::String(t);
         }
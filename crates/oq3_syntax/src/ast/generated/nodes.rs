@@ -60,7 +60,7 @@ impl Def {
     pub fn def_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![def])
     }
-    pub fn param_list(&self) -> Option<ParamList> {
+    pub fn typed_param_list(&self) -> Option<TypedParamList> {
         support::child(&self.syntax)
     }
     pub fn ret_type(&self) -> Option<RetType> {
@@ -82,6 +82,9 @@ impl Gate {
     pub fn gate_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![gate])
     }
+    pub fn opaque_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![opaque])
+    }
     pub fn qubit_args(&self) -> Option<ParamList> {
         support::child(&self.syntax)
     }
@@ -101,7 +104,7 @@ impl DefCal {
     pub fn defcal_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![defcal])
     }
-    pub fn param_list(&self) -> Option<ParamList> {
+    pub fn def_cal_param_list(&self) -> Option<DefCalParamList> {
         support::child(&self.syntax)
     }
     pub fn qubit_list(&self) -> Option<QubitList> {
@@ -110,7 +113,7 @@ impl DefCal {
     pub fn ret_type(&self) -> Option<RetType> {
         support::child(&self.syntax)
     }
-    pub fn body(&self) -> Option<BlockExpr> {
+    pub fn body(&self) -> Option<CalibrationBlock> {
         support::child(&self.syntax)
     }
     pub fn semicolon_token(&self) -> Option<SyntaxToken> {
@@ -125,12 +128,9 @@ impl Cal {
     pub fn cal_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![cal])
     }
-    pub fn body(&self) -> Option<BlockExpr> {
+    pub fn body(&self) -> Option<CalibrationBlock> {
         support::child(&self.syntax)
     }
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![;])
-    }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DefCalGrammar {
@@ -148,6 +148,25 @@ impl DefCalGrammar {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExternItem {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for ExternItem {}
+impl ExternItem {
+    pub fn extern_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![extern])
+    }
+    pub fn extern_param_list(&self) -> Option<ExternParamList> {
+        support::child(&self.syntax)
+    }
+    pub fn ret_type(&self) -> Option<RetType> {
+        support::child(&self.syntax)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![;])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TypeDeclarationStmt {
     pub(crate) syntax: SyntaxNode,
 }
@@ -192,6 +211,47 @@ impl ClassicalDeclarationStatement {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IODeclarationStatement {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for IODeclarationStatement {}
+impl IODeclarationStatement {
+    pub fn input_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![input])
+    }
+    pub fn output_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![output])
+    }
+    pub fn scalar_type(&self) -> Option<ScalarType> {
+        support::child(&self.syntax)
+    }
+    pub fn array_type(&self) -> Option<ArrayType> {
+        support::child(&self.syntax)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![;])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OldStyleDeclarationStatement {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for OldStyleDeclarationStatement {}
+impl OldStyleDeclarationStatement {
+    pub fn creg_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![creg])
+    }
+    pub fn qreg_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![qreg])
+    }
+    pub fn designator(&self) -> Option<Designator> {
+        support::child(&self.syntax)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![;])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct QuantumDeclarationStatement {
     pub(crate) syntax: SyntaxNode,
 }
@@ -211,6 +271,9 @@ pub struct GateCallStmt {
 impl ast::HasName for GateCallStmt {}
 impl ast::HasArgList for GateCallStmt {}
 impl GateCallStmt {
+    pub fn modifier_list(&self) -> Option<ModifierList> {
+        support::child(&self.syntax)
+    }
     pub fn qubit_list(&self) -> Option<QubitList> {
         support::child(&self.syntax)
     }
@@ -252,10 +315,7 @@ pub struct AssignmentStmt {
 }
 impl ast::HasName for AssignmentStmt {}
 impl AssignmentStmt {
-    pub fn eq_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![=])
-    }
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn indexed_identifier(&self) -> Option<IndexedIdentifier> {
         support::child(&self.syntax)
     }
     pub fn semicolon_token(&self) -> Option<SyntaxToken> {
@@ -285,13 +345,19 @@ impl ForStmt {
     pub fn for_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![for])
     }
+    pub fn scalar_type(&self) -> Option<ScalarType> {
+        support::child(&self.syntax)
+    }
     pub fn loop_var(&self) -> Option<Name> {
         support::child(&self.syntax)
     }
     pub fn in_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![in])
     }
-    pub fn loop_body(&self) -> Option<Expr> {
+    pub fn for_iterable(&self) -> Option<ForIterable> {
+        support::child(&self.syntax)
+    }
+    pub fn loop_body(&self) -> Option<BlockExpr> {
         support::child(&self.syntax)
     }
 }
@@ -320,6 +386,27 @@ impl WhileStmt {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SwitchCaseStmt {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SwitchCaseStmt {
+    pub fn switch_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![switch])
+    }
+    pub fn control(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    pub fn l_curly_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['{'])
+    }
+    pub fn case_items(&self) -> AstChildren<CaseItem> {
+        support::children(&self.syntax)
+    }
+    pub fn r_curly_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['}'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Reset {
     pub(crate) syntax: SyntaxNode,
 }
@@ -327,19 +414,61 @@ impl Reset {
     pub fn reset_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![reset])
     }
-    pub fn qubit(&self) -> Option<Expr> {
+    pub fn gate_operand(&self) -> Option<GateOperand> {
         support::child(&self.syntax)
     }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![;])
+    }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Measure {
+pub struct BoxStmt {
     pub(crate) syntax: SyntaxNode,
 }
-impl Measure {
-    pub fn measure_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![measure])
+impl BoxStmt {
+    pub fn box_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![box])
+    }
+    pub fn designator(&self) -> Option<Designator> {
+        support::child(&self.syntax)
+    }
+    pub fn body(&self) -> Option<BlockExpr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DelayStmt {
+    pub(crate) syntax: SyntaxNode,
+}
+impl DelayStmt {
+    pub fn delay_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![delay])
+    }
+    pub fn designator(&self) -> Option<Designator> {
+        support::child(&self.syntax)
+    }
+    pub fn qubit_list(&self) -> Option<QubitList> {
+        support::child(&self.syntax)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![;])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MeasureArrowAssignmentStmt {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MeasureArrowAssignmentStmt {
+    pub fn measure_expression(&self) -> Option<MeasureExpression> {
+        support::child(&self.syntax)
+    }
+    pub fn thin_arrow_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![->])
+    }
+    pub fn identifier(&self) -> Option<Identifier> {
+        support::child(&self.syntax)
     }
-    pub fn qubit(&self) -> Option<Expr> {
+    pub fn indexed_identifier(&self) -> Option<IndexedIdentifier> {
         support::child(&self.syntax)
     }
     pub fn semicolon_token(&self) -> Option<SyntaxToken> {
@@ -413,11 +542,58 @@ impl EndStmt {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnnotatedStmt {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AnnotatedStmt {
+    pub fn annotations(&self) -> AstChildren<Annotation> {
+        support::children(&self.syntax)
+    }
+    pub fn stmt(&self) -> Option<Stmt> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pragma {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Pragma {}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Version {
     pub(crate) syntax: SyntaxNode,
 }
 impl Version {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Designator {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Designator {
+    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['['])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![']'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl BlockExpr {
+    pub fn l_curly_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['{'])
+    }
+    pub fn statements(&self) -> AstChildren<Stmt> {
+        support::children(&self.syntax)
+    }
+    pub fn r_curly_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['}'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct QubitList {
     pub(crate) syntax: SyntaxNode,
 }
@@ -427,14 +603,45 @@ impl QubitList {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct BlockExpr {
+pub struct MeasureExpression {
     pub(crate) syntax: SyntaxNode,
 }
-impl BlockExpr {
+impl MeasureExpression {
+    pub fn measure_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![measure])
+    }
+    pub fn gate_operand(&self) -> Option<GateOperand> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Identifier {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Identifier {
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![ident])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IndexedIdentifier {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for IndexedIdentifier {}
+impl IndexedIdentifier {
+    pub fn index_operators(&self) -> AstChildren<IndexOperator> {
+        support::children(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CalibrationBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CalibrationBlock {
     pub fn l_curly_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T!['{'])
     }
-    pub fn statements(&self) -> AstChildren<Stmt> {
+    pub fn calibration_blocks(&self) -> AstChildren<CalibrationBlock> {
         support::children(&self.syntax)
     }
     pub fn r_curly_token(&self) -> Option<SyntaxToken> {
@@ -463,14 +670,14 @@ impl TypeSpec {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ParamList {
+pub struct TypedParamList {
     pub(crate) syntax: SyntaxNode,
 }
-impl ParamList {
+impl TypedParamList {
     pub fn l_paren_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T!['('])
     }
-    pub fn params(&self) -> AstChildren<Param> {
+    pub fn typed_params(&self) -> AstChildren<TypedParam> {
         support::children(&self.syntax)
     }
     pub fn r_paren_token(&self) -> Option<SyntaxToken> {
@@ -490,66 +697,243 @@ impl RetType {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Param {
+pub struct DefCalParamList {
     pub(crate) syntax: SyntaxNode,
 }
-impl ast::HasName for Param {}
-impl Param {}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ExternItem {
-    pub(crate) syntax: SyntaxNode,
+impl DefCalParamList {
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['('])
+    }
+    pub fn typed_params(&self) -> AstChildren<TypedParam> {
+        support::children(&self.syntax)
+    }
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
+    }
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![')'])
+    }
 }
-impl ast::HasName for ExternItem {}
-impl ExternItem {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ExprStmt {
+pub struct TypedParam {
     pub(crate) syntax: SyntaxNode,
 }
-impl ExprStmt {
-    pub fn expr(&self) -> Option<Expr> {
+impl ast::HasName for TypedParam {}
+impl TypedParam {
+    pub fn scalar_type(&self) -> Option<ScalarType> {
         support::child(&self.syntax)
     }
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![;])
+    pub fn qubit_type(&self) -> Option<QubitType> {
+        support::child(&self.syntax)
+    }
+    pub fn array_type(&self) -> Option<ArrayType> {
+        support::child(&self.syntax)
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ArrayExpr {
+pub struct ParamList {
     pub(crate) syntax: SyntaxNode,
 }
-impl ArrayExpr {
-    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T!['['])
+impl ParamList {
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['('])
     }
-    pub fn exprs(&self) -> AstChildren<Expr> {
+    pub fn params(&self) -> AstChildren<Param> {
         support::children(&self.syntax)
     }
-    pub fn expr(&self) -> Option<Expr> {
-        support::child(&self.syntax)
-    }
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![;])
-    }
-    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![']'])
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![')'])
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct BinExpr {
+pub struct Param {
     pub(crate) syntax: SyntaxNode,
 }
-impl BinExpr {}
+impl ast::HasName for Param {}
+impl Param {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct BoxExpr {
+pub struct ScalarType {
     pub(crate) syntax: SyntaxNode,
 }
-impl BoxExpr {
-    pub fn box_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![box])
+impl ScalarType {
+    pub fn bit_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![bit])
+    }
+    pub fn designator(&self) -> Option<Designator> {
+        support::child(&self.syntax)
+    }
+    pub fn int_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![int])
+    }
+    pub fn uint_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![uint])
+    }
+    pub fn float_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![float])
+    }
+    pub fn angle_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![angle])
+    }
+    pub fn bool_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![bool])
+    }
+    pub fn duration_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![duration])
+    }
+    pub fn stretch_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![stretch])
+    }
+    pub fn complex_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![complex])
+    }
+    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['['])
+    }
+    pub fn scalar_type(&self) -> Option<ScalarType> {
+        support::child(&self.syntax)
+    }
+    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![']'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QubitType {
+    pub(crate) syntax: SyntaxNode,
+}
+impl QubitType {
+    pub fn qubit_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![qubit])
+    }
+    pub fn designator(&self) -> Option<Designator> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ArrayType {
+    pub fn readonly_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![readonly])
+    }
+    pub fn mutable_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![mutable])
+    }
+    pub fn array_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![array])
+    }
+    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['['])
+    }
+    pub fn scalar_type(&self) -> Option<ScalarType> {
+        support::child(&self.syntax)
+    }
+    pub fn comma_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![,])
+    }
+    pub fn expression_list(&self) -> Option<ExpressionList> {
+        support::child(&self.syntax)
+    }
+    pub fn dim_expr(&self) -> Option<DimExpr> {
+        support::child(&self.syntax)
+    }
+    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![']'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExternParamList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ExternParamList {
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['('])
+    }
+    pub fn extern_params(&self) -> AstChildren<ExternParam> {
+        support::children(&self.syntax)
+    }
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![')'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExternParam {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ExternParam {
+    pub fn scalar_type(&self) -> Option<ScalarType> {
+        support::child(&self.syntax)
+    }
+    pub fn array_type(&self) -> Option<ArrayType> {
+        support::child(&self.syntax)
+    }
+    pub fn creg_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![creg])
+    }
+    pub fn designator(&self) -> Option<Designator> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExprStmt {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ExprStmt {
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![;])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Annotation {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Annotation {}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ArrayExpr {
+    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['['])
+    }
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
     }
     pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![;])
+    }
+    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![']'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BinExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl BinExpr {}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DurationOfExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl DurationOfExpr {
+    pub fn durationof_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![durationof])
+    }
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['('])
+    }
+    pub fn block_expr(&self) -> Option<BlockExpr> {
+        support::child(&self.syntax)
+    }
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![')'])
+    }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CallExpr {
@@ -595,16 +979,6 @@ impl IndexExpr {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct IndexedIdentifier {
-    pub(crate) syntax: SyntaxNode,
-}
-impl ast::HasName for IndexedIdentifier {}
-impl IndexedIdentifier {
-    pub fn index_operators(&self) -> AstChildren<IndexOperator> {
-        support::children(&self.syntax)
-    }
-}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Literal {
     pub(crate) syntax: SyntaxNode,
 }
@@ -670,33 +1044,21 @@ impl ArrayLiteral {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct MeasureExpression {
+pub struct HardwareQubit {
     pub(crate) syntax: SyntaxNode,
 }
-impl MeasureExpression {
-    pub fn measure_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![measure])
-    }
-    pub fn gate_operand(&self) -> Option<GateOperand> {
-        support::child(&self.syntax)
-    }
-}
+impl ast::HasName for HardwareQubit {}
+impl HardwareQubit {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Identifier {
+pub struct PrefixExpr {
     pub(crate) syntax: SyntaxNode,
 }
-impl Identifier {
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![ident])
+impl PrefixExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct HardwareQubit {
-    pub(crate) syntax: SyntaxNode,
-}
-impl ast::HasName for HardwareQubit {}
-impl HardwareQubit {}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ConcatenationExpr {
     pub(crate) syntax: SyntaxNode,
 }
@@ -748,98 +1110,111 @@ impl ExpressionList {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ScalarType {
+pub struct ModifierList {
     pub(crate) syntax: SyntaxNode,
 }
-impl ScalarType {
-    pub fn bit_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![bit])
+impl ModifierList {
+    pub fn gate_modifiers(&self) -> AstChildren<GateModifier> {
+        support::children(&self.syntax)
     }
-    pub fn designator(&self) -> Option<Designator> {
-        support::child(&self.syntax)
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GateModifier {
+    pub(crate) syntax: SyntaxNode,
+}
+impl GateModifier {
+    pub fn inv_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![inv])
     }
-    pub fn int_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![int])
+    pub fn pow_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![pow])
     }
-    pub fn uint_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![uint])
+    pub fn ctrl_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![ctrl])
     }
-    pub fn float_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![float])
+    pub fn negctrl_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![negctrl])
     }
-    pub fn angle_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![angle])
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['('])
     }
-    pub fn bool_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![bool])
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
-    pub fn duration_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![duration])
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![')'])
     }
-    pub fn stretch_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![stretch])
+    pub fn at_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![@])
     }
-    pub fn complex_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![complex])
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ForIterable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ForIterable {
+    pub fn set_expression(&self) -> Option<SetExpression> {
+        support::child(&self.syntax)
     }
     pub fn l_brack_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T!['['])
     }
-    pub fn scalar_type(&self) -> Option<ScalarType> {
+    pub fn range(&self) -> Option<RangeExpr> {
         support::child(&self.syntax)
     }
     pub fn r_brack_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![']'])
     }
+    pub fn for_iterable_expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ArrayType {
+pub struct SetExpression {
     pub(crate) syntax: SyntaxNode,
 }
-impl ArrayType {
-    pub fn array_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![array])
-    }
-    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T!['['])
+impl SetExpression {
+    pub fn l_curly_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['{'])
     }
-    pub fn scalar_type(&self) -> Option<ScalarType> {
+    pub fn expression_list(&self) -> Option<ExpressionList> {
         support::child(&self.syntax)
     }
-    pub fn comma_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![,])
-    }
-    pub fn expression_list(&self) -> Option<ExpressionList> {
-        support::child(&self.syntax)
-    }
-    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![']'])
+    pub fn r_curly_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['}'])
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Designator {
+pub struct CaseItem {
     pub(crate) syntax: SyntaxNode,
 }
-impl Designator {
-    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T!['['])
+impl CaseItem {
+    pub fn case_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![case])
     }
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn expression_list(&self) -> Option<ExpressionList> {
         support::child(&self.syntax)
     }
-    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![']'])
+    pub fn default_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![default])
+    }
+    pub fn block_expr(&self) -> Option<BlockExpr> {
+        support::child(&self.syntax)
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct QubitType {
+pub struct DimExpr {
     pub(crate) syntax: SyntaxNode,
 }
-impl QubitType {
-    pub fn qubit_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![qubit])
+impl ast::HasName for DimExpr {}
+impl DimExpr {
+    pub fn pound_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![#])
     }
-    pub fn designator(&self) -> Option<Designator> {
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![=])
+    }
+    pub fn expr(&self) -> Option<Expr> {
         support::child(&self.syntax)
     }
 }
@@ -889,21 +1264,6 @@ pub struct IntNum {
 }
 impl IntNum {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SetExpression {
-    pub(crate) syntax: SyntaxNode,
-}
-impl SetExpression {
-    pub fn l_curly_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T!['{'])
-    }
-    pub fn expression_list(&self) -> Option<ExpressionList> {
-        support::child(&self.syntax)
-    }
-    pub fn r_curly_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T!['}'])
-    }
-}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ConstDeclarationStatement {
     pub(crate) syntax: SyntaxNode,
 }
@@ -926,55 +1286,17 @@ impl ConstDeclarationStatement {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct IODeclarationStatement {
-    pub(crate) syntax: SyntaxNode,
-}
-impl ast::HasName for IODeclarationStatement {}
-impl IODeclarationStatement {
-    pub fn input_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![input])
-    }
-    pub fn output_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![output])
-    }
-    pub fn scalar_type(&self) -> Option<ScalarType> {
-        support::child(&self.syntax)
-    }
-    pub fn array_type(&self) -> Option<ArrayType> {
-        support::child(&self.syntax)
-    }
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![;])
-    }
-}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct OldStyleDeclarationStatement {
-    pub(crate) syntax: SyntaxNode,
-}
-impl ast::HasName for OldStyleDeclarationStatement {}
-impl OldStyleDeclarationStatement {
-    pub fn creg_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![creg])
-    }
-    pub fn qreg_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![qreg])
-    }
-    pub fn designator(&self) -> Option<Designator> {
-        support::child(&self.syntax)
-    }
-    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![;])
-    }
-}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Item {
     Def(Def),
     Gate(Gate),
     DefCal(DefCal),
     Cal(Cal),
     DefCalGrammar(DefCalGrammar),
+    ExternItem(ExternItem),
     TypeDeclarationStmt(TypeDeclarationStmt),
     ClassicalDeclarationStatement(ClassicalDeclarationStatement),
+    IODeclarationStatement(IODeclarationStatement),
+    OldStyleDeclarationStatement(OldStyleDeclarationStatement),
     QuantumDeclarationStatement(QuantumDeclarationStatement),
     GateCallStmt(GateCallStmt),
     GPhaseCallStmt(GPhaseCallStmt),
@@ -984,20 +1306,31 @@ pub enum Item {
     ForStmt(ForStmt),
     IfStmt(IfStmt),
     WhileStmt(WhileStmt),
+    SwitchCaseStmt(SwitchCaseStmt),
     Reset(Reset),
-    Measure(Measure),
+    BoxStmt(BoxStmt),
+    DelayStmt(DelayStmt),
+    MeasureArrowAssignmentStmt(MeasureArrowAssignmentStmt),
     Barrier(Barrier),
     VersionString(VersionString),
     BreakStmt(BreakStmt),
     ContinueStmt(ContinueStmt),
     EndStmt(EndStmt),
+    AnnotatedStmt(AnnotatedStmt),
+    Pragma(Pragma),
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GateOperand {
+    Identifier(Identifier),
+    IndexedIdentifier(IndexedIdentifier),
+    HardwareQubit(HardwareQubit),
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Expr {
     ArrayExpr(ArrayExpr),
     BinExpr(BinExpr),
     BlockExpr(BlockExpr),
-    BoxExpr(BoxExpr),
+    DurationOfExpr(DurationOfExpr),
     CallExpr(CallExpr),
     CastExpression(CastExpression),
     IndexExpr(IndexExpr),
@@ -1010,12 +1343,7 @@ pub enum Expr {
     MeasureExpression(MeasureExpression),
     Identifier(Identifier),
     HardwareQubit(HardwareQubit),
-}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum GateOperand {
-    Identifier(Identifier),
-    IndexedIdentifier(IndexedIdentifier),
-    HardwareQubit(HardwareQubit),
+    PrefixExpr(PrefixExpr),
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Stmt {
@@ -1036,15 +1364,270 @@ impl ast::HasArgList for AnyHasArgList {}
 pub struct AnyHasModuleItem {
     pub(crate) syntax: SyntaxNode,
 }
-impl ast::HasModuleItem for AnyHasModuleItem {}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct AnyHasName {
-    pub(crate) syntax: SyntaxNode,
+impl ast::HasModuleItem for AnyHasModuleItem {}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnyHasName {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for AnyHasName {}
+impl AstNode for Name {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == NAME
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for NameRef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == NAME_REF
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeArg {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_ARG
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Type {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for SourceFile {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SOURCE_FILE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Def {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == DEF
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Gate {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == GATE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for DefCal {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == DEF_CAL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Cal {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CAL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for DefCalGrammar {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == DEF_CAL_GRAMMAR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ExternItem {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == EXTERN_ITEM
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeDeclarationStmt {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_DECLARATION_STMT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ClassicalDeclarationStatement {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CLASSICAL_DECLARATION_STATEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for IODeclarationStatement {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == I_O_DECLARATION_STATEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for OldStyleDeclarationStatement {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == OLD_STYLE_DECLARATION_STATEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for QuantumDeclarationStatement {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == QUANTUM_DECLARATION_STATEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for GateCallStmt {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == GATE_CALL_STMT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
 }
-impl ast::HasName for AnyHasName {}
-impl AstNode for Name {
+impl AstNode for GPhaseCallStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == NAME
+        kind == G_PHASE_CALL_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1057,9 +1640,9 @@ impl AstNode for Name {
         &self.syntax
     }
 }
-impl AstNode for NameRef {
+impl AstNode for LetStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == NAME_REF
+        kind == LET_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1072,9 +1655,9 @@ impl AstNode for NameRef {
         &self.syntax
     }
 }
-impl AstNode for TypeArg {
+impl AstNode for AssignmentStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_ARG
+        kind == ASSIGNMENT_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1087,9 +1670,9 @@ impl AstNode for TypeArg {
         &self.syntax
     }
 }
-impl AstNode for Type {
+impl AstNode for Include {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE
+        kind == INCLUDE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1102,9 +1685,9 @@ impl AstNode for Type {
         &self.syntax
     }
 }
-impl AstNode for SourceFile {
+impl AstNode for ForStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SOURCE_FILE
+        kind == FOR_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1117,9 +1700,9 @@ impl AstNode for SourceFile {
         &self.syntax
     }
 }
-impl AstNode for Def {
+impl AstNode for IfStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == DEF
+        kind == IF_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1132,9 +1715,9 @@ impl AstNode for Def {
         &self.syntax
     }
 }
-impl AstNode for Gate {
+impl AstNode for WhileStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == GATE
+        kind == WHILE_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1147,9 +1730,9 @@ impl AstNode for Gate {
         &self.syntax
     }
 }
-impl AstNode for DefCal {
+impl AstNode for SwitchCaseStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == DEF_CAL
+        kind == SWITCH_CASE_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1162,9 +1745,9 @@ impl AstNode for DefCal {
         &self.syntax
     }
 }
-impl AstNode for Cal {
+impl AstNode for Reset {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CAL
+        kind == RESET
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1177,9 +1760,9 @@ impl AstNode for Cal {
         &self.syntax
     }
 }
-impl AstNode for DefCalGrammar {
+impl AstNode for BoxStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == DEF_CAL_GRAMMAR
+        kind == BOX_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1192,9 +1775,9 @@ impl AstNode for DefCalGrammar {
         &self.syntax
     }
 }
-impl AstNode for TypeDeclarationStmt {
+impl AstNode for DelayStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_DECLARATION_STMT
+        kind == DELAY_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1207,9 +1790,9 @@ impl AstNode for TypeDeclarationStmt {
         &self.syntax
     }
 }
-impl AstNode for ClassicalDeclarationStatement {
+impl AstNode for MeasureArrowAssignmentStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CLASSICAL_DECLARATION_STATEMENT
+        kind == MEASURE_ARROW_ASSIGNMENT_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1222,9 +1805,9 @@ impl AstNode for ClassicalDeclarationStatement {
         &self.syntax
     }
 }
-impl AstNode for QuantumDeclarationStatement {
+impl AstNode for Barrier {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == QUANTUM_DECLARATION_STATEMENT
+        kind == BARRIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1237,9 +1820,9 @@ impl AstNode for QuantumDeclarationStatement {
         &self.syntax
     }
 }
-impl AstNode for GateCallStmt {
+impl AstNode for VersionString {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == GATE_CALL_STMT
+        kind == VERSION_STRING
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1252,9 +1835,9 @@ impl AstNode for GateCallStmt {
         &self.syntax
     }
 }
-impl AstNode for GPhaseCallStmt {
+impl AstNode for BreakStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == G_PHASE_CALL_STMT
+        kind == BREAK_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1267,9 +1850,9 @@ impl AstNode for GPhaseCallStmt {
         &self.syntax
     }
 }
-impl AstNode for LetStmt {
+impl AstNode for ContinueStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == LET_STMT
+        kind == CONTINUE_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1282,9 +1865,9 @@ impl AstNode for LetStmt {
         &self.syntax
     }
 }
-impl AstNode for AssignmentStmt {
+impl AstNode for EndStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == ASSIGNMENT_STMT
+        kind == END_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1297,9 +1880,9 @@ impl AstNode for AssignmentStmt {
         &self.syntax
     }
 }
-impl AstNode for Include {
+impl AstNode for AnnotatedStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == INCLUDE
+        kind == ANNOTATED_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1312,9 +1895,9 @@ impl AstNode for Include {
         &self.syntax
     }
 }
-impl AstNode for ForStmt {
+impl AstNode for Pragma {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == FOR_STMT
+        kind == PRAGMA
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1327,9 +1910,9 @@ impl AstNode for ForStmt {
         &self.syntax
     }
 }
-impl AstNode for IfStmt {
+impl AstNode for Version {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == IF_STMT
+        kind == VERSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1342,9 +1925,9 @@ impl AstNode for IfStmt {
         &self.syntax
     }
 }
-impl AstNode for WhileStmt {
+impl AstNode for Designator {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == WHILE_STMT
+        kind == DESIGNATOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1357,9 +1940,9 @@ impl AstNode for WhileStmt {
         &self.syntax
     }
 }
-impl AstNode for Reset {
+impl AstNode for BlockExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == RESET
+        kind == BLOCK_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1372,9 +1955,9 @@ impl AstNode for Reset {
         &self.syntax
     }
 }
-impl AstNode for Measure {
+impl AstNode for QubitList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MEASURE
+        kind == QUBIT_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1387,9 +1970,9 @@ impl AstNode for Measure {
         &self.syntax
     }
 }
-impl AstNode for Barrier {
+impl AstNode for MeasureExpression {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == BARRIER
+        kind == MEASURE_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1402,9 +1985,9 @@ impl AstNode for Barrier {
         &self.syntax
     }
 }
-impl AstNode for VersionString {
+impl AstNode for Identifier {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == VERSION_STRING
+        kind == IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1417,9 +2000,9 @@ impl AstNode for VersionString {
         &self.syntax
     }
 }
-impl AstNode for BreakStmt {
+impl AstNode for IndexedIdentifier {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == BREAK_STMT
+        kind == INDEXED_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1432,9 +2015,9 @@ impl AstNode for BreakStmt {
         &self.syntax
     }
 }
-impl AstNode for ContinueStmt {
+impl AstNode for CalibrationBlock {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CONTINUE_STMT
+        kind == CALIBRATION_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1447,9 +2030,9 @@ impl AstNode for ContinueStmt {
         &self.syntax
     }
 }
-impl AstNode for EndStmt {
+impl AstNode for FilePath {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == END_STMT
+        kind == FILE_PATH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1462,9 +2045,9 @@ impl AstNode for EndStmt {
         &self.syntax
     }
 }
-impl AstNode for Version {
+impl AstNode for TypeSpec {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == VERSION
+        kind == TYPE_SPEC
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1477,9 +2060,9 @@ impl AstNode for Version {
         &self.syntax
     }
 }
-impl AstNode for QubitList {
+impl AstNode for TypedParamList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == QUBIT_LIST
+        kind == TYPED_PARAM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1492,9 +2075,9 @@ impl AstNode for QubitList {
         &self.syntax
     }
 }
-impl AstNode for BlockExpr {
+impl AstNode for RetType {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == BLOCK_EXPR
+        kind == RET_TYPE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1507,9 +2090,9 @@ impl AstNode for BlockExpr {
         &self.syntax
     }
 }
-impl AstNode for FilePath {
+impl AstNode for DefCalParamList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == FILE_PATH
+        kind == DEF_CAL_PARAM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1522,9 +2105,9 @@ impl AstNode for FilePath {
         &self.syntax
     }
 }
-impl AstNode for TypeSpec {
+impl AstNode for TypedParam {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_SPEC
+        kind == TYPED_PARAM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1552,9 +2135,9 @@ impl AstNode for ParamList {
         &self.syntax
     }
 }
-impl AstNode for RetType {
+impl AstNode for Param {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == RET_TYPE
+        kind == PARAM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1567,9 +2150,9 @@ impl AstNode for RetType {
         &self.syntax
     }
 }
-impl AstNode for Param {
+impl AstNode for ScalarType {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == PARAM
+        kind == SCALAR_TYPE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1582,9 +2165,9 @@ impl AstNode for Param {
         &self.syntax
     }
 }
-impl AstNode for ExternItem {
+impl AstNode for QubitType {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == EXTERN_ITEM
+        kind == QUBIT_TYPE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1597,9 +2180,9 @@ impl AstNode for ExternItem {
         &self.syntax
     }
 }
-impl AstNode for ExprStmt {
+impl AstNode for ArrayType {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == EXPR_STMT
+        kind == ARRAY_TYPE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1612,9 +2195,9 @@ impl AstNode for ExprStmt {
         &self.syntax
     }
 }
-impl AstNode for ArrayExpr {
+impl AstNode for ExternParamList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == ARRAY_EXPR
+        kind == EXTERN_PARAM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1627,9 +2210,9 @@ impl AstNode for ArrayExpr {
         &self.syntax
     }
 }
-impl AstNode for BinExpr {
+impl AstNode for ExternParam {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == BIN_EXPR
+        kind == EXTERN_PARAM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1642,9 +2225,9 @@ impl AstNode for BinExpr {
         &self.syntax
     }
 }
-impl AstNode for BoxExpr {
+impl AstNode for ExprStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == BOX_EXPR
+        kind == EXPR_STMT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1657,9 +2240,9 @@ impl AstNode for BoxExpr {
         &self.syntax
     }
 }
-impl AstNode for CallExpr {
+impl AstNode for Annotation {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CALL_EXPR
+        kind == ANNOTATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1672,9 +2255,9 @@ impl AstNode for CallExpr {
         &self.syntax
     }
 }
-impl AstNode for CastExpression {
+impl AstNode for ArrayExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CAST_EXPRESSION
+        kind == ARRAY_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1687,9 +2270,9 @@ impl AstNode for CastExpression {
         &self.syntax
     }
 }
-impl AstNode for IndexExpr {
+impl AstNode for BinExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == INDEX_EXPR
+        kind == BIN_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1702,9 +2285,9 @@ impl AstNode for IndexExpr {
         &self.syntax
     }
 }
-impl AstNode for IndexedIdentifier {
+impl AstNode for DurationOfExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == INDEXED_IDENTIFIER
+        kind == DURATION_OF_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1717,9 +2300,9 @@ impl AstNode for IndexedIdentifier {
         &self.syntax
     }
 }
-impl AstNode for Literal {
+impl AstNode for CallExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == LITERAL
+        kind == CALL_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1732,9 +2315,9 @@ impl AstNode for Literal {
         &self.syntax
     }
 }
-impl AstNode for ParenExpr {
+impl AstNode for CastExpression {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == PAREN_EXPR
+        kind == CAST_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1747,9 +2330,9 @@ impl AstNode for ParenExpr {
         &self.syntax
     }
 }
-impl AstNode for RangeExpr {
+impl AstNode for IndexExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == RANGE_EXPR
+        kind == INDEX_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1762,9 +2345,9 @@ impl AstNode for RangeExpr {
         &self.syntax
     }
 }
-impl AstNode for ReturnExpr {
+impl AstNode for Literal {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == RETURN_EXPR
+        kind == LITERAL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1777,9 +2360,9 @@ impl AstNode for ReturnExpr {
         &self.syntax
     }
 }
-impl AstNode for ArrayLiteral {
+impl AstNode for ParenExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == ARRAY_LITERAL
+        kind == PAREN_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1792,9 +2375,9 @@ impl AstNode for ArrayLiteral {
         &self.syntax
     }
 }
-impl AstNode for MeasureExpression {
+impl AstNode for RangeExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MEASURE_EXPRESSION
+        kind == RANGE_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1807,9 +2390,24 @@ impl AstNode for MeasureExpression {
         &self.syntax
     }
 }
-impl AstNode for Identifier {
+impl AstNode for ReturnExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == IDENTIFIER
+        kind == RETURN_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ArrayLiteral {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ARRAY_LITERAL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1837,9 +2435,9 @@ impl AstNode for HardwareQubit {
         &self.syntax
     }
 }
-impl AstNode for ConcatenationExpr {
+impl AstNode for PrefixExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CONCATENATION_EXPR
+        kind == PREFIX_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1852,9 +2450,9 @@ impl AstNode for ConcatenationExpr {
         &self.syntax
     }
 }
-impl AstNode for IndexOperator {
+impl AstNode for ConcatenationExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == INDEX_OPERATOR
+        kind == CONCATENATION_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1867,9 +2465,9 @@ impl AstNode for IndexOperator {
         &self.syntax
     }
 }
-impl AstNode for ArgList {
+impl AstNode for IndexOperator {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == ARG_LIST
+        kind == INDEX_OPERATOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1882,9 +2480,9 @@ impl AstNode for ArgList {
         &self.syntax
     }
 }
-impl AstNode for ExpressionList {
+impl AstNode for ArgList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == EXPRESSION_LIST
+        kind == ARG_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1897,9 +2495,9 @@ impl AstNode for ExpressionList {
         &self.syntax
     }
 }
-impl AstNode for ScalarType {
+impl AstNode for ExpressionList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SCALAR_TYPE
+        kind == EXPRESSION_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1912,9 +2510,9 @@ impl AstNode for ScalarType {
         &self.syntax
     }
 }
-impl AstNode for ArrayType {
+impl AstNode for ModifierList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == ARRAY_TYPE
+        kind == MODIFIER_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1927,9 +2525,9 @@ impl AstNode for ArrayType {
         &self.syntax
     }
 }
-impl AstNode for Designator {
+impl AstNode for GateModifier {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == DESIGNATOR
+        kind == GATE_MODIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1942,9 +2540,9 @@ impl AstNode for Designator {
         &self.syntax
     }
 }
-impl AstNode for QubitType {
+impl AstNode for ForIterable {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == QUBIT_TYPE
+        kind == FOR_ITERABLE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1957,9 +2555,9 @@ impl AstNode for QubitType {
         &self.syntax
     }
 }
-impl AstNode for ReturnSignature {
+impl AstNode for SetExpression {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == RETURN_SIGNATURE
+        kind == SET_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1972,9 +2570,9 @@ impl AstNode for ReturnSignature {
         &self.syntax
     }
 }
-impl AstNode for AliasDeclarationStatement {
+impl AstNode for CaseItem {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == ALIAS_DECLARATION_STATEMENT
+        kind == CASE_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1987,9 +2585,9 @@ impl AstNode for AliasDeclarationStatement {
         &self.syntax
     }
 }
-impl AstNode for AliasExpression {
+impl AstNode for DimExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == ALIAS_EXPRESSION
+        kind == DIM_EXPR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2002,9 +2600,9 @@ impl AstNode for AliasExpression {
         &self.syntax
     }
 }
-impl AstNode for IntNum {
+impl AstNode for ReturnSignature {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == INT_NUM
+        kind == RETURN_SIGNATURE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2017,9 +2615,9 @@ impl AstNode for IntNum {
         &self.syntax
     }
 }
-impl AstNode for SetExpression {
+impl AstNode for AliasDeclarationStatement {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == SET_EXPRESSION
+        kind == ALIAS_DECLARATION_STATEMENT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2032,9 +2630,9 @@ impl AstNode for SetExpression {
         &self.syntax
     }
 }
-impl AstNode for ConstDeclarationStatement {
+impl AstNode for AliasExpression {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CONST_DECLARATION_STATEMENT
+        kind == ALIAS_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2047,9 +2645,9 @@ impl AstNode for ConstDeclarationStatement {
         &self.syntax
     }
 }
-impl AstNode for IODeclarationStatement {
+impl AstNode for IntNum {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == I_O_DECLARATION_STATEMENT
+        kind == INT_NUM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2062,9 +2660,9 @@ impl AstNode for IODeclarationStatement {
         &self.syntax
     }
 }
-impl AstNode for OldStyleDeclarationStatement {
+impl AstNode for ConstDeclarationStatement {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == OLD_STYLE_DECLARATION_STATEMENT
+        kind == CONST_DECLARATION_STATEMENT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2102,6 +2700,11 @@ impl From<DefCalGrammar> for Item {
         Item::DefCalGrammar(node)
     }
 }
+impl From<ExternItem> for Item {
+    fn from(node: ExternItem) -> Item {
+        Item::ExternItem(node)
+    }
+}
 impl From<TypeDeclarationStmt> for Item {
     fn from(node: TypeDeclarationStmt) -> Item {
         Item::TypeDeclarationStmt(node)
@@ -2112,6 +2715,16 @@ impl From<ClassicalDeclarationStatement> for Item {
         Item::ClassicalDeclarationStatement(node)
     }
 }
+impl From<IODeclarationStatement> for Item {
+    fn from(node: IODeclarationStatement) -> Item {
+        Item::IODeclarationStatement(node)
+    }
+}
+impl From<OldStyleDeclarationStatement> for Item {
+    fn from(node: OldStyleDeclarationStatement) -> Item {
+        Item::OldStyleDeclarationStatement(node)
+    }
+}
 impl From<QuantumDeclarationStatement> for Item {
     fn from(node: QuantumDeclarationStatement) -> Item {
         Item::QuantumDeclarationStatement(node)
@@ -2157,14 +2770,29 @@ impl From<WhileStmt> for Item {
         Item::WhileStmt(node)
     }
 }
+impl From<SwitchCaseStmt> for Item {
+    fn from(node: SwitchCaseStmt) -> Item {
+        Item::SwitchCaseStmt(node)
+    }
+}
 impl From<Reset> for Item {
     fn from(node: Reset) -> Item {
         Item::Reset(node)
     }
 }
-impl From<Measure> for Item {
-    fn from(node: Measure) -> Item {
-        Item::Measure(node)
+impl From<BoxStmt> for Item {
+    fn from(node: BoxStmt) -> Item {
+        Item::BoxStmt(node)
+    }
+}
+impl From<DelayStmt> for Item {
+    fn from(node: DelayStmt) -> Item {
+        Item::DelayStmt(node)
+    }
+}
+impl From<MeasureArrowAssignmentStmt> for Item {
+    fn from(node: MeasureArrowAssignmentStmt) -> Item {
+        Item::MeasureArrowAssignmentStmt(node)
     }
 }
 impl From<Barrier> for Item {
@@ -2192,6 +2820,16 @@ impl From<EndStmt> for Item {
         Item::EndStmt(node)
     }
 }
+impl From<AnnotatedStmt> for Item {
+    fn from(node: AnnotatedStmt) -> Item {
+        Item::AnnotatedStmt(node)
+    }
+}
+impl From<Pragma> for Item {
+    fn from(node: Pragma) -> Item {
+        Item::Pragma(node)
+    }
+}
 impl AstNode for Item {
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
@@ -2200,8 +2838,11 @@ impl AstNode for Item {
                 | DEF_CAL
                 | CAL
                 | DEF_CAL_GRAMMAR
+                | EXTERN_ITEM
                 | TYPE_DECLARATION_STMT
                 | CLASSICAL_DECLARATION_STATEMENT
+                | I_O_DECLARATION_STATEMENT
+                | OLD_STYLE_DECLARATION_STATEMENT
                 | QUANTUM_DECLARATION_STATEMENT
                 | GATE_CALL_STMT
                 | G_PHASE_CALL_STMT
@@ -2211,13 +2852,18 @@ impl AstNode for Item {
                 | FOR_STMT
                 | IF_STMT
                 | WHILE_STMT
+                | SWITCH_CASE_STMT
                 | RESET
-                | MEASURE
+                | BOX_STMT
+                | DELAY_STMT
+                | MEASURE_ARROW_ASSIGNMENT_STMT
                 | BARRIER
                 | VERSION_STRING
                 | BREAK_STMT
                 | CONTINUE_STMT
                 | END_STMT
+                | ANNOTATED_STMT
+                | PRAGMA
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -2227,10 +2873,17 @@ impl AstNode for Item {
             DEF_CAL => Item::DefCal(DefCal { syntax }),
             CAL => Item::Cal(Cal { syntax }),
             DEF_CAL_GRAMMAR => Item::DefCalGrammar(DefCalGrammar { syntax }),
+            EXTERN_ITEM => Item::ExternItem(ExternItem { syntax }),
             TYPE_DECLARATION_STMT => Item::TypeDeclarationStmt(TypeDeclarationStmt { syntax }),
             CLASSICAL_DECLARATION_STATEMENT => {
                 Item::ClassicalDeclarationStatement(ClassicalDeclarationStatement { syntax })
             }
+            I_O_DECLARATION_STATEMENT => {
+                Item::IODeclarationStatement(IODeclarationStatement { syntax })
+            }
+            OLD_STYLE_DECLARATION_STATEMENT => {
+                Item::OldStyleDeclarationStatement(OldStyleDeclarationStatement { syntax })
+            }
             QUANTUM_DECLARATION_STATEMENT => {
                 Item::QuantumDeclarationStatement(QuantumDeclarationStatement { syntax })
             }
@@ -2242,13 +2895,20 @@ impl AstNode for Item {
             FOR_STMT => Item::ForStmt(ForStmt { syntax }),
             IF_STMT => Item::IfStmt(IfStmt { syntax }),
             WHILE_STMT => Item::WhileStmt(WhileStmt { syntax }),
+            SWITCH_CASE_STMT => Item::SwitchCaseStmt(SwitchCaseStmt { syntax }),
             RESET => Item::Reset(Reset { syntax }),
-            MEASURE => Item::Measure(Measure { syntax }),
+            BOX_STMT => Item::BoxStmt(BoxStmt { syntax }),
+            DELAY_STMT => Item::DelayStmt(DelayStmt { syntax }),
+            MEASURE_ARROW_ASSIGNMENT_STMT => {
+                Item::MeasureArrowAssignmentStmt(MeasureArrowAssignmentStmt { syntax })
+            }
             BARRIER => Item::Barrier(Barrier { syntax }),
             VERSION_STRING => Item::VersionString(VersionString { syntax }),
             BREAK_STMT => Item::BreakStmt(BreakStmt { syntax }),
             CONTINUE_STMT => Item::ContinueStmt(ContinueStmt { syntax }),
             END_STMT => Item::EndStmt(EndStmt { syntax }),
+            ANNOTATED_STMT => Item::AnnotatedStmt(AnnotatedStmt { syntax }),
+            PRAGMA => Item::Pragma(Pragma { syntax }),
             _ => return None,
         };
         Some(res)
@@ -2260,8 +2920,11 @@ impl AstNode for Item {
             Item::DefCal(it) => &it.syntax,
             Item::Cal(it) => &it.syntax,
             Item::DefCalGrammar(it) => &it.syntax,
+            Item::ExternItem(it) => &it.syntax,
             Item::TypeDeclarationStmt(it) => &it.syntax,
             Item::ClassicalDeclarationStatement(it) => &it.syntax,
+            Item::IODeclarationStatement(it) => &it.syntax,
+            Item::OldStyleDeclarationStatement(it) => &it.syntax,
             Item::QuantumDeclarationStatement(it) => &it.syntax,
             Item::GateCallStmt(it) => &it.syntax,
             Item::GPhaseCallStmt(it) => &it.syntax,
@@ -2271,13 +2934,54 @@ impl AstNode for Item {
             Item::ForStmt(it) => &it.syntax,
             Item::IfStmt(it) => &it.syntax,
             Item::WhileStmt(it) => &it.syntax,
+            Item::SwitchCaseStmt(it) => &it.syntax,
             Item::Reset(it) => &it.syntax,
-            Item::Measure(it) => &it.syntax,
+            Item::BoxStmt(it) => &it.syntax,
+            Item::DelayStmt(it) => &it.syntax,
+            Item::MeasureArrowAssignmentStmt(it) => &it.syntax,
             Item::Barrier(it) => &it.syntax,
             Item::VersionString(it) => &it.syntax,
             Item::BreakStmt(it) => &it.syntax,
             Item::ContinueStmt(it) => &it.syntax,
             Item::EndStmt(it) => &it.syntax,
+            Item::AnnotatedStmt(it) => &it.syntax,
+            Item::Pragma(it) => &it.syntax,
+        }
+    }
+}
+impl From<Identifier> for GateOperand {
+    fn from(node: Identifier) -> GateOperand {
+        GateOperand::Identifier(node)
+    }
+}
+impl From<IndexedIdentifier> for GateOperand {
+    fn from(node: IndexedIdentifier) -> GateOperand {
+        GateOperand::IndexedIdentifier(node)
+    }
+}
+impl From<HardwareQubit> for GateOperand {
+    fn from(node: HardwareQubit) -> GateOperand {
+        GateOperand::HardwareQubit(node)
+    }
+}
+impl AstNode for GateOperand {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, IDENTIFIER | INDEXED_IDENTIFIER | HARDWARE_QUBIT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            IDENTIFIER => GateOperand::Identifier(Identifier { syntax }),
+            INDEXED_IDENTIFIER => GateOperand::IndexedIdentifier(IndexedIdentifier { syntax }),
+            HARDWARE_QUBIT => GateOperand::HardwareQubit(HardwareQubit { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            GateOperand::Identifier(it) => &it.syntax,
+            GateOperand::IndexedIdentifier(it) => &it.syntax,
+            GateOperand::HardwareQubit(it) => &it.syntax,
         }
     }
 }
@@ -2296,9 +3000,9 @@ impl From<BlockExpr> for Expr {
         Expr::BlockExpr(node)
     }
 }
-impl From<BoxExpr> for Expr {
-    fn from(node: BoxExpr) -> Expr {
-        Expr::BoxExpr(node)
+impl From<DurationOfExpr> for Expr {
+    fn from(node: DurationOfExpr) -> Expr {
+        Expr::DurationOfExpr(node)
     }
 }
 impl From<CallExpr> for Expr {
@@ -2361,6 +3065,11 @@ impl From<HardwareQubit> for Expr {
         Expr::HardwareQubit(node)
     }
 }
+impl From<PrefixExpr> for Expr {
+    fn from(node: PrefixExpr) -> Expr {
+        Expr::PrefixExpr(node)
+    }
+}
 impl AstNode for Expr {
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
@@ -2368,7 +3077,7 @@ impl AstNode for Expr {
             ARRAY_EXPR
                 | BIN_EXPR
                 | BLOCK_EXPR
-                | BOX_EXPR
+                | DURATION_OF_EXPR
                 | CALL_EXPR
                 | CAST_EXPRESSION
                 | INDEX_EXPR
@@ -2381,6 +3090,7 @@ impl AstNode for Expr {
                 | MEASURE_EXPRESSION
                 | IDENTIFIER
                 | HARDWARE_QUBIT
+                | PREFIX_EXPR
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -2388,7 +3098,7 @@ impl AstNode for Expr {
             ARRAY_EXPR => Expr::ArrayExpr(ArrayExpr { syntax }),
             BIN_EXPR => Expr::BinExpr(BinExpr { syntax }),
             BLOCK_EXPR => Expr::BlockExpr(BlockExpr { syntax }),
-            BOX_EXPR => Expr::BoxExpr(BoxExpr { syntax }),
+            DURATION_OF_EXPR => Expr::DurationOfExpr(DurationOfExpr { syntax }),
             CALL_EXPR => Expr::CallExpr(CallExpr { syntax }),
             CAST_EXPRESSION => Expr::CastExpression(CastExpression { syntax }),
             INDEX_EXPR => Expr::IndexExpr(IndexExpr { syntax }),
@@ -2401,6 +3111,7 @@ impl AstNode for Expr {
             MEASURE_EXPRESSION => Expr::MeasureExpression(MeasureExpression { syntax }),
             IDENTIFIER => Expr::Identifier(Identifier { syntax }),
             HARDWARE_QUBIT => Expr::HardwareQubit(HardwareQubit { syntax }),
+            PREFIX_EXPR => Expr::PrefixExpr(PrefixExpr { syntax }),
             _ => return None,
         };
         Some(res)
@@ -2410,7 +3121,7 @@ impl AstNode for Expr {
             Expr::ArrayExpr(it) => &it.syntax,
             Expr::BinExpr(it) => &it.syntax,
             Expr::BlockExpr(it) => &it.syntax,
-            Expr::BoxExpr(it) => &it.syntax,
+            Expr::DurationOfExpr(it) => &it.syntax,
             Expr::CallExpr(it) => &it.syntax,
             Expr::CastExpression(it) => &it.syntax,
             Expr::IndexExpr(it) => &it.syntax,
@@ -2423,42 +3134,7 @@ impl AstNode for Expr {
             Expr::MeasureExpression(it) => &it.syntax,
             Expr::Identifier(it) => &it.syntax,
             Expr::HardwareQubit(it) => &it.syntax,
-        }
-    }
-}
-impl From<Identifier> for GateOperand {
-    fn from(node: Identifier) -> GateOperand {
-        GateOperand::Identifier(node)
-    }
-}
-impl From<IndexedIdentifier> for GateOperand {
-    fn from(node: IndexedIdentifier) -> GateOperand {
-        GateOperand::IndexedIdentifier(node)
-    }
-}
-impl From<HardwareQubit> for GateOperand {
-    fn from(node: HardwareQubit) -> GateOperand {
-        GateOperand::HardwareQubit(node)
-    }
-}
-impl AstNode for GateOperand {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, IDENTIFIER | INDEXED_IDENTIFIER | HARDWARE_QUBIT)
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            IDENTIFIER => GateOperand::Identifier(Identifier { syntax }),
-            INDEXED_IDENTIFIER => GateOperand::IndexedIdentifier(IndexedIdentifier { syntax }),
-            HARDWARE_QUBIT => GateOperand::HardwareQubit(HardwareQubit { syntax }),
-            _ => return None,
-        };
-        Some(res)
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        match self {
-            GateOperand::Identifier(it) => &it.syntax,
-            GateOperand::IndexedIdentifier(it) => &it.syntax,
-            GateOperand::HardwareQubit(it) => &it.syntax,
+            Expr::PrefixExpr(it) => &it.syntax,
         }
     }
 }
@@ -2547,21 +3223,23 @@ impl AstNode for AnyHasName {
             kind,
             DEF | GATE
                 | DEF_CAL
+                | EXTERN_ITEM
                 | TYPE_DECLARATION_STMT
                 | CLASSICAL_DECLARATION_STATEMENT
+                | I_O_DECLARATION_STATEMENT
+                | OLD_STYLE_DECLARATION_STATEMENT
                 | QUANTUM_DECLARATION_STATEMENT
                 | GATE_CALL_STMT
                 | LET_STMT
                 | ASSIGNMENT_STMT
+                | INDEXED_IDENTIFIER
                 | TYPE_SPEC
+                | TYPED_PARAM
                 | PARAM
-                | EXTERN_ITEM
-                | INDEXED_IDENTIFIER
                 | HARDWARE_QUBIT
+                | DIM_EXPR
                 | ALIAS_DECLARATION_STATEMENT
                 | CONST_DECLARATION_STATEMENT
-                | I_O_DECLARATION_STATEMENT
-                | OLD_STYLE_DECLARATION_STATEMENT
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -2576,12 +3254,12 @@ impl std::fmt::Display for Item {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for Expr {
+impl std::fmt::Display for GateOperand {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for GateOperand {
+impl std::fmt::Display for Expr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2646,6 +3324,11 @@ impl std::fmt::Display for DefCalGrammar {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for ExternItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for TypeDeclarationStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -2656,6 +3339,16 @@ impl std::fmt::Display for ClassicalDeclarationStatement {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for IODeclarationStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for OldStyleDeclarationStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for QuantumDeclarationStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -2701,12 +3394,27 @@ impl std::fmt::Display for WhileStmt {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for SwitchCaseStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for Reset {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for Measure {
+impl std::fmt::Display for BoxStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for DelayStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MeasureArrowAssignmentStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2736,12 +3444,22 @@ impl std::fmt::Display for EndStmt {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AnnotatedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for Pragma {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for Version {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for QubitList {
+impl std::fmt::Display for Designator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2751,6 +3469,31 @@ impl std::fmt::Display for BlockExpr {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for QubitList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MeasureExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for Identifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for IndexedIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CalibrationBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for FilePath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -2761,7 +3504,7 @@ impl std::fmt::Display for TypeSpec {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ParamList {
+impl std::fmt::Display for TypedParamList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2771,12 +3514,47 @@ impl std::fmt::Display for RetType {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for DefCalParamList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TypedParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ParamList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for Param {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ExternItem {
+impl std::fmt::Display for ScalarType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for QubitType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ArrayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ExternParamList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ExternParam {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2786,6 +3564,11 @@ impl std::fmt::Display for ExprStmt {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for Annotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for ArrayExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -2796,7 +3579,7 @@ impl std::fmt::Display for BinExpr {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for BoxExpr {
+impl std::fmt::Display for DurationOfExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2816,11 +3599,6 @@ impl std::fmt::Display for IndexExpr {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for IndexedIdentifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
-    }
-}
 impl std::fmt::Display for Literal {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -2846,17 +3624,12 @@ impl std::fmt::Display for ArrayLiteral {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MeasureExpression {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
-    }
-}
-impl std::fmt::Display for Identifier {
+impl std::fmt::Display for HardwareQubit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for HardwareQubit {
+impl std::fmt::Display for PrefixExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2881,62 +3654,57 @@ impl std::fmt::Display for ExpressionList {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ScalarType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
-    }
-}
-impl std::fmt::Display for ArrayType {
+impl std::fmt::Display for ModifierList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for Designator {
+impl std::fmt::Display for GateModifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for QubitType {
+impl std::fmt::Display for ForIterable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ReturnSignature {
+impl std::fmt::Display for SetExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AliasDeclarationStatement {
+impl std::fmt::Display for CaseItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AliasExpression {
+impl std::fmt::Display for DimExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for IntNum {
+impl std::fmt::Display for ReturnSignature {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for SetExpression {
+impl std::fmt::Display for AliasDeclarationStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ConstDeclarationStatement {
+impl std::fmt::Display for AliasExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for IODeclarationStatement {
+impl std::fmt::Display for IntNum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for OldStyleDeclarationStatement {
+impl std::fmt::Display for ConstDeclarationStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
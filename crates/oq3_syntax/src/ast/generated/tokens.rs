@@ -298,3 +298,28 @@ impl AstToken for BitString {
         &self.syntax
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImaginaryNumber {
+    pub(crate) syntax: SyntaxToken,
+}
+impl std::fmt::Display for ImaginaryNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.syntax, f)
+    }
+}
+impl AstToken for ImaginaryNumber {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == IMAGINARY_NUMBER
+    }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken {
+        &self.syntax
+    }
+}
@@ -99,25 +99,10 @@ fn text_of_first_token(node: &SyntaxNode) -> TokenText<'_> {
 //     }
 // }
 
-impl ast::ForStmt {
-    pub fn iterable(&self) -> Option<ast::Expr> {
-        // If the iterable is a BlockExpr, check if the body is missing.
-        // If it is assume the iterable is the expression that is missing instead.
-        let mut exprs = support::children(self.syntax());
-        let first = exprs.next();
-        match first {
-            Some(ast::Expr::BlockExpr(_)) => exprs.next().and(first),
-            first => first,
-        }
-    }
-}
-
+// The iterable is wrapped in `ForIterable`, so the loop body is the only `BlockExpr` child.
 impl ast::HasLoopBody for ast::ForStmt {
     fn loop_body(&self) -> Option<ast::BlockExpr> {
-        let mut exprs = support::children(self.syntax());
-        let first = exprs.next();
-        let second = exprs.next();
-        second.or(first)
+        support::child(self.syntax())
     }
 }
 
@@ -150,3 +135,65 @@ impl ast::HasLoopBody for ast::WhileStmt {
         second.or(first)
     }
 }
+
+// The lexer produces an annotation, including the rest of its line, as a single token.
+impl ast::Annotation {
+    /// The keyword without the leading `@`. For example `bind` in `@bind $0`.
+    pub fn keyword(&self) -> String {
+        let text = text_of_first_token(self.syntax());
+        let text = text.trim_start_matches('@');
+        text.split_whitespace().next().unwrap_or("").to_string()
+    }
+
+    /// The text following the keyword, or `None` if there is none.
+    pub fn body(&self) -> Option<String> {
+        let text = text_of_first_token(self.syntax());
+        let body = text
+            .trim_start_matches('@')
+            .trim_start_matches(|c: char| !c.is_whitespace())
+            .trim();
+        (!body.is_empty()).then(|| body.to_string())
+    }
+}
+
+impl ast::Pragma {
+    /// The text following `pragma` or `#pragma`.
+    pub fn content(&self) -> String {
+        let text = text_of_first_token(self.syntax());
+        text.trim_start_matches('#')
+            .trim_start_matches("pragma")
+            .trim()
+            .to_string()
+    }
+}
+
+impl ast::CalibrationBlock {
+    /// The unparsed text between the outer braces.
+    pub fn contents(&self) -> String {
+        let text = self.syntax().text().to_string();
+        let text = text.strip_prefix('{').unwrap_or(&text);
+        text.strip_suffix('}').unwrap_or(text).to_string()
+    }
+}
+
+/// A parameter of a `defcal`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DefCalParam {
+    /// For example `angle[20] theta`.
+    Typed(ast::TypedParam),
+    /// For example `pi / 2`.
+    Constant(ast::Expr),
+}
+
+impl ast::DefCalParamList {
+    /// The parameters in the order in which they appear.
+    pub fn params(&self) -> impl Iterator<Item = DefCalParam> {
+        self.syntax().children().filter_map(|node| {
+            if let Some(typed_param) = ast::TypedParam::cast(node.clone()) {
+                Some(DefCalParam::Typed(typed_param))
+            } else {
+                ast::Expr::cast(node).map(DefCalParam::Constant)
+            }
+        })
+    }
+}
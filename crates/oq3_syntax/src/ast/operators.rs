@@ -17,8 +17,8 @@ pub enum RangeOp {
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum UnaryOp {
-    /// `*`
-    Deref,
+    /// `~`
+    BitNot,
     /// `!`
     Not,
     /// `-`
@@ -30,6 +30,7 @@ pub enum BinaryOp {
     LogicOp(LogicOp),
     ArithOp(ArithOp),
     CmpOp(CmpOp),
+    ConcatenationOp,
     Assignment { op: Option<ArithOp> },
 }
 
@@ -58,6 +59,7 @@ pub enum ArithOp {
     Sub,
     Div,
     Rem,
+    Pow,
     Shl,
     Shr,
     BitXor,
@@ -65,6 +67,17 @@ pub enum ArithOp {
     BitAnd,
 }
 
+impl fmt::Display for UnaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let res = match self {
+            UnaryOp::BitNot => "~",
+            UnaryOp::Not => "!",
+            UnaryOp::Neg => "-",
+        };
+        f.write_str(res)
+    }
+}
+
 impl fmt::Display for LogicOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let res = match self {
@@ -83,6 +96,7 @@ impl fmt::Display for ArithOp {
             ArithOp::Sub => "-",
             ArithOp::Div => "/",
             ArithOp::Rem => "%",
+            ArithOp::Pow => "**",
             ArithOp::Shl => "<<",
             ArithOp::Shr => ">>",
             ArithOp::BitXor => "^",
@@ -125,6 +139,7 @@ impl fmt::Display for BinaryOp {
             BinaryOp::LogicOp(op) => fmt::Display::fmt(op, f),
             BinaryOp::ArithOp(op) => fmt::Display::fmt(op, f),
             BinaryOp::CmpOp(op) => fmt::Display::fmt(op, f),
+            BinaryOp::ConcatenationOp => f.write_str("++"),
             BinaryOp::Assignment { op } => {
                 if let Some(op) = op {
                     fmt::Display::fmt(op, f)?;
@@ -117,6 +117,7 @@ impl Expr {
                 let Some(op) = e.op_kind() else { return (0, 0) };
                 match op {
                     Assignment { .. } => (4, 3),
+                    ConcatenationOp => (5, 6),
                     //
                     // Ranges are here in order :)
                     //
@@ -132,15 +133,16 @@ impl Expr {
                         Shl | Shr => (19, 20),
                         Add | Sub => (21, 22),
                         Mul | Div | Rem => (23, 24),
+                        Pow => (28, 27),
                     },
                 }
             }
+            PrefixExpr(_) => (0, 25),
             ArrayLiteral(_) => (0, 0), // These need to be checked
             MeasureExpression(_) => (0, 0),
-            BoxExpr(_) => (0, 27),
             CallExpr(_) | CastExpression(_) | IndexExpr(_) | IndexedIdentifier(_) => (29, 0),
             ArrayExpr(_) | Literal(_) | ParenExpr(_) | Identifier(_) | HardwareQubit(_)
-            | BlockExpr(_) => (0, 0),
+            | BlockExpr(_) | DurationOfExpr(_) => (0, 0),
         }
     }
 
@@ -183,7 +185,8 @@ impl Expr {
             let token = match this {
                 RangeExpr(_) => None,
                 BinExpr(e) => e.op_token(),
-                BoxExpr(e) => e.box_token(),
+                PrefixExpr(e) => e.op_token(),
+                DurationOfExpr(e) => e.durationof_token(),
                 CallExpr(e) => e.arg_list().and_then(|args| args.l_paren_token()),
                 CastExpression(e) => e.l_paren_token(),
                 //                IndexExpr(e) => e.l_brack_token(),
@@ -209,10 +212,15 @@ impl Expr {
         match self {
             ArrayExpr(_) | BlockExpr(_) | CallExpr(_) | CastExpression(_) | IndexExpr(_)
             | IndexedIdentifier(_) | Literal(_) | Identifier(_) | HardwareQubit(_)
-            | ParenExpr(_) => false,
+            | ParenExpr(_) | DurationOfExpr(_) => false,
+
+            PrefixExpr(e) => e
+                .expr()
+                .map(|e| e.child_is_followed_by_a_block())
+                .unwrap_or(false),
 
             // For BinExpr and RangeExpr this is technically wrong -- the child can be on the left...
-            BinExpr(_) | RangeExpr(_) | BoxExpr(_) | ReturnExpr(_) => self
+            BinExpr(_) | RangeExpr(_) | ReturnExpr(_) => self
                 .syntax()
                 .parent()
                 .and_then(Expr::cast)
@@ -371,44 +371,64 @@ impl ast::FloatNumber {
     }
 }
 
-// impl ast::TimingFloatNumber {
-//     pub fn split_into_parts(&self) -> (&str, &str) {
-//         let text = self.text();
-//         let mut float_text = self.text();
-//         let mut suffix = "";
-//         let mut indices = text.char_indices();
-//         if let Some((mut suffix_start, c)) = indices.by_ref().find(|(_, c)| c.is_ascii_alphabetic())
-//         {
-//             if c == 'e' || c == 'E' {
-//                 if let Some(suffix_start_tuple) = indices.find(|(_, c)| c.is_ascii_alphabetic()) {
-//                     suffix_start = suffix_start_tuple.0;
-
-//                     float_text = &text[..suffix_start];
-//                     suffix = &text[suffix_start..];
-//                 }
-//             } else {
-//                 float_text = &text[..suffix_start];
-//                 suffix = &text[suffix_start..];
-//             }
-//         }
-
-//         (float_text, suffix)
-//     }
-
-//     pub fn suffix(&self) -> Option<&str> {
-//         let (_, suffix) = self.split_into_parts();
-//         if suffix.is_empty() {
-//             None
-//         } else {
-//             Some(suffix)
-//         }
-//     }
-
-//     pub fn value(&self) -> Option<f64> {
-//         let (text, _) = self.split_into_parts();
-//         text.replace('_', "").parse::<f64>().ok()
-//     }
-// }
+/// Split a timing literal such as `100ns` or `1.5e3dt` into its numeric part and
+/// its unit suffix.
+fn split_timing_literal(text: &str) -> (&str, &str) {
+    for suffix in ["dt", "ns", "us", "µs", "ms", "s"] {
+        if let Some(num) = text.strip_suffix(suffix) {
+            return (num, suffix);
+        }
+    }
+    (text, "")
+}
+
+impl ast::TimingIntNumber {
+    pub fn split_into_parts(&self) -> (&str, &str) {
+        split_timing_literal(self.text())
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        let (_, suffix) = self.split_into_parts();
+        if suffix.is_empty() {
+            None
+        } else {
+            Some(suffix)
+        }
+    }
+
+    pub fn value(&self) -> Option<u128> {
+        let (text, _) = self.split_into_parts();
+        text.replace('_', "").parse::<u128>().ok()
+    }
+}
+
+impl ast::TimingFloatNumber {
+    pub fn split_into_parts(&self) -> (&str, &str) {
+        split_timing_literal(self.text())
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        let (_, suffix) = self.split_into_parts();
+        if suffix.is_empty() {
+            None
+        } else {
+            Some(suffix)
+        }
+    }
+
+    pub fn value(&self) -> Option<f64> {
+        let (text, _) = self.split_into_parts();
+        text.replace('_', "").parse::<f64>().ok()
+    }
+}
+
+impl ast::ImaginaryNumber {
+    /// Return the number before the suffix `im`, which is the imaginary part of the literal.
+    pub fn value(&self) -> Option<f64> {
+        let text = self.text().strip_suffix("im")?.trim_end();
+        text.replace('_', "").parse::<f64>().ok()
+    }
+}
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum Radix {
@@ -436,7 +456,7 @@ impl Radix {
 
 #[cfg(test)]
 mod tests {
-    use crate::ast::{self, make, FloatNumber, IntNumber};
+    use crate::ast::{self, make, FloatNumber, IntNumber, TimingFloatNumber, TimingIntNumber};
 
     fn check_float_suffix<'a>(lit: &str, expected: impl Into<Option<&'a str>>) {
         assert_eq!(
@@ -496,6 +516,32 @@ mod tests {
         check_float_suffix("1_2_3.0_f32", "f32");
     }
 
+    fn check_timing_float<'a>(lit: &str, value: f64, suffix: impl Into<Option<&'a str>>) {
+        let token = TimingFloatNumber {
+            syntax: make::tokens::literal(lit),
+        };
+        assert_eq!(token.value(), Some(value));
+        assert_eq!(token.suffix(), suffix.into());
+    }
+
+    fn check_timing_int<'a>(lit: &str, value: u128, suffix: impl Into<Option<&'a str>>) {
+        let token = TimingIntNumber {
+            syntax: make::tokens::literal(lit),
+        };
+        assert_eq!(token.value(), Some(value));
+        assert_eq!(token.suffix(), suffix.into());
+    }
+
+    #[test]
+    fn test_timing_number_suffix() {
+        check_timing_int("100ns", 100, "ns");
+        check_timing_int("1_000dt", 1000, "dt");
+        check_timing_int("3s", 3, "s");
+        check_timing_float("1.5us", 1.5, "us");
+        check_timing_float("2.0µs", 2.0, "µs");
+        check_timing_float("1e3ms", 1000.0, "ms");
+    }
+
     #[test]
     fn test_int_number_suffix() {
         check_int_suffix("123", None);
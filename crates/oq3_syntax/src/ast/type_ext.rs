@@ -27,6 +27,7 @@ impl ast::ScalarType {
             T![bit] => ScalarTypeKind::Bit,
             T![bool] => ScalarTypeKind::Bool,
             T![angle] => ScalarTypeKind::Angle,
+            T![duration] => Duration,
             T![stretch] => Stretch,
             T![complex] => Complex,
             _ => ScalarTypeKind::None, // record an error ?
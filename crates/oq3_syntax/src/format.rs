@@ -0,0 +1,416 @@
+// Copyright contributors to the openqasm-parser project
+// SPDX-License-Identifier: Apache-2.0
+
+//! Source formatter for OpenQASM 3.
+//!
+//! The formatter works on the lossless syntax tree and only ever changes whitespace. The
+//! text of every other token, including comments, is left as it is. The whitespace between
+//! each pair of adjacent tokens is decided from the tokens and their place in the tree:
+//!
+//! * Each statement starts on a new line. A block is opened with `{` on the line of the
+//!   statement that owns it, even if it was on a line of its own in the source, and closed
+//!   with `}` on a line of its own, except that an empty block is written `{}` and `else`
+//!   follows `}` on the same line.
+//! * Lines are indented four spaces for each enclosing block. A statement that is broken
+//!   across lines is indented one more level after its first line.
+//! * Blank lines between lines are kept, but several blank lines are merged into one, and
+//!   blank lines at the start of a file or directly after `{` or before `}` are removed.
+//! * Binary and assignment operators, `->`, `++`, and the `@` of gate modifiers have a space
+//!   on each side, and a comma is followed by a space. There is no space inside brackets or
+//!   parentheses, after a unary operator, around the `:` of a range, or before a bracket or
+//!   parenthesis following a name or type. Adjacent words, such as names, keywords, and
+//!   numbers, are separated by one space, as is a word following `)` or `]`. Elsewhere, a
+//!   space is kept if there was whitespace in the source.
+//! * A comment at the end of a line stays at the end of that line.
+//! * The bodies of `cal` and `defcal`, which are not OpenQASM, are not changed.
+//!
+//! Formatting formatted source does not change it.
+
+use oq3_parser::LexedStr;
+
+use crate::{
+    ast::{self, edit::IndentLevel, make, AstNode, AstToken},
+    ted, SourceFile,
+    SyntaxKind::{self, *},
+    SyntaxNode, SyntaxToken, TextRange, TextSize, T,
+};
+
+/// An edit replacing the text in `delete` with `insert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Indel {
+    pub insert: String,
+    pub delete: TextRange,
+}
+
+impl Indel {
+    pub fn apply(&self, text: &mut String) {
+        let start: usize = self.delete.start().into();
+        let end: usize = self.delete.end().into();
+        text.replace_range(start..end, &self.insert);
+    }
+}
+
+/// Parse `text` and return it formatted.
+pub fn format_text(text: &str) -> String {
+    format_source_file(&SourceFile::parse(text).tree())
+        .syntax()
+        .to_string()
+}
+
+/// Return a formatted copy of `file`.
+pub fn format_source_file(file: &SourceFile) -> SourceFile {
+    let root = file.syntax().clone_for_update();
+    let gaps: Vec<Gap> = gaps(&root).collect();
+    // Edit from the end, so that the gaps not yet edited are not disturbed.
+    for gap in gaps.into_iter().rev() {
+        gap.apply();
+    }
+    SourceFile::cast(root.clone_subtree()).unwrap()
+}
+
+/// Return the edits that format the whitespace in `range` of `file`. Whitespace that
+/// touches `range` is included, so that the indentation of the first line of a range is
+/// fixed. The edits are sorted and do not overlap, so they may be applied in reverse order.
+pub fn format_range(file: &SourceFile, range: TextRange) -> Vec<Indel> {
+    gaps(file.syntax())
+        .filter(|gap| gap.range.start() <= range.end() && range.start() <= gap.range.end())
+        .filter(|gap| gap.is_changed())
+        .map(|gap| Indel {
+            insert: gap.new_text,
+            delete: gap.range,
+        })
+        .collect()
+}
+
+// The whitespace between two adjacent tokens that are not whitespace. `prev` is `None` at
+// the start of the file and `next` is `None` at the end.
+struct Gap {
+    prev: Option<SyntaxToken>,
+    next: Option<SyntaxToken>,
+    whitespace: Vec<SyntaxToken>,
+    range: TextRange,
+    new_text: String,
+}
+
+impl Gap {
+    fn new(
+        prev: Option<SyntaxToken>,
+        next: Option<SyntaxToken>,
+        whitespace: Vec<SyntaxToken>,
+        end: TextSize,
+    ) -> Gap {
+        let start = prev
+            .as_ref()
+            .map_or(TextSize::from(0), |prev| prev.text_range().end());
+        let end = next.as_ref().map_or(end, |next| next.text_range().start());
+        let old_text = old_text(&whitespace);
+        let new_text = match (&prev, &next) {
+            (None, _) => String::new(),
+            (Some(_), None) => "\n".to_string(),
+            (Some(prev), Some(next)) => gap_text(prev, next, &old_text),
+        };
+        Gap {
+            prev,
+            next,
+            whitespace,
+            range: TextRange::new(start, end),
+            new_text,
+        }
+    }
+
+    fn is_changed(&self) -> bool {
+        old_text(&self.whitespace) != self.new_text
+    }
+
+    fn apply(self) {
+        if !self.is_changed() {
+            return;
+        }
+        if let Some((first, rest)) = self.whitespace.split_first() {
+            rest.iter().for_each(ted::remove);
+            if self.new_text.is_empty() {
+                ted::remove(first);
+            } else {
+                ted::replace(first, make::tokens::whitespace(&self.new_text));
+            }
+            return;
+        }
+        let whitespace = make::tokens::whitespace(&self.new_text);
+        match (self.prev, self.next) {
+            (Some(prev), _) => ted::insert_raw(ted::Position::after(prev), whitespace),
+            (None, Some(next)) => ted::insert_raw(ted::Position::before(next), whitespace),
+            (None, None) => {}
+        }
+    }
+}
+
+fn old_text(whitespace: &[SyntaxToken]) -> String {
+    whitespace.iter().map(|ws| ws.text()).collect()
+}
+
+fn gaps(root: &SyntaxNode) -> impl Iterator<Item = Gap> {
+    let end = root.text_range().end();
+    let mut tokens = root
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token());
+    let mut prev = None;
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let mut whitespace = Vec::new();
+        for token in tokens.by_ref() {
+            if token.kind() == WHITESPACE {
+                whitespace.push(token);
+                continue;
+            }
+            let gap = Gap::new(prev.take(), Some(token.clone()), whitespace, end);
+            prev = Some(token);
+            return Some(gap);
+        }
+        done = true;
+        Some(Gap::new(prev.take(), None, whitespace, end))
+    })
+}
+
+// How two tokens are separated.
+enum Layout {
+    Nothing,
+    Space,
+    // A number of line breaks followed by indentation.
+    Lines(usize),
+}
+
+fn gap_text(prev: &SyntaxToken, next: &SyntaxToken, old_text: &str) -> String {
+    if in_calibration_block(prev, next) {
+        return old_text.to_string();
+    }
+    match layout(prev, next, old_text) {
+        Layout::Nothing => String::new(),
+        Layout::Space => " ".to_string(),
+        Layout::Lines(n) => {
+            let mut indent = indent_level(next);
+            if !starts_statement(next) && !is_closing(next) {
+                indent = indent + 1;
+            }
+            format!("{}{indent}", "\n".repeat(n))
+        }
+    }
+}
+
+fn layout(prev: &SyntaxToken, next: &SyntaxToken, old_text: &str) -> Layout {
+    let newlines = old_text.matches('\n').count();
+    // Keep at most one blank line.
+    let lines = newlines.clamp(1, 2);
+    if is_line_comment(prev) || matches!(prev.kind(), ANNOTATION_TEXT | PRAGMA_TEXT) {
+        return Layout::Lines(lines);
+    }
+    if is_line_comment(next) && newlines == 0 {
+        return Layout::Space;
+    }
+    if is_block_brace(prev, T!['{']) {
+        if is_block_brace(next, T!['}']) && prev.parent() == next.parent() {
+            return Layout::Nothing;
+        }
+        return Layout::Lines(1);
+    }
+    if is_block_brace(next, T!['}']) {
+        return Layout::Lines(1);
+    }
+    // A block that belongs to a statement is opened on the line of the statement.
+    if is_block_brace(next, T!['{']) && !starts_statement(next) {
+        return Layout::Space;
+    }
+    if matches!(next.kind(), T![,] | T![;]) {
+        return Layout::Nothing;
+    }
+    if is_block_brace(prev, T!['}']) {
+        match next.kind() {
+            T![else] => return Layout::Space,
+            T![')'] => return Layout::Nothing,
+            _ => {}
+        }
+    }
+    if ends_statement(prev) || newlines > 0 {
+        return Layout::Lines(lines);
+    }
+    match spacing(prev, next) {
+        Some(true) => Layout::Space,
+        Some(false) if old_text.is_empty() || can_join(prev, next) => Layout::Nothing,
+        Some(false) => Layout::Space,
+        None if old_text.is_empty() => Layout::Nothing,
+        None => Layout::Space,
+    }
+}
+
+// Whether there is a space between two tokens on one line. `None` means that a space is
+// kept if there was whitespace in the source.
+fn spacing(prev: &SyntaxToken, next: &SyntaxToken) -> Option<bool> {
+    if matches!(prev.kind(), T!['('] | T!['[']) || matches!(next.kind(), T![')'] | T![']']) {
+        return Some(false);
+    }
+    if prev.kind() == T![,] || is_infix_op(prev) || is_infix_op(next) {
+        return Some(true);
+    }
+    if is_word(next) && (is_word(prev) || matches!(prev.kind(), T![')'] | T![']'])) {
+        return Some(true);
+    }
+    if is_prefix_op(prev) || prev.kind() == T![:] || next.kind() == T![:] {
+        return Some(false);
+    }
+    match next.kind() {
+        T!['('] if matches!(prev.kind(), T![if] | T![while] | T![switch]) => Some(true),
+        T!['('] | T!['['] if is_callee(prev) => Some(false),
+        T!['{'] if is_block_brace(next, T!['{']) || has_parent(next, CALIBRATION_BLOCK) => {
+            Some(true)
+        }
+        T!['{'] if prev.kind() == T!['{'] => Some(false),
+        T!['}'] if prev.kind() == T!['}'] => Some(false),
+        _ if prev.kind() == T!['{'] || next.kind() == T!['}'] => Some(false),
+        _ => None,
+    }
+}
+
+// A name, keyword, number, or hardware qubit.
+fn is_word(token: &SyntaxToken) -> bool {
+    token
+        .text()
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_alphanumeric() || matches!(c, '_' | '$'))
+}
+
+fn has_parent(token: &SyntaxToken, kind: SyntaxKind) -> bool {
+    token.parent().map_or(false, |parent| parent.kind() == kind)
+}
+
+fn is_line_comment(token: &SyntaxToken) -> bool {
+    ast::Comment::cast(token.clone()).map_or(false, |comment| comment.kind().shape.is_line())
+}
+
+// A brace that opens or closes a block of statements.
+fn is_block_brace(token: &SyntaxToken, kind: SyntaxKind) -> bool {
+    token.kind() == kind && token.parent().map_or(false, |parent| is_block(&parent))
+}
+
+fn is_block(node: &SyntaxNode) -> bool {
+    matches!(node.kind(), BLOCK_EXPR | SWITCH_CASE_STMT)
+}
+
+fn is_closing(token: &SyntaxToken) -> bool {
+    matches!(token.kind(), T![')'] | T![']'] | T!['}'])
+}
+
+fn ends_statement(token: &SyntaxToken) -> bool {
+    match token.kind() {
+        T![;] => true,
+        T!['}'] => {
+            is_block_brace(token, T!['}'])
+                || token.parent().map_or(false, |parent| {
+                    parent.kind() == CALIBRATION_BLOCK
+                        && !parent
+                            .parent()
+                            .map_or(false, |grandparent| grandparent.kind() == CALIBRATION_BLOCK)
+                })
+        }
+        _ => false,
+    }
+}
+
+// Whether `token` is the first token of a statement, not counting comments.
+fn starts_statement(token: &SyntaxToken) -> bool {
+    let prev_code = std::iter::successors(token.prev_token(), |token| token.prev_token())
+        .find(|token| !token.kind().is_trivia());
+    match prev_code {
+        None => true,
+        Some(prev) => {
+            ends_statement(&prev)
+                || is_block_brace(&prev, T!['{'])
+                || matches!(prev.kind(), ANNOTATION_TEXT | PRAGMA_TEXT)
+        }
+    }
+}
+
+fn indent_level(token: &SyntaxToken) -> IndentLevel {
+    let level = token
+        .parent_ancestors()
+        .filter(|node| is_block(node) && is_inside_braces(token, node))
+        .count();
+    IndentLevel(level as u8)
+}
+
+fn is_inside_braces(token: &SyntaxToken, block: &SyntaxNode) -> bool {
+    let brace = |kind| {
+        block
+            .children_with_tokens()
+            .filter_map(|element| element.into_token())
+            .find(|token| token.kind() == kind)
+    };
+    let Some(l_curly) = brace(T!['{']) else {
+        return false;
+    };
+    let start = token.text_range().start();
+    start >= l_curly.text_range().end()
+        && brace(T!['}']).map_or(true, |r_curly| start < r_curly.text_range().start())
+}
+
+fn in_calibration_block(prev: &SyntaxToken, next: &SyntaxToken) -> bool {
+    prev.parent_ancestors().any(|node| {
+        node.kind() == CALIBRATION_BLOCK && node.text_range().contains_range(next.text_range())
+    })
+}
+
+fn is_infix_op(token: &SyntaxToken) -> bool {
+    match token.kind() {
+        T![=]
+        | T![+=]
+        | T![-=]
+        | T![*=]
+        | T![/=]
+        | T![%=]
+        | T![&=]
+        | T![|=]
+        | T![^=]
+        | T![<<=]
+        | T![>>=]
+        | T![**=]
+        | T![->]
+        | T![++]
+        | T![@] => true,
+        _ => has_parent(token, BIN_EXPR),
+    }
+}
+
+fn is_prefix_op(token: &SyntaxToken) -> bool {
+    has_parent(token, PREFIX_EXPR)
+}
+
+// A token that may be followed directly by an argument list, designator or index.
+fn is_callee(token: &SyntaxToken) -> bool {
+    let kind = token.kind();
+    kind.is_scalar_type()
+        || matches!(
+            kind,
+            IDENT
+                | HARDWAREIDENT
+                | T![')']
+                | T![']']
+                | T![qubit]
+                | T![array]
+                | T![gphase]
+                | T![durationof]
+                | T![ctrl]
+                | T![negctrl]
+                | T![pow]
+                | T![delay]
+                | T![box]
+        )
+}
+
+// Whether removing the whitespace between two tokens leaves the same two tokens.
+fn can_join(left: &SyntaxToken, right: &SyntaxToken) -> bool {
+    let text = format!("{}{}", left.text(), right.text());
+    let lexed = LexedStr::new(&text);
+    lexed.len() == 2 && lexed.kind(0) == left.kind() && lexed.kind(1) == right.kind()
+}
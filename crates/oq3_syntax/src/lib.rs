@@ -44,6 +44,7 @@ mod token_text;
 mod validation;
 
 pub mod ast;
+pub mod format;
 pub mod ted;
 
 use std::marker::PhantomData;
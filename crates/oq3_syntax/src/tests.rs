@@ -20,7 +20,8 @@ use crate::ast::HasTextName; // for methods: text(), string()
                              // Adds complication from rust-analyzer
                              // use test_utils::{bench, bench_fixture, project_root};
                              //use crate::{ast, AstNode, SourceFile, SyntaxError};
-use crate::SourceFile;
+use crate::format::{format_range, format_text};
+use crate::{SourceFile, TextRange};
 
 // fn collect_items(code: &str) -> (usize, Vec<ast::Item>){
 //     let parse = SourceFile::parse(code);
@@ -173,8 +174,10 @@ defcal xmeasure(int a, b) q, p -> bit {
    1 + 1;
 }
     "##;
+    // `b` is parsed as a constant argument, as in `defcal rx(pi / 2) $0`.
+    // Whether it is defined is checked in semantic analysis.
     let parse = SourceFile::parse(code);
-    assert_eq!(parse.errors.len(), 1);
+    assert_eq!(parse.errors.len(), 0);
 }
 
 #[test]
@@ -199,6 +202,35 @@ defcal xmeasure(int a, int b) q, p -> bit {
     assert_eq!(parse.errors.len(), 0);
 }
 
+#[test]
+fn parse_qasm_defcal_calibration_block_test() {
+    let code = r##"
+defcalgrammar "openpulse";
+defcal rx(angle[20] theta, pi / 2) $0 {
+   play(drive($0), gaussian(theta)) { nested; }
+}
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let defcal = match file.items().nth(1).unwrap() {
+        ast::Item::DefCal(defcal) => defcal,
+        _ => unreachable!(),
+    };
+    let params = defcal
+        .def_cal_param_list()
+        .unwrap()
+        .params()
+        .collect::<Vec<_>>();
+    assert_eq!(params.len(), 2);
+    assert!(matches!(params[0], ast::DefCalParam::Typed(_)));
+    assert!(matches!(params[1], ast::DefCalParam::Constant(_)));
+    assert_eq!(
+        defcal.body().unwrap().contents().trim(),
+        "play(drive($0), gaussian(theta)) { nested; }"
+    );
+}
+
 #[test]
 fn parse_qasm_def_test() {
     let code = r##"
@@ -343,12 +375,731 @@ int(x) + z;
     assert_eq!(parse.errors.len(), 0);
 }
 
-// FIXME: Should be zero errors!
 #[test]
 fn parse_cast_expr_test_7() {
     let code = r##"
 z + int[32](x);
     "##;
     let parse = SourceFile::parse(code);
-    assert_eq!(parse.errors.len(), 2);
+    assert_eq!(parse.errors.len(), 0);
+}
+
+#[test]
+fn parse_cast_expr_stmt_test() {
+    let code = r##"
+int[32](x);
+angle[16](x) + 1;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let stmts: Vec<_> = parse.tree().statements().collect();
+    assert_eq!(stmts.len(), 2);
+    assert!(stmts
+        .iter()
+        .all(|stmt| matches!(stmt, ast::Stmt::ExprStmt(_))));
+}
+
+#[test]
+fn parse_for_range_test() {
+    let code = r##"
+for int i in [0:2:10] {
+   x;
+}
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+}
+
+#[test]
+fn parse_for_set_test() {
+    let code = r##"
+for uint i in {1, 3, 5} {
+   x;
+}
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+}
+
+#[test]
+fn parse_for_expr_test() {
+    let code = r##"
+for int i in myarr {
+   x;
+}
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let for_stmt = match file.items().next() {
+        Some(ast::Item::ForStmt(f)) => f,
+        _ => unreachable!(),
+    };
+    assert_eq!(for_stmt.loop_var().unwrap().text(), "i");
+    assert_eq!(
+        for_stmt.scalar_type().unwrap().kind(),
+        ast::ScalarTypeKind::Int
+    );
+    assert!(for_stmt
+        .for_iterable()
+        .unwrap()
+        .for_iterable_expr()
+        .is_some());
+}
+
+#[test]
+fn parse_for_err1_test() {
+    let code = r##"
+for i in [0:3] {
+   x;
+}
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 1);
+}
+
+#[test]
+fn parse_def_typed_params_test() {
+    use ast::HasName;
+
+    let code = r##"
+def f(int[32] a, qubit[2] q, readonly array[int[8], 2, 3] b, mutable array[float, #dim=2] c) -> bit {
+   return a;
+}
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let def = match file.items().next() {
+        Some(ast::Item::Def(def)) => def,
+        _ => unreachable!(),
+    };
+    let params = def
+        .typed_param_list()
+        .unwrap()
+        .typed_params()
+        .collect::<Vec<_>>();
+    assert_eq!(params.len(), 4);
+    assert_eq!(params[0].name().unwrap().text(), "a");
+    assert!(params[1].qubit_type().is_some());
+    assert!(params[2].array_type().unwrap().readonly_token().is_some());
+    assert!(params[3].array_type().unwrap().dim_expr().is_some());
+    assert!(def.ret_type().unwrap().scalar_type().is_some());
+}
+
+#[test]
+fn parse_call_expr_test() {
+    let code = r##"
+x = f(1, q) + 2;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+}
+
+#[test]
+fn parse_gate_modifiers_test() {
+    let code = r##"
+inv @ pow(2) @ ctrl(2) @ negctrl @ x q0, q1, q2, q3;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let gate_call = match file.items().next() {
+        Some(ast::Item::GateCallStmt(gate_call)) => gate_call,
+        _ => unreachable!(),
+    };
+    let modifiers = gate_call
+        .modifier_list()
+        .unwrap()
+        .gate_modifiers()
+        .collect::<Vec<_>>();
+    assert_eq!(modifiers.len(), 4);
+    assert!(modifiers[0].inv_token().is_some());
+    assert!(modifiers[1].expr().is_some());
+    assert!(modifiers[2].ctrl_token().is_some());
+    assert!(modifiers[3].expr().is_none());
+    assert_eq!(gate_call.identifier().unwrap().text(), "x");
+}
+
+#[test]
+fn parse_gate_modifiers_err1_test() {
+    let code = r##"
+pow @ x q;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 1);
+}
+
+#[test]
+fn parse_measure_arrow_test() {
+    let code = r##"
+measure q[0] -> c[0];
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let measure_stmt = match file.items().next() {
+        Some(ast::Item::MeasureArrowAssignmentStmt(m)) => m,
+        _ => unreachable!(),
+    };
+    assert!(measure_stmt.indexed_identifier().is_some());
+    assert!(matches!(
+        measure_stmt.measure_expression().unwrap().gate_operand(),
+        Some(ast::GateOperand::IndexedIdentifier(_))
+    ));
+}
+
+#[test]
+fn parse_measure_arrow_err1_test() {
+    let code = r##"
+measure q -> ;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 1);
+}
+
+#[test]
+fn parse_reset_test() {
+    let code = r##"
+reset q[0];
+reset $1;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+}
+
+#[test]
+fn parse_delay_test() {
+    let code = r##"
+delay[d] q[0], $1;
+delay[d];
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let delays = file
+        .items()
+        .map(|item| match item {
+            ast::Item::DelayStmt(delay) => delay,
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(delays[0].qubit_list().unwrap().gate_operands().count(), 2);
+    assert!(delays[1].qubit_list().is_none());
+}
+
+#[test]
+fn parse_delay_err1_test() {
+    let code = r##"
+delay q;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 1);
+}
+
+#[test]
+fn parse_box_test() {
+    let code = r##"
+box[100ns] { x q; }
+box { x q; }
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let boxes = file
+        .items()
+        .map(|item| match item {
+            ast::Item::BoxStmt(box_stmt) => box_stmt,
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert!(boxes[0].designator().is_some());
+    assert!(boxes[1].designator().is_none());
+    assert!(boxes[1].body().is_some());
+}
+
+#[test]
+fn parse_durationof_test() {
+    let code = r##"
+d = durationof({ x q; });
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+}
+
+#[test]
+fn parse_io_declaration_test() {
+    let code = r##"
+input angle theta;
+output array[bit, 4] result;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let decls = file
+        .items()
+        .map(|item| match item {
+            ast::Item::IODeclarationStatement(decl) => decl,
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert!(decls[0].input_token().is_some());
+    assert!(decls[0].scalar_type().is_some());
+    assert!(decls[1].output_token().is_some());
+    assert!(decls[1].array_type().is_some());
+}
+
+#[test]
+fn parse_old_style_declaration_test() {
+    let code = r##"
+qreg q[5];
+creg c;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let decls = file
+        .items()
+        .map(|item| match item {
+            ast::Item::OldStyleDeclarationStatement(decl) => decl,
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert!(decls[0].qreg_token().is_some());
+    assert!(decls[0].designator().is_some());
+    assert!(decls[1].creg_token().is_some());
+    assert!(decls[1].designator().is_none());
+}
+
+#[test]
+fn parse_opaque_test() {
+    let code = r##"
+opaque g(theta) a, b;
+opaque q;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let mut items = file.items();
+    match items.next().unwrap() {
+        ast::Item::Gate(gate) => {
+            assert!(gate.opaque_token().is_some());
+            assert!(gate.body().is_none());
+        }
+        _ => unreachable!(),
+    };
+    // `opaque` is only a keyword in a gate declaration. Here it names a gate.
+    assert!(matches!(items.next().unwrap(), ast::Item::GateCallStmt(_)));
+}
+
+#[test]
+fn parse_if_single_stmt_test() {
+    let code = r##"
+if (c == 1) x q; else y q;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let if_stmt = match file.items().next().unwrap() {
+        ast::Item::IfStmt(if_stmt) => if_stmt,
+        _ => unreachable!(),
+    };
+    assert_eq!(if_stmt.then_branch().unwrap().statements().count(), 1);
+    assert_eq!(if_stmt.else_branch().unwrap().statements().count(), 1);
+}
+
+#[test]
+fn parse_let_concatenation_test() {
+    use ast::HasName;
+    let code = r##"
+let q2 = q[0:2] ++ r ++ s;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let let_stmt = match file.items().next().unwrap() {
+        ast::Item::LetStmt(let_stmt) => let_stmt,
+        _ => unreachable!(),
+    };
+    assert_eq!(let_stmt.name().unwrap().string(), "q2");
+    let concat = match let_stmt.expr().unwrap() {
+        ast::Expr::BinExpr(bin_expr) => bin_expr,
+        _ => unreachable!(),
+    };
+    assert_eq!(concat.op_kind(), Some(ast::BinaryOp::ConcatenationOp));
+    // `++` is left associative.
+    assert!(matches!(concat.lhs().unwrap(), ast::Expr::BinExpr(_)));
+}
+
+#[test]
+fn parse_extern_test() {
+    use ast::HasName;
+    let code = r##"
+extern f(int[32], readonly array[float[64], 2], creg[4]) -> bit;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let extern_item = match file.items().next().unwrap() {
+        ast::Item::ExternItem(extern_item) => extern_item,
+        _ => unreachable!(),
+    };
+    assert_eq!(extern_item.name().unwrap().string(), "f");
+    let params: Vec<_> = extern_item
+        .extern_param_list()
+        .unwrap()
+        .extern_params()
+        .collect();
+    assert_eq!(params.len(), 3);
+    assert!(params[0].scalar_type().is_some());
+    assert!(params[1].array_type().is_some());
+    assert!(params[2].creg_token().is_some());
+    assert!(extern_item.ret_type().is_some());
+}
+
+#[test]
+fn parse_switch_test() {
+    let code = r##"
+switch (i) {
+  case 0, 1 { x q; }
+  default { }
+}
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let switch_stmt = match file.items().next().unwrap() {
+        ast::Item::SwitchCaseStmt(switch_stmt) => switch_stmt,
+        _ => unreachable!(),
+    };
+    assert!(switch_stmt.control().is_some());
+    let case_items: Vec<_> = switch_stmt.case_items().collect();
+    assert_eq!(case_items.len(), 2);
+    assert_eq!(case_items[0].expression_list().unwrap().exprs().count(), 2);
+    assert!(case_items[1].default_token().is_some());
+    assert!(case_items[1].block_expr().is_some());
+}
+
+#[test]
+fn parse_annotation_test() {
+    let code = r##"
+#pragma mode fast
+@schedule asap
+ctrl @ x q0, q1;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let mut items = file.items();
+    let pragma = match items.next().unwrap() {
+        ast::Item::Pragma(pragma) => pragma,
+        _ => unreachable!(),
+    };
+    assert_eq!(pragma.content(), "mode fast");
+    let annotated = match items.next().unwrap() {
+        ast::Item::AnnotatedStmt(annotated) => annotated,
+        _ => unreachable!(),
+    };
+    let annotation = annotated.annotations().next().unwrap();
+    assert_eq!(annotation.keyword(), "schedule");
+    assert_eq!(annotation.body().as_deref(), Some("asap"));
+    // The `@` in the modifier is not an annotation.
+    assert!(matches!(
+        annotated.stmt(),
+        Some(ast::Stmt::Item(ast::Item::GateCallStmt(_)))
+    ));
+}
+
+#[test]
+fn parse_power_and_prefix_test() {
+    let code = r##"
+-x ** 2 ** 3;
+~c & !b;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let exprs = file
+        .statements()
+        .map(|stmt| match stmt {
+            ast::Stmt::ExprStmt(expr_stmt) => expr_stmt.expr().unwrap(),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    // `**` binds more tightly than unary minus and is right associative.
+    let prefix_expr = match &exprs[0] {
+        ast::Expr::PrefixExpr(prefix_expr) => prefix_expr,
+        _ => unreachable!(),
+    };
+    assert_eq!(prefix_expr.op_kind(), Some(ast::UnaryOp::Neg));
+    let power = match prefix_expr.expr().unwrap() {
+        ast::Expr::BinExpr(bin_expr) => bin_expr,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        power.op_kind(),
+        Some(ast::BinaryOp::ArithOp(ast::ArithOp::Pow))
+    );
+    assert!(matches!(power.rhs(), Some(ast::Expr::BinExpr(_))));
+    let bit_and = match &exprs[1] {
+        ast::Expr::BinExpr(bin_expr) => bin_expr,
+        _ => unreachable!(),
+    };
+    assert!(matches!(
+        bit_and.lhs(),
+        Some(ast::Expr::PrefixExpr(ref e)) if e.op_kind() == Some(ast::UnaryOp::BitNot)
+    ));
+}
+
+#[test]
+fn parse_compound_assignment_test() {
+    let code = r##"
+x += 1;
+a[i, j] **= 2;
+c[0:3] = "0101";
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let ops = file
+        .items()
+        .map(|item| match item {
+            ast::Item::AssignmentStmt(assignment) => assignment.op_kind().unwrap(),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ops,
+        vec![
+            ast::BinaryOp::Assignment {
+                op: Some(ast::ArithOp::Add)
+            },
+            ast::BinaryOp::Assignment {
+                op: Some(ast::ArithOp::Pow)
+            },
+            ast::BinaryOp::Assignment { op: None },
+        ]
+    );
+}
+
+#[test]
+fn parse_array_declaration_test() {
+    let code = r##"
+array[int[32], 2, 3] a = {{1, 2, 3}, {4, 5, 6}};
+a[0] = {7, 8, 9};
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let mut items = file.items();
+    let decl = match items.next() {
+        Some(ast::Item::ClassicalDeclarationStatement(decl)) => decl,
+        _ => unreachable!(),
+    };
+    assert!(decl.array_type().is_some());
+    let rows = match decl.expr() {
+        Some(ast::Expr::ArrayLiteral(array_literal)) => array_literal.expression_list().unwrap(),
+        _ => unreachable!(),
+    };
+    assert!(rows
+        .exprs()
+        .all(|row| matches!(row, ast::Expr::ArrayLiteral(_))));
+    assert!(matches!(
+        items.next(),
+        Some(ast::Item::AssignmentStmt(assignment))
+            if matches!(assignment.rhs(), Some(ast::Expr::ArrayLiteral(_)))
+    ));
+}
+
+#[test]
+fn parse_complex_declaration_test() {
+    let code = r##"
+complex[float[32]] z = 1.5im;
+    "##;
+    let parse = SourceFile::parse(code);
+    assert_eq!(parse.errors.len(), 0);
+    let file: SourceFile = parse.tree();
+    let decl = match file.items().next() {
+        Some(ast::Item::ClassicalDeclarationStatement(decl)) => decl,
+        _ => unreachable!(),
+    };
+    let scalar_type = decl.scalar_type().unwrap();
+    assert_eq!(scalar_type.kind(), ast::ScalarTypeKind::Complex);
+    assert_eq!(
+        scalar_type.scalar_type().unwrap().kind(),
+        ast::ScalarTypeKind::Float
+    );
+    let literal = match decl.expr() {
+        Some(ast::Expr::Literal(literal)) => literal,
+        _ => unreachable!(),
+    };
+    assert!(matches!(
+        literal.kind(),
+        ast::LiteralKind::ImaginaryNumber(num) if num.value() == Some(1.5)
+    ));
+}
+
+#[test]
+fn format_layout_test() {
+    let code = r##"
+
+OPENQASM 3.0;
+int x=1;
+int[32] y = -x+2*(x-1);
+gate h a { U(π/2, 0, π) a; }
+gate id a {}
+if(x==1){ x = -x; }
+   else { x += 1; }
+for int i in [0 : 2 : 4] {
+  array[int[8], 2, 2] a = { {1,2},{3,4} };
+}
+switch (x) { case 1, 2 { h q[0]; } default { reset q; } }
+ctrl (2)  @ inv  @ h q[0],q[1],q[2];
+def f(int y, qubit p)->int { return y+1; }
+x = f(x,
+  q[0]);
+"##;
+    let expected = r##"OPENQASM 3.0;
+int x = 1;
+int[32] y = -x + 2 * (x - 1);
+gate h a {
+    U(π / 2, 0, π) a;
+}
+gate id a {}
+if (x == 1) {
+    x = -x;
+} else {
+    x += 1;
+}
+for int i in [0:2:4] {
+    array[int[8], 2, 2] a = {{1, 2}, {3, 4}};
+}
+switch (x) {
+    case 1, 2 {
+        h q[0];
+    }
+    default {
+        reset q;
+    }
+}
+ctrl(2) @ inv @ h q[0], q[1], q[2];
+def f(int y, qubit p) -> int {
+    return y + 1;
+}
+x = f(x,
+    q[0]);
+"##;
+    assert_eq!(format_text(code), expected);
+}
+
+#[test]
+fn format_misplaced_brace_test() {
+    let code = r##"
+gate mygate(a, b) q
+{
+U(a, b, 0)q; // trailing
+}
+qubit[2]q;
+if (true)
+{
+  mygate(0, 1)q[0];
+}
+else
+{
+}
+{
+int[32]x;
+}
+"##;
+    let expected = r##"gate mygate(a, b) q {
+    U(a, b, 0) q; // trailing
+}
+qubit[2] q;
+if (true) {
+    mygate(0, 1) q[0];
+} else {}
+{
+    int[32] x;
+}
+"##;
+    assert_eq!(format_text(code), expected);
+}
+
+#[test]
+fn format_comments_test() {
+    let code = r##"
+// A comment on its own line.
+int x; // A comment at the end of a line.
+
+
+
+/* A block comment */ gate h a {
+// Indented with the block.
+  U(0, 0, 0) a;  // After a statement.
+}
+@bind q
+int y;
+pragma   is not changed
+cal {   waveform w = gaussian(1, 2);
+  }
+"##;
+    let expected = r##"// A comment on its own line.
+int x; // A comment at the end of a line.
+
+/* A block comment */ gate h a {
+    // Indented with the block.
+    U(0, 0, 0) a; // After a statement.
+}
+@bind q
+int y;
+pragma   is not changed
+cal {   waveform w = gaussian(1, 2);
+  }
+"##;
+    assert_eq!(format_text(code), expected);
+}
+
+// Formatting changes only whitespace, and formatting formatted code changes nothing.
+#[test]
+fn format_idempotent_test() {
+    let without_whitespace =
+        |text: &str| -> String { text.chars().filter(|c| !c.is_whitespace()).collect() };
+    let sources = include_str!("tests.rs");
+    for code in sources
+        .split("r##\"")
+        .skip(1)
+        .map(|rest| rest.split("\"##").next().unwrap())
+    {
+        let formatted = format_text(code);
+        assert_eq!(without_whitespace(code), without_whitespace(&formatted));
+        assert_eq!(
+            SourceFile::parse(code).errors.len(),
+            SourceFile::parse(&formatted).errors.len()
+        );
+        assert_eq!(format_text(&formatted), formatted, "{code}");
+    }
+}
+
+#[test]
+fn format_range_test() {
+    let code = r##"int  x;
+if (x==1) { x=2; }
+int  y;
+"##;
+    let file = SourceFile::parse(code).tree();
+    let start = code.find("if").unwrap();
+    let end = code.find("int  y").unwrap();
+    let range = TextRange::new((start as u32).into(), (end as u32).into());
+    let indels = format_range(&file, range);
+    let mut formatted = code.to_string();
+    for indel in indels.iter().rev() {
+        indel.apply(&mut formatted);
+    }
+    let expected = r##"int  x;
+if (x == 1) {
+    x = 2;
+}
+int  y;
+"##;
+    assert_eq!(formatted, expected);
 }
@@ -6,6 +6,7 @@
 pub(crate) struct KindsSrc<'a> {
     pub(crate) punct: &'a [(&'a str, &'a str)],
     pub(crate) keywords: &'a [&'a str],
+    pub(crate) contextual_keywords: &'a [&'a str],
     pub(crate) literals: &'a [&'a str],
     pub(crate) scalar_types: &'a [&'a str],
     pub(crate) tokens: &'a [&'a str],
@@ -67,6 +68,8 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         (">>", "SHR"),
         ("<<=", "SHLEQ"),
         (">>=", "SHREQ"),
+        ("**", "STAR2"),
+        ("**=", "STAR2EQ"),
     ],
     keywords: &[
         "OPENQASM",
@@ -86,13 +89,21 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         "extern",
         "const",
         "barrier",
+        "durationof",
         "gphase", // This is a slight hack because a `gphase` call has unique syntax.
+        "inv",
+        "pow",
+        "ctrl",
+        "negctrl",
         // Flow control
         "if",
         "else",
         "for",
         "in",
         "while",
+        "switch",
+        "case",
+        "default",
         "continue",
         "return",
         "break",
@@ -110,6 +121,8 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         "false",
         "true",
     ],
+    // Keywords only in some contexts. The lexer produces an `IDENT`.
+    contextual_keywords: &["opaque"],
     // GJL: try introducing scalar_types to help parse var declarations. May not be useful
     // sourcegen_ast.rs can convert these to upper snake case.
     scalar_types: &[
@@ -126,18 +139,30 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         "BIT_STRING",
         "TIMING_FLOAT_NUMBER",
         "TIMING_INT_NUMBER",
+        "IMAGINARY_NUMBER",
+    ],
+    tokens: &[
+        "ERROR",
+        "IDENT",
+        "HARDWAREIDENT", // FIXME, prob remove HARDWAREIDENT
+        "WHITESPACE",
+        "COMMENT",
+        "ANNOTATION_TEXT",
+        "PRAGMA_TEXT",
     ],
-    tokens: &["ERROR", "IDENT", "HARDWAREIDENT", "WHITESPACE", "COMMENT"], // FIXME, prob remove HARDWAREIDENT
     nodes: &[
         "SOURCE_FILE",
         "GATE",
         "DEF_CAL",
+        "DEF_CAL_PARAM_LIST",
+        "CALIBRATION_BLOCK",
         "CAL",
         "DEF_CAL_GRAMMAR",
-        "MEASURE",
+        "MEASURE_ARROW_ASSIGNMENT_STMT",
         "BARRIER",
         "DEF",
         "RESET",
+        "DELAY_STMT",
         "RET_TYPE",
         "CONST",
         "PAREN_TYPE",
@@ -150,7 +175,10 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         "PATH_EXPR",
         "IF_STMT",
         "WHILE_STMT",
+        "SWITCH_CASE_STMT",
+        "CASE_ITEM",
         "FOR_STMT",
+        "FOR_ITERABLE",
         "END_STMT",
         "CONTINUE_STMT",
         "BREAK_STMT",
@@ -162,12 +190,15 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         "LET_STMT",
         "ALIAS_EXPR",
         "CONCATENATION_EXPR",
-        "BOX_EXPR",
+        "BOX_STMT",
+        "DURATION_OF_EXPR",
         // postfix
         "CALL_EXPR",
         "CAST_EXPRESSION",
         "GATE_CALL_STMT",
         "G_PHASE_CALL_STMT",
+        "MODIFIER_LIST",
+        "GATE_MODIFIER",
         "INDEX_EXPR",
         // unary
         "PREFIX_EXPR",
@@ -175,6 +206,8 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         "BIN_EXPR",
         "SET_NUM",
         "EXTERN_ITEM",
+        "EXTERN_PARAM_LIST",
+        "EXTERN_PARAM",
         "ITEM_LIST",
         "PATH",
         "PATH_SEGMENT",
@@ -183,6 +216,9 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         "NAME_REF",
         // "LET_ELSE",
         "EXPR_STMT",
+        "ANNOTATED_STMT",
+        "ANNOTATION",
+        "PRAGMA",
         //        "TYPE_PARAM",
         "TYPE_SPEC", // "SPEC" to avoid the word "type"
         "TYPE_ARG",
@@ -194,9 +230,11 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         "CONST_PARAM",
         "CONST_ARG",
         "PARAM_LIST",
+        "TYPED_PARAM_LIST",
         "QUBIT_LIST",
         "FILE_PATH",
         "PARAM",
+        "TYPED_PARAM",
         "ARG_LIST",
         "GATE_ARG_LIST",
         "VERSION",
@@ -208,6 +246,7 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         "SCALAR_TYPE",
         "SCALAR_TYPE_NAME",
         "ARRAY_TYPE",
+        "DIM_EXPR",
         "QUBIT_TYPE",
         "EXPRESSION_LIST",
         "RETURN_SIGNATURE",
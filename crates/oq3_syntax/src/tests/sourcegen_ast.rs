@@ -410,13 +410,13 @@ fn generate_syntax_kinds(grammar: KindsSrc<'_>) -> String {
     let full_keywords_values = grammar.keywords;
     let full_keywords = full_keywords_values.iter().map(upper_snake);
 
-    // let contextual_keywords_values = &grammar.contextual_keywords;
-    // let contextual_keywords = contextual_keywords_values.iter().map(upper_snake);
+    let contextual_keywords_values = &grammar.contextual_keywords;
+    let contextual_keywords = contextual_keywords_values.iter().map(upper_snake);
 
     let all_keywords_values = grammar
         .keywords
         .iter()
-        //        .chain(grammar.contextual_keywords.iter())
+        .chain(grammar.contextual_keywords.iter())
         .copied()
         .collect::<Vec<_>>();
     let all_keywords_idents = all_keywords_values.iter().map(|kw| format_ident!("{}", kw));
@@ -509,6 +509,14 @@ fn generate_syntax_kinds(grammar: KindsSrc<'_>) -> String {
                 Some(kw)
             }
 
+            pub fn from_contextual_keyword(ident: &str) -> Option<SyntaxKind> {
+                let kw = match ident {
+                    #(#contextual_keywords_values => #contextual_keywords,)*
+                    _ => return None,
+                };
+                Some(kw)
+            }
+
             pub fn from_scalar_type(type_name: &str) -> Option<SyntaxKind> {
                 let ty = match type_name {
                     #(#scalar_types_values => #scalar_types,)*
@@ -665,7 +673,7 @@ impl Field {
 fn lower(grammar: &Grammar) -> AstSrc {
     let mut res = AstSrc {
         tokens:
-            "Whitespace Comment String IntNumber FloatNumber Char Byte Ident TimingIntNumber TimingFloatNumber SimpleFloatNumber BitString"
+            "Whitespace Comment String IntNumber FloatNumber Char Byte Ident TimingIntNumber TimingFloatNumber SimpleFloatNumber BitString ImaginaryNumber"
                 .split_ascii_whitespace()
                 .map(|it| it.to_string())
                 .collect::<Vec<_>>(),
@@ -757,7 +765,11 @@ fn lower_rule(acc: &mut Vec<Field>, grammar: &Grammar, label: Option<&String>, r
             // pub fn hardwareident_token(&self) -> Option<SyntaxToken> {
             //     support::token(&self.syntax, T![hardwareident])
             // }
-            if name != "int_number" && name != "string" && name != "hardwareident" {
+            // Likewise for the single-token text of annotations and pragmas.
+            if !matches!(
+                name.as_str(),
+                "int_number" | "string" | "hardwareident" | "annotation_text" | "pragma_text"
+            ) {
                 if "[]{}()".contains(&name) {
                     name = format!("'{name}'");
                 }
@@ -174,7 +174,9 @@ fn validate_literal(literal: ast::Literal, acc: &mut Vec<SyntaxError>) {
         }
         ast::LiteralKind::IntNumber(_)
         | ast::LiteralKind::FloatNumber(_)
+        | ast::LiteralKind::TimingIntNumber(_)
         | ast::LiteralKind::TimingFloatNumber(_)
+        | ast::LiteralKind::ImaginaryNumber(_)
         | ast::LiteralKind::SimpleFloatNumber(_)
         | ast::LiteralKind::Bool(_) => {}
     }